@@ -42,6 +42,10 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public ulong DamageDealt { get; set; }
+
+            public bool RewardClaimed { get; set; }
+
             public static BossFightAccount Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -67,6 +71,54 @@ namespace Chaindepth
                 offset += 1;
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
+                result.DamageDealt = _data.GetU64(offset);
+                offset += 8;
+                result.RewardClaimed = _data.GetBool(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
+        public partial class DiscoveryRoyaltyAccount
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 2111539169183863140UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{100, 17, 135, 220, 175, 177, 77, 29};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "HjnoDXbEnSx";
+            public PublicKey Owner { get; set; }
+
+            public ulong SeasonSeed { get; set; }
+
+            public ulong SeasonAccrued { get; set; }
+
+            public ulong Pending { get; set; }
+
+            public ulong TotalClaimed { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static DiscoveryRoyaltyAccount Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                DiscoveryRoyaltyAccount result = new DiscoveryRoyaltyAccount();
+                result.Owner = _data.GetPubKey(offset);
+                offset += 32;
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.SeasonAccrued = _data.GetU64(offset);
+                offset += 8;
+                result.Pending = _data.GetU64(offset);
+                offset += 8;
+                result.TotalClaimed = _data.GetU64(offset);
+                offset += 8;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
                 return result;
             }
         }
@@ -187,6 +239,98 @@ namespace Chaindepth
             }
         }
 
+        public partial class ExportedItem
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 4888897327734102509UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{237, 153, 242, 146, 55, 218, 216, 67};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "gk2ncVKTNQi";
+            public PublicKey Mint { get; set; }
+
+            public ushort ItemId { get; set; }
+
+            public ushort Durability { get; set; }
+
+            public PublicKey Exporter { get; set; }
+
+            public ulong ExportedSlot { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static ExportedItem Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                ExportedItem result = new ExportedItem();
+                result.Mint = _data.GetPubKey(offset);
+                offset += 32;
+                result.ItemId = _data.GetU16(offset);
+                offset += 2;
+                result.Durability = _data.GetU16(offset);
+                offset += 2;
+                result.Exporter = _data.GetPubKey(offset);
+                offset += 32;
+                result.ExportedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
+        public partial class FloorConnectivity
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 16247111631399661278UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{222, 174, 177, 167, 242, 85, 121, 225};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "eFJJAkzzqmJ";
+            public ulong SeasonSeed { get; set; }
+
+            public byte Floor { get; set; }
+
+            public bool IsAnalyzed { get; set; }
+
+            public ushort ReachableWithoutRepairs { get; set; }
+
+            public ushort RepairCount { get; set; }
+
+            public byte[] RepairedWalls { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static FloorConnectivity Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                FloorConnectivity result = new FloorConnectivity();
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.Floor = _data.GetU8(offset);
+                offset += 1;
+                result.IsAnalyzed = _data.GetBool(offset);
+                offset += 1;
+                result.ReachableWithoutRepairs = _data.GetU16(offset);
+                offset += 2;
+                result.RepairCount = _data.GetU16(offset);
+                offset += 2;
+                result.RepairedWalls = _data.GetBytes(offset, 200);
+                offset += 200;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
         public partial class GlobalAccount
         {
             public static ulong ACCOUNT_DISCRIMINATOR => 5002420280216021377UL;
@@ -208,6 +352,36 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public ushort DeepExitScoreBps { get; set; }
+
+            public DungeonGrid Grid { get; set; }
+
+            public DungeonGrid NextGrid { get; set; }
+
+            public PublicKey[] AdminSet { get; set; }
+
+            public byte AdminCount { get; set; }
+
+            public byte AdminThreshold { get; set; }
+
+            public ulong AdminTimelockSlots { get; set; }
+
+            public ushort PausedFlags { get; set; }
+
+            public PublicKey[] RoleHolders { get; set; }
+
+            public PublicKey[] PendingRoleHolders { get; set; }
+
+            public ulong TotalRentReimbursed { get; set; }
+
+            public ulong RoomsFunded { get; set; }
+
+            public ulong SeasonRentReimbursed { get; set; }
+
+            public ulong SeasonRentBudget { get; set; }
+
+            public ulong TotalTreasuryFunded { get; set; }
+
             public static GlobalAccount Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -235,6 +409,83 @@ namespace Chaindepth
                 offset += 8;
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
+                result.DeepExitScoreBps = _data.GetU16(offset);
+                offset += 2;
+                offset += DungeonGrid.Deserialize(_data, offset, out var resultGrid);
+                result.Grid = resultGrid;
+                offset += DungeonGrid.Deserialize(_data, offset, out var resultNextGrid);
+                result.NextGrid = resultNextGrid;
+                result.AdminSet = new PublicKey[5];
+                for (uint resultAdminSetIdx = 0; resultAdminSetIdx < 5; resultAdminSetIdx++)
+                {
+                    result.AdminSet[resultAdminSetIdx] = _data.GetPubKey(offset);
+                    offset += 32;
+                }
+
+                result.AdminCount = _data.GetU8(offset);
+                offset += 1;
+                result.AdminThreshold = _data.GetU8(offset);
+                offset += 1;
+                result.AdminTimelockSlots = _data.GetU64(offset);
+                offset += 8;
+                result.PausedFlags = _data.GetU16(offset);
+                offset += 2;
+                result.RoleHolders = new PublicKey[4];
+                for (uint resultRoleHoldersIdx = 0; resultRoleHoldersIdx < 4; resultRoleHoldersIdx++)
+                {
+                    result.RoleHolders[resultRoleHoldersIdx] = _data.GetPubKey(offset);
+                    offset += 32;
+                }
+
+                result.PendingRoleHolders = new PublicKey[4];
+                for (uint resultPendingRoleHoldersIdx = 0; resultPendingRoleHoldersIdx < 4; resultPendingRoleHoldersIdx++)
+                {
+                    result.PendingRoleHolders[resultPendingRoleHoldersIdx] = _data.GetPubKey(offset);
+                    offset += 32;
+                }
+
+                result.TotalRentReimbursed = _data.GetU64(offset);
+                offset += 8;
+                result.RoomsFunded = _data.GetU64(offset);
+                offset += 8;
+                result.SeasonRentReimbursed = _data.GetU64(offset);
+                offset += 8;
+                result.SeasonRentBudget = _data.GetU64(offset);
+                offset += 8;
+                result.TotalTreasuryFunded = _data.GetU64(offset);
+                offset += 8;
+                return result;
+            }
+        }
+
+        public partial class GrantedItems
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 2723733778578207221UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{245, 241, 226, 206, 112, 165, 204, 37};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "i8yTADNxHnC";
+            public PublicKey Owner { get; set; }
+
+            public ulong GrantedUnits { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static GrantedItems Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                GrantedItems result = new GrantedItems();
+                result.Owner = _data.GetPubKey(offset);
+                offset += 32;
+                result.GrantedUnits = _data.GetU64(offset);
+                offset += 8;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
                 return result;
             }
         }
@@ -294,6 +545,8 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public ushort BonusSlots { get; set; }
+
             public static InventoryAccount Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -318,6 +571,8 @@ namespace Chaindepth
 
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
+                result.BonusSlots = _data.GetU16(offset);
+                offset += 2;
                 return result;
             }
         }
@@ -362,6 +617,107 @@ namespace Chaindepth
             }
         }
 
+        public partial class MerchantReceipt
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 4795722798520721406UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{254, 223, 17, 247, 121, 212, 141, 66};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "jda9e3Kk6Kb";
+            public PublicKey Player { get; set; }
+
+            public ulong SeasonSeed { get; set; }
+
+            public sbyte RoomX { get; set; }
+
+            public sbyte RoomY { get; set; }
+
+            public byte[] Purchased { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static MerchantReceipt Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                MerchantReceipt result = new MerchantReceipt();
+                result.Player = _data.GetPubKey(offset);
+                offset += 32;
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.RoomX = _data.GetS8(offset);
+                offset += 1;
+                result.RoomY = _data.GetS8(offset);
+                offset += 1;
+                result.Purchased = _data.GetBytes(offset, 3);
+                offset += 3;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
+        public partial class PendingAdminAction
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 4991302883819669944UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{184, 73, 116, 28, 135, 171, 68, 69};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "XppDTdY4wFS";
+            public PublicKey Proposer { get; set; }
+
+            public ulong ActionId { get; set; }
+
+            public AdminAction Action { get; set; }
+
+            public ulong ProposedSlot { get; set; }
+
+            public ulong ExecuteAfterSlot { get; set; }
+
+            public PublicKey[] Approvals { get; set; }
+
+            public byte ApprovalCount { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static PendingAdminAction Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                PendingAdminAction result = new PendingAdminAction();
+                result.Proposer = _data.GetPubKey(offset);
+                offset += 32;
+                result.ActionId = _data.GetU64(offset);
+                offset += 8;
+                offset += AdminAction.Deserialize(_data, offset, out var resultAction);
+                result.Action = resultAction;
+                result.ProposedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.ExecuteAfterSlot = _data.GetU64(offset);
+                offset += 8;
+                result.Approvals = new PublicKey[5];
+                for (uint resultApprovalsIdx = 0; resultApprovalsIdx < 5; resultApprovalsIdx++)
+                {
+                    result.Approvals[resultApprovalsIdx] = _data.GetPubKey(offset);
+                    offset += 32;
+                }
+
+                result.ApprovalCount = _data.GetU8(offset);
+                offset += 1;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
         public partial class PlayerAccount
         {
             public static ulong ACCOUNT_DISCRIMINATOR => 17019182578430687456UL;
@@ -401,6 +757,12 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public ulong Xp { get; set; }
+
+            public ushort Level { get; set; }
+
+            public byte CurrentFloor { get; set; }
+
             public static PlayerAccount Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -453,6 +815,12 @@ namespace Chaindepth
                 offset += 8;
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
+                result.Xp = _data.GetU64(offset);
+                offset += 8;
+                result.Level = _data.GetU16(offset);
+                offset += 2;
+                result.CurrentFloor = _data.GetU8(offset);
+                offset += 1;
                 return result;
             }
         }
@@ -554,6 +922,16 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public byte Floor { get; set; }
+
+            public byte BiomeId { get; set; }
+
+            public byte BossModifiers { get; set; }
+
+            public ulong BossEngagedSlot { get; set; }
+
+            public bool TrapDisarmed { get; set; }
+
             public static RoomAccount Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -652,6 +1030,16 @@ namespace Chaindepth
                 offset += 8;
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
+                result.Floor = _data.GetU8(offset);
+                offset += 1;
+                result.BiomeId = _data.GetU8(offset);
+                offset += 1;
+                result.BossModifiers = _data.GetU8(offset);
+                offset += 1;
+                result.BossEngagedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.TrapDisarmed = _data.GetBool(offset);
+                offset += 1;
                 return result;
             }
         }
@@ -681,6 +1069,8 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public byte Floor { get; set; }
+
             public static RoomPresence Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -712,26 +1102,80 @@ namespace Chaindepth
                 offset += 1;
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
+                result.Floor = _data.GetU8(offset);
+                offset += 1;
                 return result;
             }
         }
 
-        public partial class SessionAuthority
+        public partial class SeasonHistoryAccount
         {
-            public static ulong ACCOUNT_DISCRIMINATOR => 12298243742889806128UL;
-            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{48, 9, 30, 120, 134, 35, 172, 170};
-            public static string ACCOUNT_DISCRIMINATOR_B58 => "931LAeW67wX";
-            public PublicKey Player { get; set; }
+            public static ulong ACCOUNT_DISCRIMINATOR => 8871751069422177780UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{244, 205, 153, 212, 151, 202, 30, 123};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "hwu2WMsHUu8";
+            public ulong SeasonSeed { get; set; }
 
-            public PublicKey SessionKey { get; set; }
+            public ulong WorldBossSpawnedSlot { get; set; }
 
-            public ulong ExpiresAtSlot { get; set; }
+            public bool WorldBossDefeated { get; set; }
 
-            public long ExpiresAtUnixTimestamp { get; set; }
+            public ulong WorldBossDefeatedSlot { get; set; }
 
-            public ulong InstructionAllowlist { get; set; }
+            public uint WorldBossParticipants { get; set; }
 
-            public ulong MaxTokenSpend { get; set; }
+            public ulong WorldBossTotalDamage { get; set; }
+
+            public ulong WorldBossPrize { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static SeasonHistoryAccount Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                SeasonHistoryAccount result = new SeasonHistoryAccount();
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.WorldBossSpawnedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.WorldBossDefeated = _data.GetBool(offset);
+                offset += 1;
+                result.WorldBossDefeatedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.WorldBossParticipants = _data.GetU32(offset);
+                offset += 4;
+                result.WorldBossTotalDamage = _data.GetU64(offset);
+                offset += 8;
+                result.WorldBossPrize = _data.GetU64(offset);
+                offset += 8;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
+        public partial class SessionAuthority
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 12298243742889806128UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{48, 9, 30, 120, 134, 35, 172, 170};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "931LAeW67wX";
+            public PublicKey Player { get; set; }
+
+            public PublicKey SessionKey { get; set; }
+
+            public ulong ExpiresAtSlot { get; set; }
+
+            public long ExpiresAtUnixTimestamp { get; set; }
+
+            public ulong InstructionAllowlist { get; set; }
+
+            public ulong MaxTokenSpend { get; set; }
 
             public ulong SpentTokenAmount { get; set; }
 
@@ -772,6 +1216,100 @@ namespace Chaindepth
             }
         }
 
+        public partial class ShopConfig
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 11204253662193389740UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{172, 16, 104, 212, 87, 129, 125, 155};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "VnEmHV5Xnpr";
+            public ulong SeasonSeed { get; set; }
+
+            public bool BurnPayments { get; set; }
+
+            public ShopListing[] Listings { get; set; }
+
+            public ShopSellPrice[] SellPrices { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static ShopConfig Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                ShopConfig result = new ShopConfig();
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.BurnPayments = _data.GetBool(offset);
+                offset += 1;
+                int resultListingsLength = (int)_data.GetU32(offset);
+                offset += 4;
+                result.Listings = new ShopListing[resultListingsLength];
+                for (uint resultListingsIdx = 0; resultListingsIdx < resultListingsLength; resultListingsIdx++)
+                {
+                    offset += ShopListing.Deserialize(_data, offset, out var resultListingsresultListingsIdx);
+                    result.Listings[resultListingsIdx] = resultListingsresultListingsIdx;
+                }
+
+                int resultSellPricesLength = (int)_data.GetU32(offset);
+                offset += 4;
+                result.SellPrices = new ShopSellPrice[resultSellPricesLength];
+                for (uint resultSellPricesIdx = 0; resultSellPricesIdx < resultSellPricesLength; resultSellPricesIdx++)
+                {
+                    offset += ShopSellPrice.Deserialize(_data, offset, out var resultSellPricesresultSellPricesIdx);
+                    result.SellPrices[resultSellPricesIdx] = resultSellPricesresultSellPricesIdx;
+                }
+
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
+        public partial class ShrineReceipt
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 7998616287312538529UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{161, 235, 234, 131, 34, 203, 0, 111};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "U5qpwkLtriz";
+            public PublicKey Player { get; set; }
+
+            public ulong SeasonSeed { get; set; }
+
+            public sbyte RoomX { get; set; }
+
+            public sbyte RoomY { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static ShrineReceipt Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                ShrineReceipt result = new ShrineReceipt();
+                result.Player = _data.GetPubKey(offset);
+                offset += 32;
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.RoomX = _data.GetS8(offset);
+                offset += 1;
+                result.RoomY = _data.GetS8(offset);
+                offset += 1;
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                return result;
+            }
+        }
+
         public partial class StorageAccount
         {
             public static ulong ACCOUNT_DISCRIMINATOR => 16991321729293299753UL;
@@ -783,6 +1321,8 @@ namespace Chaindepth
 
             public byte Bump { get; set; }
 
+            public ushort BonusSlots { get; set; }
+
             public static StorageAccount Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -805,6 +1345,92 @@ namespace Chaindepth
                     result.Items[resultItemsIdx] = resultItemsresultItemsIdx;
                 }
 
+                result.Bump = _data.GetU8(offset);
+                offset += 1;
+                result.BonusSlots = _data.GetU16(offset);
+                offset += 2;
+                return result;
+            }
+        }
+
+        public partial class WorldBossAccount
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 6918751622928751107UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{3, 90, 211, 165, 241, 87, 4, 96};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "ZYegeAgHh9";
+            public ulong SeasonSeed { get; set; }
+
+            public ushort BossId { get; set; }
+
+            public ulong MaxHp { get; set; }
+
+            public ulong CurrentHp { get; set; }
+
+            public ulong LastUpdateSlot { get; set; }
+
+            public ulong TotalDps { get; set; }
+
+            public uint FighterCount { get; set; }
+
+            public uint ParticipantCount { get; set; }
+
+            public ulong TotalDamage { get; set; }
+
+            public PublicKey SpawnedBy { get; set; }
+
+            public ulong SpawnedSlot { get; set; }
+
+            public bool Defeated { get; set; }
+
+            public ulong DefeatedSlot { get; set; }
+
+            public ulong PrizeAmount { get; set; }
+
+            public ulong PrizePaid { get; set; }
+
+            public byte Bump { get; set; }
+
+            public static WorldBossAccount Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                WorldBossAccount result = new WorldBossAccount();
+                result.SeasonSeed = _data.GetU64(offset);
+                offset += 8;
+                result.BossId = _data.GetU16(offset);
+                offset += 2;
+                result.MaxHp = _data.GetU64(offset);
+                offset += 8;
+                result.CurrentHp = _data.GetU64(offset);
+                offset += 8;
+                result.LastUpdateSlot = _data.GetU64(offset);
+                offset += 8;
+                result.TotalDps = _data.GetU64(offset);
+                offset += 8;
+                result.FighterCount = _data.GetU32(offset);
+                offset += 4;
+                result.ParticipantCount = _data.GetU32(offset);
+                offset += 4;
+                result.TotalDamage = _data.GetU64(offset);
+                offset += 8;
+                result.SpawnedBy = _data.GetPubKey(offset);
+                offset += 32;
+                result.SpawnedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.Defeated = _data.GetBool(offset);
+                offset += 1;
+                result.DefeatedSlot = _data.GetU64(offset);
+                offset += 8;
+                result.PrizeAmount = _data.GetU64(offset);
+                offset += 8;
+                result.PrizePaid = _data.GetU64(offset);
+                offset += 8;
                 result.Bump = _data.GetU8(offset);
                 offset += 1;
                 return result;
@@ -871,7 +1497,58 @@ namespace Chaindepth
             InvalidDuelEscrow = 6052U,
             DuelChallengeExpired = 6053U,
             DuelChallengeNotExpired = 6054U,
-            NotInDungeon = 6055U
+            NotInDungeon = 6055U,
+            UnsupportedDataVersion = 6056U,
+            PlayerInDungeon = 6057U,
+            ItemNotWithdrawable = 6058U,
+            ShopItemNotListed = 6059U,
+            ShopOutOfStock = 6060U,
+            ShopItemNotSellable = 6061U,
+            ShopConfigFull = 6062U,
+            PrizePoolInsufficientFunds = 6063U,
+            ItemNotExportable = 6064U,
+            ExportedItemMismatch = 6065U,
+            CapacityUpgradeLimit = 6066U,
+            LevelTooLow = 6067U,
+            OverEncumbered = 6068U,
+            ItemNotUsable = 6069U,
+            MissingItemAccounts = 6070U,
+            ItemBlockedByActiveJobs = 6071U,
+            InvalidScoreModifier = 6072U,
+            NoArmedTrap = 6073U,
+            NoShrine = 6074U,
+            ShrineAlreadyUsed = 6075U,
+            AlreadyAtFullHp = 6076U,
+            InvalidTribute = 6077U,
+            NoMerchant = 6078U,
+            InvalidMerchantOffer = 6079U,
+            MerchantLimitReached = 6080U,
+            InvalidMerchantPayment = 6081U,
+            PartnerRequired = 6082U,
+            NoStairsDown = 6083U,
+            NoStairsUp = 6084U,
+            FloorChangeBlockedByActiveJobs = 6085U,
+            InvalidDungeonGrid = 6086U,
+            WorldBossNotReady = 6087U,
+            NotAtWorldBossArena = 6088U,
+            LeaveWorldBossBeforeClaiming = 6089U,
+            WorldBossRewardClaimed = 6090U,
+            NotInAdminSet = 6091U,
+            AdminActionAlreadyApproved = 6092U,
+            AdminActionTimelocked = 6093U,
+            AdminActionNeedsApprovals = 6094U,
+            AdminActionRequiresProposal = 6095U,
+            InvalidAdminSet = 6096U,
+            SubsystemPaused = 6097U,
+            InvalidPauseFlags = 6098U,
+            InvalidRole = 6099U,
+            NotPendingRoleHolder = 6100U,
+            InvalidFundingAmount = 6101U,
+            ShopItemNotBuyable = 6102U,
+            WorldBossPresenceRequired = 6103U,
+            PlayerAccountMigrationRequired = 6104U,
+            GrantedItemNotExportable = 6105U,
+            ShopConfigRequired = 6106U
         }
     }
 
@@ -915,903 +1592,1236 @@ namespace Chaindepth
             }
         }
 
-        public partial class InventoryItem
+        public partial class SetDeepExitScoreType
         {
-            public ushort ItemId { get; set; }
-
-            public uint Amount { get; set; }
-
-            public ushort Durability { get; set; }
+            public ushort ScoreBps { get; set; }
 
             public int Serialize(byte[] _data, int initialOffset)
             {
                 int offset = initialOffset;
-                _data.WriteU16(ItemId, offset);
-                offset += 2;
-                _data.WriteU32(Amount, offset);
-                offset += 4;
-                _data.WriteU16(Durability, offset);
+                _data.WriteU16(ScoreBps, offset);
                 offset += 2;
                 return offset - initialOffset;
             }
 
-            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out InventoryItem result)
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetDeepExitScoreType result)
             {
                 int offset = initialOffset;
-                result = new InventoryItem();
-                result.ItemId = _data.GetU16(offset);
-                offset += 2;
-                result.Amount = _data.GetU32(offset);
-                offset += 4;
-                result.Durability = _data.GetU16(offset);
+                result = new SetDeepExitScoreType();
+                result.ScoreBps = _data.GetU16(offset);
                 offset += 2;
                 return offset - initialOffset;
             }
         }
-    }
 
-    public partial class ChaindepthClient : TransactionalBaseClient<ChaindepthErrorKind>
-    {
-        public ChaindepthClient(IRpcClient rpcClient, IStreamingRpcClient streamingRpcClient, PublicKey programId = null) : base(rpcClient, streamingRpcClient, programId ?? new PublicKey(ChaindepthProgram.ID))
+        public partial class SetSeasonGridType
         {
-        }
+            public DungeonGrid Grid { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<BossFightAccount>>> GetBossFightAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = BossFightAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<BossFightAccount>>(res);
-            List<BossFightAccount> resultingAccounts = new List<BossFightAccount>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => BossFightAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<BossFightAccount>>(res, resultingAccounts);
-        }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                offset += Grid.Serialize(_data, offset);
+                return offset - initialOffset;
+            }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DuelChallenge>>> GetDuelChallengesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = DuelChallenge.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DuelChallenge>>(res);
-            List<DuelChallenge> resultingAccounts = new List<DuelChallenge>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => DuelChallenge.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DuelChallenge>>(res, resultingAccounts);
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetSeasonGridType result)
+            {
+                int offset = initialOffset;
+                result = new SetSeasonGridType();
+                offset += DungeonGrid.Deserialize(_data, offset, out var resultGrid);
+                result.Grid = resultGrid;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GlobalAccount>>> GetGlobalAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        public partial class SetAdminSetType
         {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = GlobalAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GlobalAccount>>(res);
-            List<GlobalAccount> resultingAccounts = new List<GlobalAccount>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => GlobalAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GlobalAccount>>(res, resultingAccounts);
-        }
+            public PublicKey[] Admins { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<HelperStake>>> GetHelperStakesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = HelperStake.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<HelperStake>>(res);
-            List<HelperStake> resultingAccounts = new List<HelperStake>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => HelperStake.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<HelperStake>>(res, resultingAccounts);
-        }
+            public byte AdminCount { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<InventoryAccount>>> GetInventoryAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = InventoryAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<InventoryAccount>>(res);
-            List<InventoryAccount> resultingAccounts = new List<InventoryAccount>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => InventoryAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<InventoryAccount>>(res, resultingAccounts);
-        }
+            public byte Threshold { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<LootReceipt>>> GetLootReceiptsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = LootReceipt.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<LootReceipt>>(res);
-            List<LootReceipt> resultingAccounts = new List<LootReceipt>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => LootReceipt.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<LootReceipt>>(res, resultingAccounts);
-        }
+            public ulong TimelockSlots { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerAccount>>> GetPlayerAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PlayerAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerAccount>>(res);
-            List<PlayerAccount> resultingAccounts = new List<PlayerAccount>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => PlayerAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerAccount>>(res, resultingAccounts);
-        }
-
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>> GetPlayerProfilesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PlayerProfile.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>(res);
-            List<PlayerProfile> resultingAccounts = new List<PlayerProfile>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => PlayerProfile.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>(res, resultingAccounts);
-        }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                foreach (var adminsElement in Admins)
+                {
+                    _data.WritePubKey(adminsElement, offset);
+                    offset += 32;
+                }
+                _data.WriteU8(AdminCount, offset);
+                offset += 1;
+                _data.WriteU8(Threshold, offset);
+                offset += 1;
+                _data.WriteU64(TimelockSlots, offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomAccount>>> GetRoomAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = RoomAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomAccount>>(res);
-            List<RoomAccount> resultingAccounts = new List<RoomAccount>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => RoomAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomAccount>>(res, resultingAccounts);
-        }
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetAdminSetType result)
+            {
+                int offset = initialOffset;
+                result = new SetAdminSetType();
+                result.Admins = new PublicKey[5];
+                for (uint resultAdminsIdx = 0; resultAdminsIdx < 5; resultAdminsIdx++)
+                {
+                    result.Admins[resultAdminsIdx] = _data.GetPubKey(offset);
+                    offset += 32;
+                }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomPresence>>> GetRoomPresencesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = RoomPresence.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomPresence>>(res);
-            List<RoomPresence> resultingAccounts = new List<RoomPresence>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => RoomPresence.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomPresence>>(res, resultingAccounts);
+                result.AdminCount = _data.GetU8(offset);
+                offset += 1;
+                result.Threshold = _data.GetU8(offset);
+                offset += 1;
+                result.TimelockSlots = _data.GetU64(offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SessionAuthority>>> GetSessionAuthoritysAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        public partial class SetRoleType
         {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = SessionAuthority.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SessionAuthority>>(res);
-            List<SessionAuthority> resultingAccounts = new List<SessionAuthority>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => SessionAuthority.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SessionAuthority>>(res, resultingAccounts);
-        }
+            public byte Role { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<StorageAccount>>> GetStorageAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
-        {
-            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = StorageAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
-            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
-            if (!res.WasSuccessful || !(res.Result?.Count > 0))
-                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<StorageAccount>>(res);
-            List<StorageAccount> resultingAccounts = new List<StorageAccount>(res.Result.Count);
-            resultingAccounts.AddRange(res.Result.Select(result => StorageAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
-            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<StorageAccount>>(res, resultingAccounts);
-        }
+            public PublicKey Holder { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<BossFightAccount>> GetBossFightAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<BossFightAccount>(res);
-            var resultingAccount = BossFightAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<BossFightAccount>(res, resultingAccount);
-        }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU8(Role, offset);
+                offset += 1;
+                _data.WritePubKey(Holder, offset);
+                offset += 32;
+                return offset - initialOffset;
+            }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<DuelChallenge>> GetDuelChallengeAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<DuelChallenge>(res);
-            var resultingAccount = DuelChallenge.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<DuelChallenge>(res, resultingAccount);
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetRoleType result)
+            {
+                int offset = initialOffset;
+                result = new SetRoleType();
+                result.Role = _data.GetU8(offset);
+                offset += 1;
+                result.Holder = _data.GetPubKey(offset);
+                offset += 32;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<GlobalAccount>> GetGlobalAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        public partial class SetRentBudgetType
         {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<GlobalAccount>(res);
-            var resultingAccount = GlobalAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<GlobalAccount>(res, resultingAccount);
-        }
+            public ulong Lamports { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<HelperStake>> GetHelperStakeAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<HelperStake>(res);
-            var resultingAccount = HelperStake.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<HelperStake>(res, resultingAccount);
-        }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU64(Lamports, offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<InventoryAccount>> GetInventoryAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<InventoryAccount>(res);
-            var resultingAccount = InventoryAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<InventoryAccount>(res, resultingAccount);
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetRentBudgetType result)
+            {
+                int offset = initialOffset;
+                result = new SetRentBudgetType();
+                result.Lamports = _data.GetU64(offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<LootReceipt>> GetLootReceiptAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        public partial class ConfigureShopType
         {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<LootReceipt>(res);
-            var resultingAccount = LootReceipt.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<LootReceipt>(res, resultingAccount);
-        }
+            public bool BurnPayments { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PlayerAccount>> GetPlayerAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerAccount>(res);
-            var resultingAccount = PlayerAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerAccount>(res, resultingAccount);
-        }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteBool(BurnPayments, offset);
+                offset += 1;
+                return offset - initialOffset;
+            }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>> GetPlayerProfileAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>(res);
-            var resultingAccount = PlayerProfile.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>(res, resultingAccount);
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out ConfigureShopType result)
+            {
+                int offset = initialOffset;
+                result = new ConfigureShopType();
+                result.BurnPayments = _data.GetBool(offset);
+                offset += 1;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<RoomAccount>> GetRoomAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        public partial class SetShopListingType
         {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomAccount>(res);
-            var resultingAccount = RoomAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomAccount>(res, resultingAccount);
-        }
+            public ushort ItemId { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<RoomPresence>> GetRoomPresenceAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomPresence>(res);
-            var resultingAccount = RoomPresence.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomPresence>(res, resultingAccount);
-        }
+            public ushort Durability { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<SessionAuthority>> GetSessionAuthorityAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<SessionAuthority>(res);
-            var resultingAccount = SessionAuthority.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<SessionAuthority>(res, resultingAccount);
-        }
+            public ulong Price { get; set; }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<StorageAccount>> GetStorageAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
-        {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<StorageAccount>(res);
-            var resultingAccount = StorageAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<StorageAccount>(res, resultingAccount);
-        }
+            public uint StockPerSeason { get; set; }
 
-        public async Task<SubscriptionState> SubscribeBossFightAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, BossFightAccount> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            public int Serialize(byte[] _data, int initialOffset)
             {
-                BossFightAccount parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = BossFightAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+                int offset = initialOffset;
+                _data.WriteU16(ItemId, offset);
+                offset += 2;
+                _data.WriteU16(Durability, offset);
+                offset += 2;
+                _data.WriteU64(Price, offset);
+                offset += 8;
+                _data.WriteU32(StockPerSeason, offset);
+                offset += 4;
+                return offset - initialOffset;
+            }
 
-        public async Task<SubscriptionState> SubscribeDuelChallengeAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, DuelChallenge> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetShopListingType result)
             {
-                DuelChallenge parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = DuelChallenge.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
+                int offset = initialOffset;
+                result = new SetShopListingType();
+                result.ItemId = _data.GetU16(offset);
+                offset += 2;
+                result.Durability = _data.GetU16(offset);
+                offset += 2;
+                result.Price = _data.GetU64(offset);
+                offset += 8;
+                result.StockPerSeason = _data.GetU32(offset);
+                offset += 4;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<SubscriptionState> SubscribeGlobalAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, GlobalAccount> callback, Commitment commitment = Commitment.Finalized)
+        public partial class SetValuableSellPriceType
         {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
-            {
-                GlobalAccount parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = GlobalAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+            public ushort ItemId { get; set; }
 
-        public async Task<SubscriptionState> SubscribeHelperStakeAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, HelperStake> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            public ulong Price { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
             {
-                HelperStake parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = HelperStake.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+                int offset = initialOffset;
+                _data.WriteU16(ItemId, offset);
+                offset += 2;
+                _data.WriteU64(Price, offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
 
-        public async Task<SubscriptionState> SubscribeInventoryAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, InventoryAccount> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SetValuableSellPriceType result)
             {
-                InventoryAccount parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = InventoryAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
+                int offset = initialOffset;
+                result = new SetValuableSellPriceType();
+                result.ItemId = _data.GetU16(offset);
+                offset += 2;
+                result.Price = _data.GetU64(offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
         }
 
-        public async Task<SubscriptionState> SubscribeLootReceiptAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, LootReceipt> callback, Commitment commitment = Commitment.Finalized)
+        public partial class AdminAction
         {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
-            {
-                LootReceipt parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = LootReceipt.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+            public SetDeepExitScoreType SetDeepExitScoreValue { get; set; }
 
-        public async Task<SubscriptionState> SubscribePlayerAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PlayerAccount> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
-            {
-                PlayerAccount parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = PlayerAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+            public SetSeasonGridType SetSeasonGridValue { get; set; }
 
-        public async Task<SubscriptionState> SubscribePlayerProfileAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PlayerProfile> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
-            {
-                PlayerProfile parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = PlayerProfile.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+            public SetAdminSetType SetAdminSetValue { get; set; }
 
-        public async Task<SubscriptionState> SubscribeRoomAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, RoomAccount> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
-            {
-                RoomAccount parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = RoomAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+            public SetRoleType SetRoleValue { get; set; }
 
-        public async Task<SubscriptionState> SubscribeRoomPresenceAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, RoomPresence> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
-            {
-                RoomPresence parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = RoomPresence.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+            public SetRentBudgetType SetRentBudgetValue { get; set; }
 
-        public async Task<SubscriptionState> SubscribeSessionAuthorityAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, SessionAuthority> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            public ConfigureShopType ConfigureShopValue { get; set; }
+
+            public SetShopListingType SetShopListingValue { get; set; }
+
+            public SetValuableSellPriceType SetValuableSellPriceValue { get; set; }
+
+            public AdminActionType Type { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
             {
-                SessionAuthority parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = SessionAuthority.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
-        }
+                int offset = initialOffset;
+                _data.WriteU8((byte)Type, offset);
+                offset += 1;
+                switch (Type)
+                {
+                    case AdminActionType.SetDeepExitScore:
+                        offset += SetDeepExitScoreValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.SetSeasonGrid:
+                        offset += SetSeasonGridValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.SetAdminSet:
+                        offset += SetAdminSetValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.SetRole:
+                        offset += SetRoleValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.SetRentBudget:
+                        offset += SetRentBudgetValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.ConfigureShop:
+                        offset += ConfigureShopValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.SetShopListing:
+                        offset += SetShopListingValue.Serialize(_data, offset);
+                        break;
+                    case AdminActionType.SetValuableSellPrice:
+                        offset += SetValuableSellPriceValue.Serialize(_data, offset);
+                        break;
+                }
 
-        public async Task<SubscriptionState> SubscribeStorageAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, StorageAccount> callback, Commitment commitment = Commitment.Finalized)
-        {
-            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out AdminAction result)
             {
-                StorageAccount parsingResult = null;
-                if (e.Value?.Data?.Count > 0)
-                    parsingResult = StorageAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
-                callback(s, e, parsingResult);
-            }, commitment);
-            return res;
+                int offset = initialOffset;
+                result = new AdminAction();
+                result.Type = (AdminActionType)_data.GetU8(offset);
+                offset += 1;
+                switch (result.Type)
+                {
+                    case AdminActionType.SetDeepExitScore:
+                    {
+                        offset += SetDeepExitScoreType.Deserialize(_data, offset, out var tmpSetDeepExitScoreValue);
+                        result.SetDeepExitScoreValue = tmpSetDeepExitScoreValue;
+                        break;
+                    }
+                    case AdminActionType.SetSeasonGrid:
+                    {
+                        offset += SetSeasonGridType.Deserialize(_data, offset, out var tmpSetSeasonGridValue);
+                        result.SetSeasonGridValue = tmpSetSeasonGridValue;
+                        break;
+                    }
+                    case AdminActionType.SetAdminSet:
+                    {
+                        offset += SetAdminSetType.Deserialize(_data, offset, out var tmpSetAdminSetValue);
+                        result.SetAdminSetValue = tmpSetAdminSetValue;
+                        break;
+                    }
+                    case AdminActionType.SetRole:
+                    {
+                        offset += SetRoleType.Deserialize(_data, offset, out var tmpSetRoleValue);
+                        result.SetRoleValue = tmpSetRoleValue;
+                        break;
+                    }
+                    case AdminActionType.SetRentBudget:
+                    {
+                        offset += SetRentBudgetType.Deserialize(_data, offset, out var tmpSetRentBudgetValue);
+                        result.SetRentBudgetValue = tmpSetRentBudgetValue;
+                        break;
+                    }
+                    case AdminActionType.ConfigureShop:
+                    {
+                        offset += ConfigureShopType.Deserialize(_data, offset, out var tmpConfigureShopValue);
+                        result.ConfigureShopValue = tmpConfigureShopValue;
+                        break;
+                    }
+                    case AdminActionType.SetShopListing:
+                    {
+                        offset += SetShopListingType.Deserialize(_data, offset, out var tmpSetShopListingValue);
+                        result.SetShopListingValue = tmpSetShopListingValue;
+                        break;
+                    }
+                    case AdminActionType.SetValuableSellPrice:
+                    {
+                        offset += SetValuableSellPriceType.Deserialize(_data, offset, out var tmpSetValuableSellPriceValue);
+                        result.SetValuableSellPriceValue = tmpSetValuableSellPriceValue;
+                        break;
+                    }
+                }
+
+                return offset - initialOffset;
+            }
         }
 
-        protected override Dictionary<uint, ProgramError<ChaindepthErrorKind>> BuildErrorsDictionary()
+        public enum AdminActionType : byte
         {
-            return new Dictionary<uint, ProgramError<ChaindepthErrorKind>>{{6000U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotAdjacent, "Invalid move: target room is not adjacent")}, {6001U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WallNotOpen, "Invalid move: wall is not open")}, {6002U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WallNotLocked, "Wall is not locked")}, {6003U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.OutOfBounds, "Invalid move: coordinates out of bounds")}, {6004U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDirection, "Invalid direction: must be 0-3 (N/S/E/W)")}, {6005U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotRubble, "Wall is not rubble: cannot start job")}, {6006U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyJoined, "Already joined this job")}, {6007U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobFull, "Job is full: maximum helpers reached")}, {6008U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotHelper, "Not a helper on this job")}, {6009U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobNotReady, "Job not ready: progress insufficient")}, {6010U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoActiveJob, "No active job at this location")}, {6011U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobAlreadyCompleted, "Job has already been completed")}, {6012U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobNotCompleted, "Job has not been completed yet")}, {6013U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.TooManyActiveJobs, "Too many active jobs: abandon one first")}, {6014U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InventoryFull, "Inventory is full")}, {6015U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidItemId, "Invalid item id")}, {6016U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidItemAmount, "Invalid item amount")}, {6017U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InsufficientItemAmount, "Not enough items")}, {6018U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.MissingRequiredKey, "Missing required key item")}, {6019U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidLockKind, "Invalid lock kind")}, {6020U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoChest, "Room has no chest")}, {6021U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyLooted, "Already looted this chest")}, {6022U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.TreasuryInsufficientFunds, "Treasury has insufficient SOL to reimburse room rent")}, {6023U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotInRoom, "Player not in this room")}, {6024U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotAtEntranceRoom, "Player is not at the entrance room")}, {6025U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.EntranceStairsRequired, "Entrance stairs are required to exit")}, {6026U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.CannotExitWithActiveJobs, "Cannot exit while having active jobs")}, {6027U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoBoss, "No boss in this room center")}, {6028U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.BossAlreadyDefeated, "Boss is already defeated")}, {6029U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.BossNotDefeated, "Boss has not been defeated yet")}, {6030U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyFightingBoss, "Player is already fighting this boss")}, {6031U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotBossFighter, "Player is not a fighter for this boss")}, {6032U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PlayerDead, "Player has no HP and must recover before fighting")}, {6033U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidCenterType, "Invalid center type")}, {6034U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.DisplayNameTooLong, "Display name is too long")}, {6035U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidSessionExpiry, "Invalid session expiry values")}, {6036U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidSessionAllowlist, "Session instruction allowlist cannot be empty")}, {6037U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionExpired, "Session has expired")}, {6038U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionInactive, "Session is inactive")}, {6039U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionInstructionNotAllowed, "Instruction is not allowed by session policy")}, {6040U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionSpendCapExceeded, "Session spend cap exceeded")}, {6041U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SeasonNotEnded, "Season has not ended yet")}, {6042U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.Unauthorized, "Unauthorized: only admin can perform this action")}, {6043U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InsufficientBalance, "Insufficient balance for stake")}, {6044U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.TransferFailed, "Token transfer failed")}, {6045U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.Overflow, "Arithmetic overflow")}, {6046U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidSeason, "Invalid season for this operation")}, {6047U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelOpponent, "Invalid duel opponent")}, {6048U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelStake, "Invalid duel stake")}, {6049U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelExpiry, "Invalid duel expiry")}, {6050U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PlayersNotInSameRoom, "Players must be in the same room to duel")}, {6051U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelState, "Invalid duel state transition")}, {6052U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelEscrow, "Invalid duel escrow account")}, {6053U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.DuelChallengeExpired, "Duel challenge has already expired")}, {6054U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.DuelChallengeNotExpired, "Duel challenge has not yet expired")}, {6055U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotInDungeon, "Player is not currently in the dungeon")}, };
+            ForceResetSeason,
+            SetDeepExitScore,
+            SetSeasonGrid,
+            SetAdminSet,
+            SetRole,
+            SetRentBudget,
+            ConfigureShop,
+            SetShopListing,
+            SetValuableSellPrice
         }
-    }
 
-    namespace Program
-    {
-        public class AbandonJobAccounts
+        public partial class DungeonGrid
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
+            public byte Width { get; set; }
 
-            public PublicKey Global { get; set; }
+            public byte Height { get; set; }
 
-            public PublicKey PlayerAccount { get; set; }
+            public sbyte StartX { get; set; }
 
-            public PublicKey Room { get; set; }
+            public sbyte StartY { get; set; }
 
-            public PublicKey RoomPresence { get; set; }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU8(Width, offset);
+                offset += 1;
+                _data.WriteU8(Height, offset);
+                offset += 1;
+                _data.WriteS8(StartX, offset);
+                offset += 1;
+                _data.WriteS8(StartY, offset);
+                offset += 1;
+                return offset - initialOffset;
+            }
 
-            public PublicKey Escrow { get; set; }
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out DungeonGrid result)
+            {
+                int offset = initialOffset;
+                result = new DungeonGrid();
+                result.Width = _data.GetU8(offset);
+                offset += 1;
+                result.Height = _data.GetU8(offset);
+                offset += 1;
+                result.StartX = _data.GetS8(offset);
+                offset += 1;
+                result.StartY = _data.GetS8(offset);
+                offset += 1;
+                return offset - initialOffset;
+            }
+        }
 
-            public PublicKey HelperStake { get; set; }
+        public partial class InventoryItem
+        {
+            public ushort ItemId { get; set; }
 
-            public PublicKey PrizePool { get; set; }
+            public uint Amount { get; set; }
 
-            public PublicKey PlayerTokenAccount { get; set; }
+            public ushort Durability { get; set; }
 
-            public PublicKey SessionAuthority { get; set; }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU16(ItemId, offset);
+                offset += 2;
+                _data.WriteU32(Amount, offset);
+                offset += 4;
+                _data.WriteU16(Durability, offset);
+                offset += 2;
+                return offset - initialOffset;
+            }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out InventoryItem result)
+            {
+                int offset = initialOffset;
+                result = new InventoryItem();
+                result.ItemId = _data.GetU16(offset);
+                offset += 2;
+                result.Amount = _data.GetU32(offset);
+                offset += 4;
+                result.Durability = _data.GetU16(offset);
+                offset += 2;
+                return offset - initialOffset;
+            }
         }
 
-        public class AcceptDuelChallengeAccounts
+        public partial class ShopListing
         {
-            public PublicKey Opponent { get; set; }
-
-            public PublicKey Challenger { get; set; }
+            public ushort ItemId { get; set; }
 
-            public PublicKey Global { get; set; }
+            public ushort Durability { get; set; }
 
-            public PublicKey DuelChallenge { get; set; }
+            public ulong Price { get; set; }
 
-            public PublicKey DuelEscrow { get; set; }
+            public uint StockPerSeason { get; set; }
 
-            public PublicKey ChallengerPlayerAccount { get; set; }
+            public uint SoldThisSeason { get; set; }
 
-            public PublicKey OpponentPlayerAccount { get; set; }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU16(ItemId, offset);
+                offset += 2;
+                _data.WriteU16(Durability, offset);
+                offset += 2;
+                _data.WriteU64(Price, offset);
+                offset += 8;
+                _data.WriteU32(StockPerSeason, offset);
+                offset += 4;
+                _data.WriteU32(SoldThisSeason, offset);
+                offset += 4;
+                return offset - initialOffset;
+            }
 
-            public PublicKey ChallengerTokenAccount { get; set; }
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out ShopListing result)
+            {
+                int offset = initialOffset;
+                result = new ShopListing();
+                result.ItemId = _data.GetU16(offset);
+                offset += 2;
+                result.Durability = _data.GetU16(offset);
+                offset += 2;
+                result.Price = _data.GetU64(offset);
+                offset += 8;
+                result.StockPerSeason = _data.GetU32(offset);
+                offset += 4;
+                result.SoldThisSeason = _data.GetU32(offset);
+                offset += 4;
+                return offset - initialOffset;
+            }
+        }
 
-            public PublicKey OpponentTokenAccount { get; set; }
+        public partial class ShopSellPrice
+        {
+            public ushort ItemId { get; set; }
 
-            public PublicKey DevTreasuryTokenAccount { get; set; }
+            public ulong Price { get; set; }
 
-            public PublicKey OracleQueue { get; set; } = new PublicKey("Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh");
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey ProgramIdentity { get; set; }
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU16(ItemId, offset);
+                offset += 2;
+                _data.WriteU64(Price, offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
 
-            public PublicKey VrfProgram { get; set; } = new PublicKey("Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz");
-            public PublicKey SlotHashes { get; set; } = new PublicKey("SysvarS1otHashes111111111111111111111111111");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out ShopSellPrice result)
+            {
+                int offset = initialOffset;
+                result = new ShopSellPrice();
+                result.ItemId = _data.GetU16(offset);
+                offset += 2;
+                result.Price = _data.GetU64(offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
         }
+    }
 
-        public class AddInventoryItemAccounts
+    public partial class ChaindepthClient : TransactionalBaseClient<ChaindepthErrorKind>
+    {
+        public ChaindepthClient(IRpcClient rpcClient, IStreamingRpcClient streamingRpcClient, PublicKey programId = null) : base(rpcClient, streamingRpcClient, programId ?? new PublicKey(ChaindepthProgram.ID))
         {
-            public PublicKey Player { get; set; }
-
-            public PublicKey Inventory { get; set; }
-
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class BeginSessionAccounts
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<BossFightAccount>>> GetBossFightAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
         {
-            public PublicKey Player { get; set; }
-
-            public PublicKey SessionKey { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerTokenAccount { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = BossFightAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<BossFightAccount>>(res);
+            List<BossFightAccount> resultingAccounts = new List<BossFightAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => BossFightAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<BossFightAccount>>(res, resultingAccounts);
         }
 
-        public class BoostJobAccounts
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DiscoveryRoyaltyAccount>>> GetDiscoveryRoyaltyAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey Room { get; set; }
-
-            public PublicKey PrizePool { get; set; }
-
-            public PublicKey PlayerTokenAccount { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = DiscoveryRoyaltyAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DiscoveryRoyaltyAccount>>(res);
+            List<DiscoveryRoyaltyAccount> resultingAccounts = new List<DiscoveryRoyaltyAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => DiscoveryRoyaltyAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DiscoveryRoyaltyAccount>>(res, resultingAccounts);
         }
 
-        public class ClaimJobRewardAccounts
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DuelChallenge>>> GetDuelChallengesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Room { get; set; }
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = DuelChallenge.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DuelChallenge>>(res);
+            List<DuelChallenge> resultingAccounts = new List<DuelChallenge>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => DuelChallenge.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<DuelChallenge>>(res, resultingAccounts);
+        }
 
-            public PublicKey RoomPresence { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ExportedItem>>> GetExportedItemsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = ExportedItem.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ExportedItem>>(res);
+            List<ExportedItem> resultingAccounts = new List<ExportedItem>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => ExportedItem.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ExportedItem>>(res, resultingAccounts);
+        }
 
-            public PublicKey Escrow { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<FloorConnectivity>>> GetFloorConnectivitysAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = FloorConnectivity.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<FloorConnectivity>>(res);
+            List<FloorConnectivity> resultingAccounts = new List<FloorConnectivity>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => FloorConnectivity.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<FloorConnectivity>>(res, resultingAccounts);
+        }
 
-            public PublicKey HelperStake { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GlobalAccount>>> GetGlobalAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = GlobalAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GlobalAccount>>(res);
+            List<GlobalAccount> resultingAccounts = new List<GlobalAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => GlobalAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GlobalAccount>>(res, resultingAccounts);
+        }
 
-            public PublicKey PlayerTokenAccount { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GrantedItems>>> GetGrantedItemssAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = GrantedItems.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GrantedItems>>(res);
+            List<GrantedItems> resultingAccounts = new List<GrantedItems>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => GrantedItems.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<GrantedItems>>(res, resultingAccounts);
+        }
 
-            public PublicKey SessionAuthority { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<HelperStake>>> GetHelperStakesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = HelperStake.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<HelperStake>>(res);
+            List<HelperStake> resultingAccounts = new List<HelperStake>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => HelperStake.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<HelperStake>>(res, resultingAccounts);
+        }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<InventoryAccount>>> GetInventoryAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = InventoryAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<InventoryAccount>>(res);
+            List<InventoryAccount> resultingAccounts = new List<InventoryAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => InventoryAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<InventoryAccount>>(res, resultingAccounts);
         }
 
-        public class CompleteJobAccounts
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<LootReceipt>>> GetLootReceiptsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
         {
-            public PublicKey Authority { get; set; }
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = LootReceipt.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<LootReceipt>>(res);
+            List<LootReceipt> resultingAccounts = new List<LootReceipt>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => LootReceipt.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<LootReceipt>>(res, resultingAccounts);
+        }
 
-            public PublicKey Player { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<MerchantReceipt>>> GetMerchantReceiptsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = MerchantReceipt.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<MerchantReceipt>>(res);
+            List<MerchantReceipt> resultingAccounts = new List<MerchantReceipt>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => MerchantReceipt.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<MerchantReceipt>>(res, resultingAccounts);
+        }
 
-            public PublicKey Global { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PendingAdminAction>>> GetPendingAdminActionsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PendingAdminAction.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PendingAdminAction>>(res);
+            List<PendingAdminAction> resultingAccounts = new List<PendingAdminAction>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => PendingAdminAction.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PendingAdminAction>>(res, resultingAccounts);
+        }
 
-            public PublicKey PlayerAccount { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerAccount>>> GetPlayerAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PlayerAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerAccount>>(res);
+            List<PlayerAccount> resultingAccounts = new List<PlayerAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => PlayerAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerAccount>>(res, resultingAccounts);
+        }
 
-            public PublicKey Room { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>> GetPlayerProfilesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PlayerProfile.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>(res);
+            List<PlayerProfile> resultingAccounts = new List<PlayerProfile>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => PlayerProfile.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>(res, resultingAccounts);
+        }
 
-            public PublicKey HelperStake { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomAccount>>> GetRoomAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = RoomAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomAccount>>(res);
+            List<RoomAccount> resultingAccounts = new List<RoomAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => RoomAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomAccount>>(res, resultingAccounts);
+        }
 
-            public PublicKey AdjacentRoom { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomPresence>>> GetRoomPresencesAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = RoomPresence.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomPresence>>(res);
+            List<RoomPresence> resultingAccounts = new List<RoomPresence>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => RoomPresence.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<RoomPresence>>(res, resultingAccounts);
+        }
 
-            public PublicKey Escrow { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SeasonHistoryAccount>>> GetSeasonHistoryAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = SeasonHistoryAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SeasonHistoryAccount>>(res);
+            List<SeasonHistoryAccount> resultingAccounts = new List<SeasonHistoryAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => SeasonHistoryAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SeasonHistoryAccount>>(res, resultingAccounts);
+        }
 
-            public PublicKey PrizePool { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SessionAuthority>>> GetSessionAuthoritysAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = SessionAuthority.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SessionAuthority>>(res);
+            List<SessionAuthority> resultingAccounts = new List<SessionAuthority>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => SessionAuthority.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SessionAuthority>>(res, resultingAccounts);
+        }
 
-            public PublicKey SessionAuthority { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ShopConfig>>> GetShopConfigsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = ShopConfig.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ShopConfig>>(res);
+            List<ShopConfig> resultingAccounts = new List<ShopConfig>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => ShopConfig.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ShopConfig>>(res, resultingAccounts);
+        }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ShrineReceipt>>> GetShrineReceiptsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = ShrineReceipt.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ShrineReceipt>>(res);
+            List<ShrineReceipt> resultingAccounts = new List<ShrineReceipt>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => ShrineReceipt.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<ShrineReceipt>>(res, resultingAccounts);
         }
 
-        public class ConsumeDuelRandomnessAccounts
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<StorageAccount>>> GetStorageAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
         {
-            public PublicKey VrfProgramIdentity { get; set; } = new PublicKey("9irBy75QS2BN81FUgXuHcjqceJJRuc9oDkAe8TKVvvAw");
-            public PublicKey Global { get; set; }
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = StorageAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<StorageAccount>>(res);
+            List<StorageAccount> resultingAccounts = new List<StorageAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => StorageAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<StorageAccount>>(res, resultingAccounts);
+        }
 
-            public PublicKey DuelChallenge { get; set; }
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<WorldBossAccount>>> GetWorldBossAccountsAsync(string programAddress = ChaindepthProgram.ID, Commitment commitment = Commitment.Confirmed)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = WorldBossAccount.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<WorldBossAccount>>(res);
+            List<WorldBossAccount> resultingAccounts = new List<WorldBossAccount>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => WorldBossAccount.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<WorldBossAccount>>(res, resultingAccounts);
+        }
 
-            public PublicKey DuelEscrow { get; set; }
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<BossFightAccount>> GetBossFightAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<BossFightAccount>(res);
+            var resultingAccount = BossFightAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<BossFightAccount>(res, resultingAccount);
+        }
 
-            public PublicKey ChallengerTokenAccount { get; set; }
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<DiscoveryRoyaltyAccount>> GetDiscoveryRoyaltyAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<DiscoveryRoyaltyAccount>(res);
+            var resultingAccount = DiscoveryRoyaltyAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<DiscoveryRoyaltyAccount>(res, resultingAccount);
+        }
 
-            public PublicKey OpponentTokenAccount { get; set; }
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<DuelChallenge>> GetDuelChallengeAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<DuelChallenge>(res);
+            var resultingAccount = DuelChallenge.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<DuelChallenge>(res, resultingAccount);
+        }
 
-            public PublicKey DevTreasuryTokenAccount { get; set; }
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<ExportedItem>> GetExportedItemAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<ExportedItem>(res);
+            var resultingAccount = ExportedItem.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<ExportedItem>(res, resultingAccount);
+        }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<FloorConnectivity>> GetFloorConnectivityAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<FloorConnectivity>(res);
+            var resultingAccount = FloorConnectivity.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<FloorConnectivity>(res, resultingAccount);
         }
 
-        public class CreateDuelChallengeAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<GlobalAccount>> GetGlobalAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Challenger { get; set; }
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<GlobalAccount>(res);
+            var resultingAccount = GlobalAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<GlobalAccount>(res, resultingAccount);
+        }
 
-            public PublicKey Opponent { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey ChallengerPlayerAccount { get; set; }
-
-            public PublicKey OpponentPlayerAccount { get; set; }
-
-            public PublicKey ChallengerProfile { get; set; }
-
-            public PublicKey OpponentProfile { get; set; }
-
-            public PublicKey DuelChallenge { get; set; }
-
-            public PublicKey DuelEscrow { get; set; }
-
-            public PublicKey ChallengerTokenAccount { get; set; }
-
-            public PublicKey SkrMint { get; set; }
-
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<GrantedItems>> GetGrantedItemsAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<GrantedItems>(res);
+            var resultingAccount = GrantedItems.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<GrantedItems>(res, resultingAccount);
         }
 
-        public class CreatePlayerProfileAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<HelperStake>> GetHelperStakeAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Profile { get; set; }
-
-            public PublicKey Inventory { get; set; }
-
-            public PublicKey RoomPresence { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<HelperStake>(res);
+            var resultingAccount = HelperStake.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<HelperStake>(res, resultingAccount);
         }
 
-        public class DeclineDuelChallengeAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<InventoryAccount>> GetInventoryAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Opponent { get; set; }
-
-            public PublicKey Challenger { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey DuelChallenge { get; set; }
-
-            public PublicKey DuelEscrow { get; set; }
-
-            public PublicKey ChallengerTokenAccount { get; set; }
-
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<InventoryAccount>(res);
+            var resultingAccount = InventoryAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<InventoryAccount>(res, resultingAccount);
         }
 
-        public class EndSessionAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<LootReceipt>> GetLootReceiptAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Player { get; set; }
-
-            public PublicKey SessionKey { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerTokenAccount { get; set; }
-
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<LootReceipt>(res);
+            var resultingAccount = LootReceipt.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<LootReceipt>(res, resultingAccount);
         }
 
-        public class EnsureStartRoomAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<MerchantReceipt>> GetMerchantReceiptAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey StartRoom { get; set; }
-
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<MerchantReceipt>(res);
+            var resultingAccount = MerchantReceipt.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<MerchantReceipt>(res, resultingAccount);
         }
 
-        public class EnterDungeonAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PendingAdminAction>> GetPendingAdminActionAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Profile { get; set; }
-
-            public PublicKey StartRoom { get; set; }
-
-            public PublicKey RoomPresence { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<PendingAdminAction>(res);
+            var resultingAccount = PendingAdminAction.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<PendingAdminAction>(res, resultingAccount);
         }
 
-        public class EquipItemAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PlayerAccount>> GetPlayerAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Inventory { get; set; }
-
-            public PublicKey RoomPresence { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerAccount>(res);
+            var resultingAccount = PlayerAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerAccount>(res, resultingAccount);
         }
 
-        public class ExitDungeonAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>> GetPlayerProfileAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Room { get; set; }
-
-            public PublicKey Inventory { get; set; }
-
-            public PublicKey Storage { get; set; }
-
-            public PublicKey RoomPresence { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>(res);
+            var resultingAccount = PlayerProfile.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>(res, resultingAccount);
         }
 
-        public class ExpireDuelChallengeAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<RoomAccount>> GetRoomAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Challenger { get; set; }
-
-            public PublicKey Opponent { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey DuelChallenge { get; set; }
-
-            public PublicKey DuelEscrow { get; set; }
-
-            public PublicKey ChallengerTokenAccount { get; set; }
-
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomAccount>(res);
+            var resultingAccount = RoomAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomAccount>(res, resultingAccount);
         }
 
-        public class ForceExitOnDeathAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<RoomPresence>> GetRoomPresenceAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Player { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Room { get; set; }
-
-            public PublicKey Inventory { get; set; }
-
-            public PublicKey RoomPresence { get; set; }
-
-            public PublicKey SessionAuthority { get; set; }
-
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomPresence>(res);
+            var resultingAccount = RoomPresence.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<RoomPresence>(res, resultingAccount);
         }
 
-        public class ForceResetSeasonAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<SeasonHistoryAccount>> GetSeasonHistoryAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
-
-            public PublicKey Global { get; set; }
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<SeasonHistoryAccount>(res);
+            var resultingAccount = SeasonHistoryAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<SeasonHistoryAccount>(res, resultingAccount);
         }
 
-        public class InitGlobalAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<SessionAuthority>> GetSessionAuthorityAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Admin { get; set; }
-
-            public PublicKey Global { get; set; }
-
-            public PublicKey SkrMint { get; set; }
-
-            public PublicKey PrizePool { get; set; }
-
-            public PublicKey AdminTokenAccount { get; set; }
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<SessionAuthority>(res);
+            var resultingAccount = SessionAuthority.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<SessionAuthority>(res, resultingAccount);
+        }
 
-            public PublicKey StartRoom { get; set; }
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<ShopConfig>> GetShopConfigAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<ShopConfig>(res);
+            var resultingAccount = ShopConfig.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<ShopConfig>(res, resultingAccount);
+        }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<ShrineReceipt>> GetShrineReceiptAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<ShrineReceipt>(res);
+            var resultingAccount = ShrineReceipt.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<ShrineReceipt>(res, resultingAccount);
         }
 
-        public class InitPlayerAccounts
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<StorageAccount>> GetStorageAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Player { get; set; }
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<StorageAccount>(res);
+            var resultingAccount = StorageAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<StorageAccount>(res, resultingAccount);
+        }
 
-            public PublicKey Global { get; set; }
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<WorldBossAccount>> GetWorldBossAccountAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<WorldBossAccount>(res);
+            var resultingAccount = WorldBossAccount.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<WorldBossAccount>(res, resultingAccount);
+        }
 
-            public PublicKey SignupFaucet { get; set; }
-
-            public PublicKey PlayerAccount { get; set; }
-
-            public PublicKey Profile { get; set; }
-
-            public PublicKey RoomPresence { get; set; }
-
-            public PublicKey PlayerTokenAccount { get; set; }
-
-            public PublicKey SkrMint { get; set; }
+        public async Task<SubscriptionState> SubscribeBossFightAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, BossFightAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                BossFightAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = BossFightAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey AssociatedTokenProgram { get; set; } = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        public async Task<SubscriptionState> SubscribeDiscoveryRoyaltyAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, DiscoveryRoyaltyAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                DiscoveryRoyaltyAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = DiscoveryRoyaltyAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
         }
 
-        public class JoinBossFightAccounts
+        public async Task<SubscriptionState> SubscribeDuelChallengeAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, DuelChallenge> callback, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Authority { get; set; }
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                DuelChallenge parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = DuelChallenge.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Player { get; set; }
+        public async Task<SubscriptionState> SubscribeExportedItemAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, ExportedItem> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                ExportedItem parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = ExportedItem.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Global { get; set; }
+        public async Task<SubscriptionState> SubscribeFloorConnectivityAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, FloorConnectivity> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                FloorConnectivity parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = FloorConnectivity.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey PlayerAccount { get; set; }
+        public async Task<SubscriptionState> SubscribeGlobalAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, GlobalAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                GlobalAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = GlobalAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Profile { get; set; }
+        public async Task<SubscriptionState> SubscribeGrantedItemsAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, GrantedItems> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                GrantedItems parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = GrantedItems.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Room { get; set; }
+        public async Task<SubscriptionState> SubscribeHelperStakeAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, HelperStake> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                HelperStake parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = HelperStake.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey RoomPresence { get; set; }
+        public async Task<SubscriptionState> SubscribeInventoryAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, InventoryAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                InventoryAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = InventoryAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey BossFight { get; set; }
+        public async Task<SubscriptionState> SubscribeLootReceiptAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, LootReceipt> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                LootReceipt parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = LootReceipt.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Inventory { get; set; }
+        public async Task<SubscriptionState> SubscribeMerchantReceiptAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, MerchantReceipt> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                MerchantReceipt parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = MerchantReceipt.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey SessionAuthority { get; set; }
+        public async Task<SubscriptionState> SubscribePendingAdminActionAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PendingAdminAction> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                PendingAdminAction parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = PendingAdminAction.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        public async Task<SubscriptionState> SubscribePlayerAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PlayerAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                PlayerAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = PlayerAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
         }
 
-        public class JoinJobAccounts
+        public async Task<SubscriptionState> SubscribePlayerProfileAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PlayerProfile> callback, Commitment commitment = Commitment.Finalized)
         {
-            public PublicKey Player { get; set; }
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                PlayerProfile parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = PlayerProfile.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Global { get; set; }
+        public async Task<SubscriptionState> SubscribeRoomAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, RoomAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                RoomAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = RoomAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey PlayerAccount { get; set; }
+        public async Task<SubscriptionState> SubscribeRoomPresenceAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, RoomPresence> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                RoomPresence parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = RoomPresence.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Room { get; set; }
+        public async Task<SubscriptionState> SubscribeSeasonHistoryAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, SeasonHistoryAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                SeasonHistoryAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = SeasonHistoryAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey RoomPresence { get; set; }
+        public async Task<SubscriptionState> SubscribeSessionAuthorityAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, SessionAuthority> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                SessionAuthority parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = SessionAuthority.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey Escrow { get; set; }
+        public async Task<SubscriptionState> SubscribeShopConfigAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, ShopConfig> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                ShopConfig parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = ShopConfig.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey HelperStake { get; set; }
+        public async Task<SubscriptionState> SubscribeShrineReceiptAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, ShrineReceipt> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                ShrineReceipt parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = ShrineReceipt.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey PlayerTokenAccount { get; set; }
+        public async Task<SubscriptionState> SubscribeStorageAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, StorageAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                StorageAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = StorageAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey SkrMint { get; set; }
+        public async Task<SubscriptionState> SubscribeWorldBossAccountAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, WorldBossAccount> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                WorldBossAccount parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = WorldBossAccount.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
 
-            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        protected override Dictionary<uint, ProgramError<ChaindepthErrorKind>> BuildErrorsDictionary()
+        {
+            return new Dictionary<uint, ProgramError<ChaindepthErrorKind>>{{6000U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotAdjacent, "Invalid move: target room is not adjacent")}, {6001U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WallNotOpen, "Invalid move: wall is not open")}, {6002U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WallNotLocked, "Wall is not locked")}, {6003U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.OutOfBounds, "Invalid move: coordinates out of bounds")}, {6004U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDirection, "Invalid direction: must be 0-3 (N/S/E/W)")}, {6005U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotRubble, "Wall is not rubble: cannot start job")}, {6006U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyJoined, "Already joined this job")}, {6007U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobFull, "Job is full: maximum helpers reached")}, {6008U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotHelper, "Not a helper on this job")}, {6009U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobNotReady, "Job not ready: progress insufficient")}, {6010U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoActiveJob, "No active job at this location")}, {6011U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobAlreadyCompleted, "Job has already been completed")}, {6012U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.JobNotCompleted, "Job has not been completed yet")}, {6013U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.TooManyActiveJobs, "Too many active jobs: abandon one first")}, {6014U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InventoryFull, "Inventory is full")}, {6015U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidItemId, "Invalid item id")}, {6016U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidItemAmount, "Invalid item amount")}, {6017U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InsufficientItemAmount, "Not enough items")}, {6018U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.MissingRequiredKey, "Missing required key item")}, {6019U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidLockKind, "Invalid lock kind")}, {6020U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoChest, "Room has no chest")}, {6021U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyLooted, "Already looted this chest")}, {6022U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.TreasuryInsufficientFunds, "Treasury has insufficient SOL to reimburse room rent")}, {6023U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotInRoom, "Player not in this room")}, {6024U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotAtEntranceRoom, "Player is not at the entrance room")}, {6025U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.EntranceStairsRequired, "Entrance stairs are required to exit")}, {6026U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.CannotExitWithActiveJobs, "Cannot exit while having active jobs")}, {6027U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoBoss, "No boss in this room center")}, {6028U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.BossAlreadyDefeated, "Boss is already defeated")}, {6029U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.BossNotDefeated, "Boss has not been defeated yet")}, {6030U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyFightingBoss, "Player is already fighting this boss")}, {6031U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotBossFighter, "Player is not a fighter for this boss")}, {6032U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PlayerDead, "Player has no HP and must recover before fighting")}, {6033U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidCenterType, "Invalid center type")}, {6034U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.DisplayNameTooLong, "Display name is too long")}, {6035U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidSessionExpiry, "Invalid session expiry values")}, {6036U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidSessionAllowlist, "Session instruction allowlist cannot be empty")}, {6037U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionExpired, "Session has expired")}, {6038U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionInactive, "Session is inactive")}, {6039U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionInstructionNotAllowed, "Instruction is not allowed by session policy")}, {6040U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SessionSpendCapExceeded, "Session spend cap exceeded")}, {6041U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SeasonNotEnded, "Season has not ended yet")}, {6042U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.Unauthorized, "Unauthorized: only admin can perform this action")}, {6043U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InsufficientBalance, "Insufficient balance for stake")}, {6044U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.TransferFailed, "Token transfer failed")}, {6045U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.Overflow, "Arithmetic overflow")}, {6046U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidSeason, "Invalid season for this operation")}, {6047U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelOpponent, "Invalid duel opponent")}, {6048U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelStake, "Invalid duel stake")}, {6049U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelExpiry, "Invalid duel expiry")}, {6050U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PlayersNotInSameRoom, "Players must be in the same room to duel")}, {6051U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelState, "Invalid duel state transition")}, {6052U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDuelEscrow, "Invalid duel escrow account")}, {6053U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.DuelChallengeExpired, "Duel challenge has already expired")}, {6054U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.DuelChallengeNotExpired, "Duel challenge has not yet expired")}, {6055U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotInDungeon, "Player is not currently in the dungeon")}, {6056U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.UnsupportedDataVersion, "Player account data version is newer than this program supports")}, {6057U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PlayerInDungeon, "Player must be out of the dungeon for this action")}, {6058U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ItemNotWithdrawable, "Scored valuables cannot be withdrawn from storage")}, {6059U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShopItemNotListed, "Item is not listed in the shop")}, {6060U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShopOutOfStock, "Shop listing is sold out for this season")}, {6061U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShopItemNotSellable, "Item cannot be sold to the shop")}, {6062U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShopConfigFull, "Shop configuration has no free slots")}, {6063U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PrizePoolInsufficientFunds, "Prize pool cannot cover this payout")}, {6064U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ItemNotExportable, "Item cannot be exported as an NFT")}, {6065U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ExportedItemMismatch, "NFT mint does not match the exported item record")}, {6066U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.CapacityUpgradeLimit, "No capacity upgrades left")}, {6067U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.LevelTooLow, "Player level too low for this upgrade")}, {6068U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.OverEncumbered, "Carrying too much weight to move")}, {6069U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ItemNotUsable, "Item has no use effect here")}, {6070U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.MissingItemAccounts, "Accounts required by this item were not provided")}, {6071U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ItemBlockedByActiveJobs, "Cannot use this item while having active jobs")}, {6072U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidScoreModifier, "Score modifier is out of range")}, {6073U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoArmedTrap, "No armed trap in that room")}, {6074U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoShrine, "No shrine in this room center")}, {6075U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShrineAlreadyUsed, "Already prayed at this shrine")}, {6076U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AlreadyAtFullHp, "Player is already at full HP")}, {6077U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidTribute, "Shrine tribute must be a carried valuable")}, {6078U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoMerchant, "No merchant in this room center")}, {6079U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidMerchantOffer, "Merchant has no offer at that index")}, {6080U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.MerchantLimitReached, "Purchase limit reached for this merchant offer")}, {6081U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidMerchantPayment, "Merchant only accepts carried valuables")}, {6082U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PartnerRequired, "This lock needs a second player in the room")}, {6083U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoStairsDown, "No stairs down in this room")}, {6084U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NoStairsUp, "Stairs up are only in the start room of a lower floor")}, {6085U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.FloorChangeBlockedByActiveJobs, "Finish or abandon active jobs before changing floors")}, {6086U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidDungeonGrid, "Grid size or start position is out of range")}, {6087U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WorldBossNotReady, "The world boss spawns once exploration reaches the outermost ring")}, {6088U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotAtWorldBossArena, "The world boss is fought from the outermost ring of the first floor")}, {6089U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.LeaveWorldBossBeforeClaiming, "Leave the world boss fight before claiming")}, {6090U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WorldBossRewardClaimed, "World boss reward already claimed")}, {6091U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotInAdminSet, "Signer is not in the admin set")}, {6092U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AdminActionAlreadyApproved, "Admin already approved this action")}, {6093U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AdminActionTimelocked, "Admin action timelock has not elapsed")}, {6094U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AdminActionNeedsApprovals, "Admin action does not have enough approvals")}, {6095U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.AdminActionRequiresProposal, "An admin set is configured; propose this as an admin action")}, {6096U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidAdminSet, "Admin set needs 1-5 distinct admins and a threshold between 1 and their count")}, {6097U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.SubsystemPaused, "This part of the game is paused")}, {6098U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidPauseFlags, "Unknown pause flag")}, {6099U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidRole, "Unknown role")}, {6100U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.NotPendingRoleHolder, "Signer has not been offered this role")}, {6101U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.InvalidFundingAmount, "Treasury funding amount must be positive")}, {6102U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShopItemNotBuyable, "Scored valuables cannot be listed in the shop")}, {6103U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.WorldBossPresenceRequired, "Pass the room presence while the world boss's season is running")}, {6104U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.PlayerAccountMigrationRequired, "Run migrate_player_account before playing with this account")}, {6105U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.GrantedItemNotExportable, "Wallets that received items from add_inventory_item cannot export")}, {6106U, new ProgramError<ChaindepthErrorKind>(ChaindepthErrorKind.ShopConfigRequired, "Shop admin actions must pass the shop_config account")}, };
         }
+    }
 
-        public class JoinJobWithSessionAccounts
+    namespace Program
+    {
+        public class AbandonJobAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -1829,40 +2839,74 @@ namespace Chaindepth
 
             public PublicKey HelperStake { get; set; }
 
-            public PublicKey PlayerTokenAccount { get; set; }
+            public PublicKey PrizePool { get; set; }
 
-            public PublicKey SkrMint { get; set; }
+            public PublicKey PlayerTokenAccount { get; set; }
 
             public PublicKey SessionAuthority { get; set; }
 
             public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class LeaveBossFightAccounts
+        public class AcceptDuelChallengeAccounts
         {
-            public PublicKey Authority { get; set; }
+            public PublicKey Opponent { get; set; }
 
-            public PublicKey Player { get; set; }
+            public PublicKey Challenger { get; set; }
 
             public PublicKey Global { get; set; }
 
-            public PublicKey PlayerAccount { get; set; }
+            public PublicKey DuelChallenge { get; set; }
 
-            public PublicKey Room { get; set; }
+            public PublicKey DuelEscrow { get; set; }
 
-            public PublicKey RoomPresence { get; set; }
+            public PublicKey ChallengerPlayerAccount { get; set; }
 
-            public PublicKey BossFight { get; set; }
+            public PublicKey OpponentPlayerAccount { get; set; }
+
+            public PublicKey ChallengerTokenAccount { get; set; }
+
+            public PublicKey OpponentTokenAccount { get; set; }
+
+            public PublicKey DevTreasuryTokenAccount { get; set; }
+
+            public PublicKey OracleQueue { get; set; } = new PublicKey("Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh");
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey ProgramIdentity { get; set; }
+
+            public PublicKey VrfProgram { get; set; } = new PublicKey("Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz");
+            public PublicKey SlotHashes { get; set; } = new PublicKey("SysvarS1otHashes111111111111111111111111111");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class AcceptRoleAccounts
+        {
+            public PublicKey NewHolder { get; set; }
+
+            public PublicKey Global { get; set; }
+        }
+
+        public class AddInventoryItemAccounts
+        {
+            public PublicKey Player { get; set; }
 
             public PublicKey Inventory { get; set; }
 
-            public PublicKey SessionAuthority { get; set; }
+            public PublicKey GrantedItems { get; set; }
 
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class LootBossAccounts
+        public class ApproveAdminActionAccounts
+        {
+            public PublicKey Approver { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PendingAction { get; set; }
+        }
+
+        public class AscendAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -1872,22 +2916,36 @@ namespace Chaindepth
 
             public PublicKey PlayerAccount { get; set; }
 
-            public PublicKey Room { get; set; }
+            public PublicKey StairsRoom { get; set; }
 
-            public PublicKey RoomPresence { get; set; }
+            public PublicKey CurrentPresence { get; set; }
 
-            public PublicKey BossFight { get; set; }
+            public PublicKey StairsPresence { get; set; }
 
-            public PublicKey Inventory { get; set; }
+            public PublicKey SessionAuthority { get; set; }
 
-            public PublicKey LootReceipt { get; set; }
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class BeginSessionAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey SessionKey { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
 
             public PublicKey SessionAuthority { get; set; }
 
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class LootChestAccounts
+        public class BoostJobAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -1895,20 +2953,18 @@ namespace Chaindepth
 
             public PublicKey Global { get; set; }
 
-            public PublicKey PlayerAccount { get; set; }
-
             public PublicKey Room { get; set; }
 
-            public PublicKey Inventory { get; set; }
+            public PublicKey PrizePool { get; set; }
 
-            public PublicKey LootReceipt { get; set; }
+            public PublicKey PlayerTokenAccount { get; set; }
 
             public PublicKey SessionAuthority { get; set; }
 
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
         }
 
-        public class MovePlayerAccounts
+        public class BuyItemAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -1918,42 +2974,54 @@ namespace Chaindepth
 
             public PublicKey PlayerAccount { get; set; }
 
-            public PublicKey Profile { get; set; }
+            public PublicKey ShopConfig { get; set; }
 
-            public PublicKey CurrentRoom { get; set; }
+            public PublicKey Inventory { get; set; }
 
-            public PublicKey TargetRoom { get; set; }
+            public PublicKey PrizePool { get; set; }
 
-            public PublicKey CurrentPresence { get; set; }
+            public PublicKey PlayerTokenAccount { get; set; }
 
-            public PublicKey TargetPresence { get; set; }
+            public PublicKey SkrMint { get; set; }
 
             public PublicKey SessionAuthority { get; set; }
 
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class RemoveInventoryItemAccounts
+        public class CancelAdminActionAccounts
         {
-            public PublicKey Authority { get; set; }
+            public PublicKey Canceller { get; set; }
 
-            public PublicKey Player { get; set; }
+            public PublicKey Proposer { get; set; }
 
-            public PublicKey Inventory { get; set; }
+            public PublicKey Global { get; set; }
 
-            public PublicKey SessionAuthority { get; set; }
+            public PublicKey PendingAction { get; set; }
         }
 
-        public class ResetMyPlayerAccounts
+        public class ClaimDiscoveryRoyaltiesAccounts
         {
             public PublicKey Authority { get; set; }
 
-            public PublicKey PlayerAccount { get; set; }
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey DiscoveryRoyalty { get; set; }
+
+            public PublicKey PrizePool { get; set; }
 
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class ResetPlayerForTestingAccounts
+        public class ClaimJobRewardAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -1963,17 +3031,45 @@ namespace Chaindepth
 
             public PublicKey PlayerAccount { get; set; }
 
-            public PublicKey Profile { get; set; }
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey Escrow { get; set; }
+
+            public PublicKey HelperStake { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
         }
 
-        public class ResetSeasonAccounts
+        public class ClaimWorldBossRewardAccounts
         {
             public PublicKey Authority { get; set; }
 
+            public PublicKey Player { get; set; }
+
             public PublicKey Global { get; set; }
+
+            public PublicKey WorldBoss { get; set; }
+
+            public PublicKey SeasonHistory { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey PrizePool { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
         }
 
-        public class SetPlayerSkinAccounts
+        public class CompleteJobAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -1983,16 +3079,58 @@ namespace Chaindepth
 
             public PublicKey PlayerAccount { get; set; }
 
-            public PublicKey Profile { get; set; }
+            public PublicKey Room { get; set; }
 
-            public PublicKey RoomPresence { get; set; }
+            public PublicKey HelperStake { get; set; }
+
+            public PublicKey AdjacentRoom { get; set; }
+
+            public PublicKey FloorConnectivity { get; set; }
+
+            public PublicKey Escrow { get; set; }
+
+            public PublicKey PrizePool { get; set; }
+
+            public PublicKey DiscovererRoyalty { get; set; }
 
             public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class TickBossFightAccounts
+        public class ConfigureShopAccounts
         {
-            public PublicKey Authority { get; set; }
+            public PublicKey Admin { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey ShopConfig { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ConsumeDuelRandomnessAccounts
+        {
+            public PublicKey VrfProgramIdentity { get; set; } = new PublicKey("9irBy75QS2BN81FUgXuHcjqceJJRuc9oDkAe8TKVvvAw");
+            public PublicKey Global { get; set; }
+
+            public PublicKey DuelChallenge { get; set; }
+
+            public PublicKey DuelEscrow { get; set; }
+
+            public PublicKey ChallengerTokenAccount { get; set; }
+
+            public PublicKey OpponentTokenAccount { get; set; }
+
+            public PublicKey DevTreasuryTokenAccount { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        }
+
+        public class CrankBossFightAccounts
+        {
+            public PublicKey Cranker { get; set; }
 
             public PublicKey Player { get; set; }
 
@@ -2007,22 +3145,75 @@ namespace Chaindepth
             public PublicKey BossFight { get; set; }
 
             public PublicKey Inventory { get; set; }
+        }
+
+        public class CreateDuelChallengeAccounts
+        {
+            public PublicKey Challenger { get; set; }
+
+            public PublicKey Opponent { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey ChallengerPlayerAccount { get; set; }
+
+            public PublicKey OpponentPlayerAccount { get; set; }
+
+            public PublicKey ChallengerProfile { get; set; }
+
+            public PublicKey OpponentProfile { get; set; }
+
+            public PublicKey DuelChallenge { get; set; }
+
+            public PublicKey DuelEscrow { get; set; }
+
+            public PublicKey ChallengerTokenAccount { get; set; }
+
+            public PublicKey SkrMint { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class CreatePlayerProfileAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
 
             public PublicKey SessionAuthority { get; set; }
 
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class TickJobAccounts
+        public class DeclineDuelChallengeAccounts
         {
-            public PublicKey Caller { get; set; }
+            public PublicKey Opponent { get; set; }
+
+            public PublicKey Challenger { get; set; }
 
             public PublicKey Global { get; set; }
 
-            public PublicKey Room { get; set; }
+            public PublicKey DuelChallenge { get; set; }
+
+            public PublicKey DuelEscrow { get; set; }
+
+            public PublicKey ChallengerTokenAccount { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
         }
 
-        public class UnlockDoorAccounts
+        public class DepositToStorageAccounts
         {
             public PublicKey Authority { get; set; }
 
@@ -2032,120 +3223,1538 @@ namespace Chaindepth
 
             public PublicKey PlayerAccount { get; set; }
 
-            public PublicKey Room { get; set; }
-
-            public PublicKey AdjacentRoom { get; set; }
-
             public PublicKey Inventory { get; set; }
 
+            public PublicKey Storage { get; set; }
+
             public PublicKey SessionAuthority { get; set; }
 
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public static class ChaindepthProgram
-        {
-            public const string ID = "3Ctc2FgnNHQtGAcZftMS4ykLhJYjLzBD3hELKy55DnKo";
-            public static Solana.Unity.Rpc.Models.TransactionInstruction AbandonJob(AbandonJobAccounts accounts, byte direction, PublicKey programId = null)
+        public class DescendAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey CurrentRoom { get; set; }
+
+            public PublicKey ArrivalRoom { get; set; }
+
+            public PublicKey CurrentPresence { get; set; }
+
+            public PublicKey ArrivalPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class DisarmTrapAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey TrapRoom { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+        }
+
+        public class EndSessionAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey SessionKey { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        }
+
+        public class EnsureStartRoomAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey StartRoom { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class EnterDungeonAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+
+            public PublicKey StartRoom { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class EquipItemAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+        }
+
+        public class ExecuteAdminActionAccounts
+        {
+            public PublicKey Executor { get; set; }
+
+            public PublicKey Proposer { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PendingAction { get; set; }
+
+            public PublicKey ShopConfig { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ExitDungeonAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey Storage { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ExpandInventoryAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey PrizePool { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ExpandStorageAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Storage { get; set; }
+
+            public PublicKey PrizePool { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ExpireDuelChallengeAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Challenger { get; set; }
+
+            public PublicKey Opponent { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey DuelChallenge { get; set; }
+
+            public PublicKey DuelEscrow { get; set; }
+
+            public PublicKey ChallengerTokenAccount { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        }
+
+        public class ExportItemAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey Storage { get; set; }
+
+            public PublicKey GrantedItems { get; set; }
+
+            public PublicKey ItemMint { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey ExportedItem { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+            public PublicKey AssociatedTokenProgram { get; set; } = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ForceExitOnDeathAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ForceResetSeasonAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Global { get; set; }
+        }
+
+        public class FundTreasuryAccounts
+        {
+            public PublicKey Funder { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ImportItemAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey Storage { get; set; }
+
+            public PublicKey ItemMint { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey ExportedItem { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class InitGlobalAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SkrMint { get; set; }
+
+            public PublicKey PrizePool { get; set; }
+
+            public PublicKey AdminTokenAccount { get; set; }
+
+            public PublicKey StartRoom { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class InitPlayerAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SignupFaucet { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SkrMint { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey AssociatedTokenProgram { get; set; } = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class JoinBossFightAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class JoinJobAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey Escrow { get; set; }
+
+            public PublicKey HelperStake { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SkrMint { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class JoinJobWithSessionAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey Escrow { get; set; }
+
+            public PublicKey HelperStake { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SkrMint { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class JoinWorldBossAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey WorldBoss { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class LeaveBossFightAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class LeaveWorldBossAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey WorldBoss { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+        }
+
+        public class LootBossAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey LootReceipt { get; set; }
+
+            public PublicKey DiscovererRoyalty { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class LootChestAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey LootReceipt { get; set; }
+
+            public PublicKey DiscovererRoyalty { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class MigrateGlobalAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class MigrateInventoryAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class MigratePlayerAccountAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class MovePlayerAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+
+            public PublicKey CurrentRoom { get; set; }
+
+            public PublicKey TargetRoom { get; set; }
+
+            public PublicKey FloorConnectivity { get; set; }
+
+            public PublicKey CurrentPresence { get; set; }
+
+            public PublicKey TargetPresence { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey DiscoveryRoyalty { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class PrayAtShrineAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey ShrineReceipt { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ProposeAdminActionAccounts
+        {
+            public PublicKey Proposer { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PendingAction { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class RemoveInventoryItemAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+        }
+
+        public class ResetMyPlayerAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ResetPlayerForTestingAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+        }
+
+        public class ResetSeasonAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Global { get; set; }
+        }
+
+        public class SellValuableAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey ShopConfig { get; set; }
+
+            public PublicKey Storage { get; set; }
+
+            public PublicKey PrizePool { get; set; }
+
+            public PublicKey PlayerTokenAccount { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey TokenProgram { get; set; } = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        }
+
+        public class SetDeepExitScoreAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class SetPauseFlagsAccounts
+        {
+            public PublicKey Pauser { get; set; }
+
+            public PublicKey Global { get; set; }
+        }
+
+        public class SetPlayerSkinAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Profile { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+        }
+
+        public class SetRoleAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class SetSeasonGridAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class SetShopListingAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey ShopConfig { get; set; }
+        }
+
+        public class SetValuableSellPriceAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey ShopConfig { get; set; }
+        }
+
+        public class SpawnWorldBossAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey WorldBoss { get; set; }
+
+            public PublicKey SeasonHistory { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class TickBossFightAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey RoomPresence { get; set; }
+
+            public PublicKey BossFight { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class TickJobAccounts
+        {
+            public PublicKey Caller { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey Room { get; set; }
+        }
+
+        public class TradeWithMerchantAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey MerchantReceipt { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class UnlockDoorAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Room { get; set; }
+
+            public PublicKey AdjacentRoom { get; set; }
+
+            public PublicKey FloorConnectivity { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey PartnerPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class UseItemAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey CurrentPresence { get; set; }
+
+            public PublicKey StartPresence { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class WithdrawFromStorageAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Player { get; set; }
+
+            public PublicKey Global { get; set; }
+
+            public PublicKey PlayerAccount { get; set; }
+
+            public PublicKey Inventory { get; set; }
+
+            public PublicKey Storage { get; set; }
+
+            public PublicKey SessionAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public static class ChaindepthProgram
+        {
+            public const string ID = "3Ctc2FgnNHQtGAcZftMS4ykLhJYjLzBD3hELKy55DnKo";
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AbandonJob(AbandonJobAccounts accounts, byte direction, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(18178073425521205758UL, offset);
+                offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AcceptDuelChallenge(AcceptDuelChallengeAccounts accounts, ulong challenge_seed, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Opponent, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Challenger, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ChallengerPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OpponentPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OpponentTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DevTreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OracleQueue, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ProgramIdentity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.VrfProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SlotHashes, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(5657644814158671898UL, offset);
+                offset += 8;
+                _data.WriteU64(challenge_seed, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AcceptRole(AcceptRoleAccounts accounts, byte role, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.NewHolder, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(18264326044746455348UL, offset);
+                offset += 8;
+                _data.WriteU8(role, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AddInventoryItem(AddInventoryItemAccounts accounts, ushort item_id, uint amount, ushort durability, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.GrantedItems, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(16831650881450377564UL, offset);
+                offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU32(amount, offset);
+                offset += 4;
+                _data.WriteU16(durability, offset);
+                offset += 2;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApproveAdminAction(ApproveAdminActionAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Approver, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingAction, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(2383536960496534013UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction Ascend(AscendAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.StairsRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.CurrentPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.StairsPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7727694018398454363UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction BeginSession(BeginSessionAccounts accounts, ulong expires_at_slot, long expires_at_unix_timestamp, ulong instruction_allowlist, ulong max_token_spend, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionKey, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(15610960564545719996UL, offset);
+                offset += 8;
+                _data.WriteU64(expires_at_slot, offset);
+                offset += 8;
+                _data.WriteS64(expires_at_unix_timestamp, offset);
+                offset += 8;
+                _data.WriteU64(instruction_allowlist, offset);
+                offset += 8;
+                _data.WriteU64(max_token_spend, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction BoostJob(BoostJobAccounts accounts, byte direction, ulong boost_amount, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(14583492075229093809UL, offset);
+                offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
+                _data.WriteU64(boost_amount, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction BuyItem(BuyItemAccounts accounts, ushort item_id, uint amount, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ShopConfig, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(13278331169260261968UL, offset);
+                offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU32(amount, offset);
+                offset += 4;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CancelAdminAction(CancelAdminActionAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Canceller, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Proposer, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingAction, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(18086832056087617952UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ClaimDiscoveryRoyalties(ClaimDiscoveryRoyaltiesAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DiscoveryRoyalty, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(5502873923991878304UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ClaimJobReward(ClaimJobRewardAccounts accounts, byte direction, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10767216470660547513UL, offset);
+                offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ClaimWorldBossReward(ClaimWorldBossRewardAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WorldBoss, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SeasonHistory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(2488892997871271012UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CompleteJob(CompleteJobAccounts accounts, byte direction, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdjacentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.FloorConnectivity, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DiscovererRoyalty, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(793753272268740829UL, offset);
+                offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ConfigureShop(ConfigureShopAccounts accounts, bool burn_payments, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ShopConfig, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(4768250117610720666UL, offset);
+                offset += 8;
+                _data.WriteBool(burn_payments, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ConsumeDuelRandomness(ConsumeDuelRandomnessAccounts accounts, byte[] randomness, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.VrfProgramIdentity, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OpponentTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DevTreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(4553619578841933328UL, offset);
+                offset += 8;
+                _data.WriteSpan(randomness, offset);
+                offset += randomness.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CrankBossFight(CrankBossFightAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Cranker, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(142443261636981879UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CreateDuelChallenge(CreateDuelChallengeAccounts accounts, ulong challenge_seed, ulong stake_amount, ulong expires_at_slot, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Challenger, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Opponent, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.OpponentPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ChallengerProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.OpponentProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1820261883948273793UL, offset);
+                offset += 8;
+                _data.WriteU64(challenge_seed, offset);
+                offset += 8;
+                _data.WriteU64(stake_amount, offset);
+                offset += 8;
+                _data.WriteU64(expires_at_slot, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CreatePlayerProfile(CreatePlayerProfileAccounts accounts, ushort skin_id, string display_name, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(3674470262392566090UL, offset);
+                offset += 8;
+                _data.WriteU16(skin_id, offset);
+                offset += 2;
+                offset += _data.WriteBorshString(display_name, offset);
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DeclineDuelChallenge(DeclineDuelChallengeAccounts accounts, ulong challenge_seed, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Opponent, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Challenger, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17993572088236621907UL, offset);
+                offset += 8;
+                _data.WriteU64(challenge_seed, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DepositToStorage(DepositToStorageAccounts accounts, ushort item_id, uint amount, ushort durability, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10553690475449794843UL, offset);
+                offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU32(amount, offset);
+                offset += 4;
+                _data.WriteU16(durability, offset);
+                offset += 2;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction Descend(DescendAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CurrentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ArrivalRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.CurrentPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ArrivalPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17215022393678359778UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DisarmTrap(DisarmTrapAccounts accounts, byte direction, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TrapRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1087992611508827840UL, offset);
+                offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction EndSession(EndSessionAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionKey, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(4760298022670038027UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction EnsureStartRoom(EnsureStartRoomAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.StartRoom, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(3961151750032972163UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction EnterDungeon(EnterDungeonAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.StartRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(16581699431354939603UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction EquipItem(EquipItemAccounts accounts, ushort item_id, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(18375847094273481510UL, offset);
+                offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExecuteAdminAction(ExecuteAdminActionAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Executor, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Proposer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingAction, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ShopConfig == null ? programId : accounts.ShopConfig, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(14237742349496754313UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExitDungeon(ExitDungeonAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(2950877880480132764UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExpandInventory(ExpandInventoryAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(5668183476960376970UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExpandStorage(ExpandStorageAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(12082426235821458733UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExpireDuelChallenge(ExpireDuelChallengeAccounts accounts, ulong challenge_seed, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Challenger, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Opponent, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7453064910369947366UL, offset);
+                offset += 8;
+                _data.WriteU64(challenge_seed, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExportItem(ExportItemAccounts accounts, ushort item_id, ushort durability, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.GrantedItems, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ItemMint, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ExportedItem, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17408175121362168645UL, offset);
+                offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU16(durability, offset);
+                offset += 2;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ForceExitOnDeath(ForceExitOnDeathAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(2531119844774325399UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ForceResetSeason(ForceResetSeasonAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(15941254558648459401UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction FundTreasury(FundTreasuryAccounts accounts, ulong amount, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Funder, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17270777693841758791UL, offset);
+                offset += 8;
+                _data.WriteU64(amount, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ImportItem(ImportItemAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ItemMint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ExportedItem, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(18178073425521205758UL, offset);
+                _data.WriteU64(4191634785997662177UL, offset);
                 offset += 8;
-                _data.WriteU8(direction, offset);
-                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction AcceptDuelChallenge(AcceptDuelChallengeAccounts accounts, ulong challenge_seed, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction InitGlobal(InitGlobalAccounts accounts, ulong initial_prize_pool_amount, ulong season_seed, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Opponent, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Challenger, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ChallengerPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OpponentPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OpponentTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DevTreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OracleQueue, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ProgramIdentity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.VrfProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SlotHashes, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.StartRoom, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(5657644814158671898UL, offset);
+                _data.WriteU64(11727573871456284204UL, offset);
                 offset += 8;
-                _data.WriteU64(challenge_seed, offset);
+                _data.WriteU64(initial_prize_pool_amount, offset);
+                offset += 8;
+                _data.WriteU64(season_seed, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction AddInventoryItem(AddInventoryItemAccounts accounts, ushort item_id, uint amount, ushort durability, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction InitPlayer(InitPlayerAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SignupFaucet, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(16831650881450377564UL, offset);
+                _data.WriteU64(4819994211046333298UL, offset);
                 offset += 8;
-                _data.WriteU16(item_id, offset);
-                offset += 2;
-                _data.WriteU32(amount, offset);
-                offset += 4;
-                _data.WriteU16(durability, offset);
-                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction BeginSession(BeginSessionAccounts accounts, ulong expires_at_slot, long expires_at_unix_timestamp, ulong instruction_allowlist, ulong max_token_spend, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinBossFight(JoinBossFightAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionKey, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(15610960564545719996UL, offset);
-                offset += 8;
-                _data.WriteU64(expires_at_slot, offset);
-                offset += 8;
-                _data.WriteS64(expires_at_unix_timestamp, offset);
-                offset += 8;
-                _data.WriteU64(instruction_allowlist, offset);
-                offset += 8;
-                _data.WriteU64(max_token_spend, offset);
+                _data.WriteU64(13497229756135777931UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction BoostJob(BoostJobAccounts accounts, byte direction, ulong boost_amount, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinJob(JoinJobAccounts accounts, byte direction, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(14583492075229093809UL, offset);
+                _data.WriteU64(5937278740201911420UL, offset);
                 offset += 8;
                 _data.WriteU8(direction, offset);
                 offset += 1;
-                _data.WriteU64(boost_amount, offset);
-                offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ClaimJobReward(ClaimJobRewardAccounts accounts, byte direction, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinJobWithSession(JoinJobWithSessionAccounts accounts, byte direction, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(10767216470660547513UL, offset);
+                _data.WriteU64(10588806654058103858UL, offset);
                 offset += 8;
                 _data.WriteU8(direction, offset);
                 offset += 1;
@@ -2154,462 +4763,484 @@ namespace Chaindepth
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction CompleteJob(CompleteJobAccounts accounts, byte direction, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinWorldBoss(JoinWorldBossAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdjacentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WorldBoss, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(793753272268740829UL, offset);
+                _data.WriteU64(18314515513351153256UL, offset);
                 offset += 8;
-                _data.WriteU8(direction, offset);
-                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ConsumeDuelRandomness(ConsumeDuelRandomnessAccounts accounts, byte[] randomness, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction LeaveBossFight(LeaveBossFightAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.VrfProgramIdentity, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.OpponentTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DevTreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(4553619578841933328UL, offset);
+                _data.WriteU64(12291656720583044300UL, offset);
                 offset += 8;
-                _data.WriteSpan(randomness, offset);
-                offset += randomness.Length;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction CreateDuelChallenge(CreateDuelChallengeAccounts accounts, ulong challenge_seed, ulong stake_amount, ulong expires_at_slot, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction LeaveWorldBoss(LeaveWorldBossAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Challenger, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Opponent, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.OpponentPlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ChallengerProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.OpponentProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence == null ? programId : accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WorldBoss, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(1820261883948273793UL, offset);
-                offset += 8;
-                _data.WriteU64(challenge_seed, offset);
-                offset += 8;
-                _data.WriteU64(stake_amount, offset);
-                offset += 8;
-                _data.WriteU64(expires_at_slot, offset);
+                _data.WriteU64(13291053894518244236UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction CreatePlayerProfile(CreatePlayerProfileAccounts accounts, ushort skin_id, string display_name, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction LootBoss(LootBossAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.LootReceipt, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DiscovererRoyalty, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(3674470262392566090UL, offset);
+                _data.WriteU64(3070053737248129477UL, offset);
                 offset += 8;
-                _data.WriteU16(skin_id, offset);
-                offset += 2;
-                offset += _data.WriteBorshString(display_name, offset);
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction DeclineDuelChallenge(DeclineDuelChallengeAccounts accounts, ulong challenge_seed, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction LootChest(LootChestAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Opponent, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Challenger, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.LootReceipt, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DiscovererRoyalty, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(17993572088236621907UL, offset);
-                offset += 8;
-                _data.WriteU64(challenge_seed, offset);
+                _data.WriteU64(4166659101437723766UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction EndSession(EndSessionAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigrateGlobal(MigrateGlobalAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionKey, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(4760298022670038027UL, offset);
+                _data.WriteU64(2367772841349365759UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction EnsureStartRoom(EnsureStartRoomAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigrateInventory(MigrateInventoryAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.StartRoom, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(3961151750032972163UL, offset);
+                _data.WriteU64(5463897690828812941UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction EnterDungeon(EnterDungeonAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigratePlayerAccount(MigratePlayerAccountAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.StartRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(16581699431354939603UL, offset);
+                _data.WriteU64(13030734474402746087UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction EquipItem(EquipItemAccounts accounts, ushort item_id, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MovePlayer(MovePlayerAccounts accounts, sbyte new_x, sbyte new_y, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CurrentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TargetRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.FloorConnectivity, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.CurrentPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TargetPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DiscoveryRoyalty, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(18375847094273481510UL, offset);
+                _data.WriteU64(16684840164937447953UL, offset);
                 offset += 8;
-                _data.WriteU16(item_id, offset);
-                offset += 2;
+                _data.WriteS8(new_x, offset);
+                offset += 1;
+                _data.WriteS8(new_y, offset);
+                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ExitDungeon(ExitDungeonAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction PrayAtShrine(PrayAtShrineAccounts accounts, ushort tribute_item_id, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ShrineReceipt, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(2950877880480132764UL, offset);
+                _data.WriteU64(15197213565094177646UL, offset);
                 offset += 8;
+                _data.WriteU16(tribute_item_id, offset);
+                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ExpireDuelChallenge(ExpireDuelChallengeAccounts accounts, ulong challenge_seed, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ProposeAdminAction(ProposeAdminActionAccounts accounts, ulong action_id, AdminAction action, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Challenger, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Opponent, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelChallenge, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.DuelEscrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ChallengerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Proposer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingAction, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(7453064910369947366UL, offset);
+                _data.WriteU64(15532723961613107035UL, offset);
                 offset += 8;
-                _data.WriteU64(challenge_seed, offset);
+                _data.WriteU64(action_id, offset);
                 offset += 8;
+                offset += action.Serialize(_data, offset);
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ForceExitOnDeath(ForceExitOnDeathAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction RemoveInventoryItem(RemoveInventoryItemAccounts accounts, ushort item_id, uint amount, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(2531119844774325399UL, offset);
+                _data.WriteU64(8898137908046575999UL, offset);
                 offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU32(amount, offset);
+                offset += 4;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ForceResetSeason(ForceResetSeasonAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ResetMyPlayer(ResetMyPlayerAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(15941254558648459401UL, offset);
+                _data.WriteU64(9865713732384978146UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction InitGlobal(InitGlobalAccounts accounts, ulong initial_prize_pool_amount, ulong season_seed, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ResetPlayerForTesting(ResetPlayerForTestingAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.StartRoom, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(11727573871456284204UL, offset);
-                offset += 8;
-                _data.WriteU64(initial_prize_pool_amount, offset);
-                offset += 8;
-                _data.WriteU64(season_seed, offset);
+                _data.WriteU64(8654500352465190211UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction InitPlayer(InitPlayerAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ResetSeason(ResetSeasonAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SignupFaucet, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(4819994211046333298UL, offset);
+                _data.WriteU64(1230071605279309681UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinBossFight(JoinBossFightAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SellValuable(SellValuableAccounts accounts, ushort item_id, uint amount, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ShopConfig, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PrizePool, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(13497229756135777931UL, offset);
+                _data.WriteU64(52895085661600864UL, offset);
                 offset += 8;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU32(amount, offset);
+                offset += 4;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinJob(JoinJobAccounts accounts, byte direction, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetDeepExitScore(SetDeepExitScoreAccounts accounts, ushort score_bps, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(5937278740201911420UL, offset);
+                _data.WriteU64(2219821024163028200UL, offset);
                 offset += 8;
-                _data.WriteU8(direction, offset);
-                offset += 1;
+                _data.WriteU16(score_bps, offset);
+                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinJobWithSession(JoinJobWithSessionAccounts accounts, byte direction, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetPauseFlags(SetPauseFlagsAccounts accounts, ushort paused_flags, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Escrow, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.HelperStake, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SkrMint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Pauser, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(10588806654058103858UL, offset);
+                _data.WriteU64(12680107474667546573UL, offset);
                 offset += 8;
-                _data.WriteU8(direction, offset);
-                offset += 1;
+                _data.WriteU16(paused_flags, offset);
+                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction LeaveBossFight(LeaveBossFightAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetPlayerSkin(SetPlayerSkinAccounts accounts, ushort skin_id, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(12291656720583044300UL, offset);
+                _data.WriteU64(15470882606742745413UL, offset);
                 offset += 8;
+                _data.WriteU16(skin_id, offset);
+                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction LootBoss(LootBossAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetRole(SetRoleAccounts accounts, byte role, PublicKey new_holder, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.LootReceipt, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(3070053737248129477UL, offset);
+                _data.WriteU64(13747024286327918157UL, offset);
                 offset += 8;
+                _data.WriteU8(role, offset);
+                offset += 1;
+                _data.WritePubKey(new_holder, offset);
+                offset += 32;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction LootChest(LootChestAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetSeasonGrid(SetSeasonGridAccounts accounts, byte width, byte height, sbyte start_x, sbyte start_y, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.LootReceipt, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(4166659101437723766UL, offset);
+                _data.WriteU64(462871437478408683UL, offset);
                 offset += 8;
+                _data.WriteU8(width, offset);
+                offset += 1;
+                _data.WriteU8(height, offset);
+                offset += 1;
+                _data.WriteS8(start_x, offset);
+                offset += 1;
+                _data.WriteS8(start_y, offset);
+                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction MovePlayer(MovePlayerAccounts accounts, sbyte new_x, sbyte new_y, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetShopListing(SetShopListingAccounts accounts, ushort item_id, ushort durability, ulong price, uint stock_per_season, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CurrentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TargetRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.CurrentPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TargetPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ShopConfig, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(16684840164937447953UL, offset);
+                _data.WriteU64(11691474528632076515UL, offset);
                 offset += 8;
-                _data.WriteS8(new_x, offset);
-                offset += 1;
-                _data.WriteS8(new_y, offset);
-                offset += 1;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU16(durability, offset);
+                offset += 2;
+                _data.WriteU64(price, offset);
+                offset += 8;
+                _data.WriteU32(stock_per_season, offset);
+                offset += 4;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction RemoveInventoryItem(RemoveInventoryItemAccounts accounts, ushort item_id, uint amount, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetValuableSellPrice(SetValuableSellPriceAccounts accounts, ushort item_id, ulong price, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ShopConfig, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(8898137908046575999UL, offset);
+                _data.WriteU64(7852350214863071049UL, offset);
                 offset += 8;
                 _data.WriteU16(item_id, offset);
                 offset += 2;
-                _data.WriteU32(amount, offset);
-                offset += 4;
+                _data.WriteU64(price, offset);
+                offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ResetMyPlayer(ResetMyPlayerAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SpawnWorldBoss(SpawnWorldBossAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WorldBoss, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SeasonHistory, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(9865713732384978146UL, offset);
+                _data.WriteU64(14563847224736898001UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ResetPlayerForTesting(ResetPlayerForTestingAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction TickBossFight(TickBossFightAccounts accounts, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(8654500352465190211UL, offset);
+                _data.WriteU64(18292877083428576667UL, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction ResetSeason(ResetSeasonAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction TickJob(TickJobAccounts accounts, byte direction, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Caller, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(1230071605279309681UL, offset);
+                _data.WriteU64(8672572876003750988UL, offset);
                 offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction SetPlayerSkin(SetPlayerSkinAccounts accounts, ushort skin_id, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction TradeWithMerchant(TradeWithMerchantAccounts accounts, byte offer_index, ushort payment_item_id, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Profile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.MerchantReceipt, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(15470882606742745413UL, offset);
+                _data.WriteU64(13478682617110299023UL, offset);
                 offset += 8;
-                _data.WriteU16(skin_id, offset);
+                _data.WriteU8(offer_index, offset);
+                offset += 1;
+                _data.WriteU16(payment_item_id, offset);
                 offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction TickBossFight(TickBossFightAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UnlockDoor(UnlockDoorAccounts accounts, byte direction, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.RoomPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BossFight, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdjacentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.FloorConnectivity, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PartnerPresence == null ? programId : accounts.PartnerPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(18292877083428576667UL, offset);
+                _data.WriteU64(1945469879090199389UL, offset);
                 offset += 8;
+                _data.WriteU8(direction, offset);
+                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction TickJob(TickJobAccounts accounts, byte direction, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UseItem(UseItemAccounts accounts, ushort item_id, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Caller, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.CurrentPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.StartPresence == null ? programId : accounts.StartPresence, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(8672572876003750988UL, offset);
+                _data.WriteU64(14397049230667502886UL, offset);
                 offset += 8;
-                _data.WriteU8(direction, offset);
-                offset += 1;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction UnlockDoor(UnlockDoorAccounts accounts, byte direction, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction WithdrawFromStorage(WithdrawFromStorageAccounts accounts, ushort item_id, uint amount, ushort durability, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Room, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdjacentRoom, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Global, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Inventory, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Storage, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SessionAuthority == null ? programId : accounts.SessionAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(1945469879090199389UL, offset);
+                _data.WriteU64(14309344780741499166UL, offset);
                 offset += 8;
-                _data.WriteU8(direction, offset);
-                offset += 1;
+                _data.WriteU16(item_id, offset);
+                offset += 2;
+                _data.WriteU32(amount, offset);
+                offset += 4;
+                _data.WriteU16(durability, offset);
+                offset += 2;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
@@ -41,6 +41,8 @@ namespace SeekerDungeon.Solana
         public const string PRESENCE_SEED = "presence";
         public const string PRIZE_POOL_SEED = "prize_pool";
         public const string LOOT_RECEIPT_SEED = "loot_receipt";
+        public const string FLOOR_CONNECTIVITY_SEED = "floor_connectivity";
+        public const string DISCOVERY_ROYALTY_SEED = "discovery_royalty";
         
         // Game constants
         public const int START_X = 10;
@@ -375,6 +375,29 @@ namespace SeekerDungeon.Solana
             return success ? pda : null;
         }
 
+        /// <summary>
+        /// Derive floor connectivity PDA (repairs shared by every discovery on the floor)
+        /// </summary>
+        public PublicKey DeriveFloorConnectivityPda(ulong seasonSeed, byte floor = 0)
+        {
+            var seasonBytes = BitConverter.GetBytes(seasonSeed);
+            if (!BitConverter.IsLittleEndian)
+                Array.Reverse(seasonBytes);
+
+            var success = PublicKey.TryFindProgramAddress(
+                new List<byte[]>
+                {
+                    Encoding.UTF8.GetBytes(LGConfig.FLOOR_CONNECTIVITY_SEED),
+                    seasonBytes,
+                    new[] { floor }
+                },
+                _programId,
+                out var pda,
+                out _
+            );
+            return success ? pda : null;
+        }
+
         /// <summary>
         /// Derive escrow PDA for a room/direction job
         /// </summary>
@@ -432,6 +455,24 @@ namespace SeekerDungeon.Solana
             return success ? pda : null;
         }
 
+        /// <summary>
+        /// Derive discovery royalty PDA for a room's discoverer
+        /// </summary>
+        public PublicKey DeriveDiscoveryRoyaltyPda(PublicKey discovererPubkey)
+        {
+            var success = PublicKey.TryFindProgramAddress(
+                new List<byte[]>
+                {
+                    Encoding.UTF8.GetBytes(LGConfig.DISCOVERY_ROYALTY_SEED),
+                    discovererPubkey.KeyBytes
+                },
+                _programId,
+                out var pda,
+                out _
+            );
+            return success ? pda : null;
+        }
+
         /// <summary>
         /// Derive storage PDA for a player
         /// </summary>
@@ -780,6 +821,17 @@ namespace SeekerDungeon.Solana
             }
         }
 
+        /// <summary>
+        /// Fetch who discovered a room; job and loot royalties are paid to their account.
+        /// Leaves CurrentRoomState untouched.
+        /// </summary>
+        private async UniTask<PublicKey> FetchRoomDiscoverer(int x, int y)
+        {
+            var roomPda = DeriveRoomPda(CurrentGlobalState.SeasonSeed, x, y);
+            var result = await _client.GetRoomAccountAsync(roomPda.Key, Commitment.Confirmed);
+            return result.WasSuccessful ? result.ParsedResult?.CreatedBy : null;
+        }
+
         /// <summary>
         /// Fetch current player's room
         /// </summary>
@@ -1921,6 +1973,13 @@ namespace SeekerDungeon.Solana
 
             try
             {
+                var discoverer = await FetchRoomDiscoverer(roomX, roomY);
+                if (discoverer == null)
+                {
+                    LogError("CompleteJob failed: room not found");
+                    return TxResult.Fail("Room not found");
+                }
+
                 var result = await ExecuteGameplayActionAsync(
                     "CompleteJob",
                     (context) =>
@@ -1942,8 +2001,10 @@ namespace SeekerDungeon.Solana
                                 Room = roomPda,
                                 HelperStake = helperStakePda,
                                 AdjacentRoom = adjacentRoomPda,
+                                FloorConnectivity = DeriveFloorConnectivityPda(CurrentGlobalState.SeasonSeed),
                                 Escrow = escrowPda,
                                 PrizePool = CurrentGlobalState.PrizePool,
+                                DiscovererRoyalty = DeriveDiscoveryRoyaltyPda(discoverer),
                                 SessionAuthority = context.SessionAuthority,
                                 TokenProgram = TokenProgram.ProgramIdKey,
                                 SystemProgram = SystemProgram.ProgramIdKey
@@ -3517,6 +3578,7 @@ namespace SeekerDungeon.Solana
                             newY,
                             context.Player
                         );
+                        var inventoryPda = DeriveInventoryPda(context.Player);
 
                         return ChaindepthProgram.MovePlayer(
                             new MovePlayerAccounts
@@ -3528,8 +3590,11 @@ namespace SeekerDungeon.Solana
                                 Profile = profilePda,
                                 CurrentRoom = currentRoomPda,
                                 TargetRoom = targetRoomPda,
+                                FloorConnectivity = DeriveFloorConnectivityPda(CurrentGlobalState.SeasonSeed),
                                 CurrentPresence = currentPresencePda,
                                 TargetPresence = targetPresencePda,
+                                Inventory = inventoryPda,
+                                DiscoveryRoyalty = DeriveDiscoveryRoyaltyPda(context.Player),
                                 SessionAuthority = context.SessionAuthority,
                                 SystemProgram = SystemProgram.ProgramIdKey
                             },
@@ -3835,6 +3900,7 @@ namespace SeekerDungeon.Solana
                                 PlayerAccount = playerPda,
                                 Room = roomPda,
                                 AdjacentRoom = adjacentRoomPda,
+                                FloorConnectivity = DeriveFloorConnectivityPda(CurrentGlobalState.SeasonSeed),
                                 Inventory = inventoryPda,
                                 SessionAuthority = context.SessionAuthority,
                                 SystemProgram = SystemProgram.ProgramIdKey
@@ -4036,6 +4102,13 @@ namespace SeekerDungeon.Solana
 
             try
             {
+                var discoverer = await FetchRoomDiscoverer(CurrentPlayerState.CurrentRoomX, CurrentPlayerState.CurrentRoomY);
+                if (discoverer == null)
+                {
+                    LogError("CompleteJob failed: room not found");
+                    return TxResult.Fail("Room not found");
+                }
+
                 var result = await ExecuteGameplayActionAsync(
                     "CompleteJob",
                     (context) =>
@@ -4063,8 +4136,10 @@ namespace SeekerDungeon.Solana
                                 Room = roomPda,
                                 HelperStake = helperStakePda,
                                 AdjacentRoom = adjacentRoomPda,
+                                FloorConnectivity = DeriveFloorConnectivityPda(CurrentGlobalState.SeasonSeed),
                                 Escrow = escrowPda,
                                 PrizePool = CurrentGlobalState.PrizePool,
+                                DiscovererRoyalty = DeriveDiscoveryRoyaltyPda(discoverer),
                                 SessionAuthority = context.SessionAuthority,
                                 TokenProgram = TokenProgram.ProgramIdKey,
                                 SystemProgram = SystemProgram.ProgramIdKey
@@ -4305,6 +4380,13 @@ namespace SeekerDungeon.Solana
 
             try
             {
+                var discoverer = await FetchRoomDiscoverer(CurrentPlayerState.CurrentRoomX, CurrentPlayerState.CurrentRoomY);
+                if (discoverer == null)
+                {
+                    LogError("LootChest failed: room not found");
+                    return TxResult.Fail("Room not found");
+                }
+
                 var result = await ExecuteGameplayActionAsync(
                     "LootChest",
                     (context) =>
@@ -4331,6 +4413,7 @@ namespace SeekerDungeon.Solana
                                 Room = roomPda,
                                 Inventory = inventoryPda,
                                 LootReceipt = lootReceiptPda,
+                                DiscovererRoyalty = DeriveDiscoveryRoyaltyPda(discoverer),
                                 SessionAuthority = context.SessionAuthority,
                                 SystemProgram = SystemProgram.ProgramIdKey
                             },
@@ -4756,6 +4839,13 @@ namespace SeekerDungeon.Solana
 
             try
             {
+                var discoverer = await FetchRoomDiscoverer(CurrentPlayerState.CurrentRoomX, CurrentPlayerState.CurrentRoomY);
+                if (discoverer == null)
+                {
+                    LogError("LootBoss failed: room not found");
+                    return TxResult.Fail("Room not found");
+                }
+
                 var result = await ExecuteGameplayActionAsync(
                     "LootBoss",
                     (context) =>
@@ -4792,6 +4882,7 @@ namespace SeekerDungeon.Solana
                                 BossFight = bossFightPda,
                                 Inventory = inventoryPda,
                                 LootReceipt = lootReceiptPda,
+                                DiscovererRoyalty = DeriveDiscoveryRoyaltyPda(discoverer),
                                 SessionAuthority = context.SessionAuthority,
                                 SystemProgram = SystemProgram.ProgramIdKey
                             },
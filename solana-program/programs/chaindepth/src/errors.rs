@@ -175,4 +175,179 @@ pub enum ChainDepthError {
 
     #[msg("Player is not currently in the dungeon")]
     NotInDungeon,
+
+    #[msg("Player account data version is newer than this program supports")]
+    UnsupportedDataVersion,
+
+    #[msg("Player must be out of the dungeon for this action")]
+    PlayerInDungeon,
+
+    #[msg("Scored valuables cannot be withdrawn from storage")]
+    ItemNotWithdrawable,
+
+    // Shop errors
+    #[msg("Item is not listed in the shop")]
+    ShopItemNotListed,
+
+    #[msg("Shop listing is sold out for this season")]
+    ShopOutOfStock,
+
+    #[msg("Item cannot be sold to the shop")]
+    ShopItemNotSellable,
+
+    #[msg("Shop configuration has no free slots")]
+    ShopConfigFull,
+
+    #[msg("Prize pool cannot cover this payout")]
+    PrizePoolInsufficientFunds,
+
+    // NFT export errors
+    #[msg("Item cannot be exported as an NFT")]
+    ItemNotExportable,
+
+    #[msg("NFT mint does not match the exported item record")]
+    ExportedItemMismatch,
+
+    // Capacity errors
+    #[msg("No capacity upgrades left")]
+    CapacityUpgradeLimit,
+
+    #[msg("Player level too low for this upgrade")]
+    LevelTooLow,
+
+    // Encumbrance errors
+    #[msg("Carrying too much weight to move")]
+    OverEncumbered,
+
+    // Item use errors
+    #[msg("Item has no use effect here")]
+    ItemNotUsable,
+
+    #[msg("Accounts required by this item were not provided")]
+    MissingItemAccounts,
+
+    #[msg("Cannot use this item while having active jobs")]
+    ItemBlockedByActiveJobs,
+
+    // Extraction errors
+    #[msg("Score modifier is out of range")]
+    InvalidScoreModifier,
+
+    // Trap errors
+    #[msg("No armed trap in that room")]
+    NoArmedTrap,
+
+    // Shrine errors
+    #[msg("No shrine in this room center")]
+    NoShrine,
+
+    #[msg("Already prayed at this shrine")]
+    ShrineAlreadyUsed,
+
+    #[msg("Player is already at full HP")]
+    AlreadyAtFullHp,
+
+    #[msg("Shrine tribute must be a carried valuable")]
+    InvalidTribute,
+
+    // Merchant errors
+    #[msg("No merchant in this room center")]
+    NoMerchant,
+
+    #[msg("Merchant has no offer at that index")]
+    InvalidMerchantOffer,
+
+    #[msg("Purchase limit reached for this merchant offer")]
+    MerchantLimitReached,
+
+    #[msg("Merchant only accepts carried valuables")]
+    InvalidMerchantPayment,
+
+    // Lock errors
+    #[msg("This lock needs a second player in the room")]
+    PartnerRequired,
+
+    // Floor errors
+    #[msg("No stairs down in this room")]
+    NoStairsDown,
+
+    #[msg("Stairs up are only in the start room of a lower floor")]
+    NoStairsUp,
+
+    #[msg("Finish or abandon active jobs before changing floors")]
+    FloorChangeBlockedByActiveJobs,
+
+    // Grid errors
+    #[msg("Grid size or start position is out of range")]
+    InvalidDungeonGrid,
+
+    // World boss errors
+    #[msg("The world boss spawns once exploration reaches the outermost ring")]
+    WorldBossNotReady,
+
+    #[msg("The world boss is fought from the outermost ring of the first floor")]
+    NotAtWorldBossArena,
+
+    #[msg("Leave the world boss fight before claiming")]
+    LeaveWorldBossBeforeClaiming,
+
+    #[msg("World boss reward already claimed")]
+    WorldBossRewardClaimed,
+
+    // Admin action errors
+    #[msg("Signer is not in the admin set")]
+    NotInAdminSet,
+
+    #[msg("Admin already approved this action")]
+    AdminActionAlreadyApproved,
+
+    #[msg("Admin action timelock has not elapsed")]
+    AdminActionTimelocked,
+
+    #[msg("Admin action does not have enough approvals")]
+    AdminActionNeedsApprovals,
+
+    #[msg("An admin set is configured; propose this as an admin action")]
+    AdminActionRequiresProposal,
+
+    #[msg("Admin set needs 1-5 distinct admins and a threshold between 1 and their count")]
+    InvalidAdminSet,
+
+    // Pause errors
+    #[msg("This part of the game is paused")]
+    SubsystemPaused,
+
+    #[msg("Unknown pause flag")]
+    InvalidPauseFlags,
+
+    // Role errors
+    #[msg("Unknown role")]
+    InvalidRole,
+
+    #[msg("Signer has not been offered this role")]
+    NotPendingRoleHolder,
+
+    // Treasury errors
+    #[msg("Treasury funding amount must be positive")]
+    InvalidFundingAmount,
+
+    // Shop listing errors
+    #[msg("Scored valuables cannot be listed in the shop")]
+    ShopItemNotBuyable,
+
+    // World boss leave errors
+    #[msg("Pass the room presence while the world boss's season is running")]
+    WorldBossPresenceRequired,
+
+    // Player migration errors
+    #[msg("Run migrate_player_account before playing with this account")]
+    PlayerAccountMigrationRequired,
+
+    // Item export errors
+    #[msg("Wallets that received items from add_inventory_item cannot export")]
+    GrantedItemNotExportable,
+
+    // Shop admin action errors
+    #[msg("Shop admin actions must pass the shop_config account")]
+    ShopConfigRequired,
 }
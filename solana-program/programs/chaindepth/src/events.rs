@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::AdminAction;
+
 /// Emitted when a job is completed and a wall opens
 #[event]
 pub struct JobCompleted {
@@ -90,9 +92,12 @@ pub struct DoorUnlocked {
     pub direction: u8,
     pub player: Pubkey,
     pub key_item_id: u16,
+    pub keys_used: u32,
+    /// Second player present for a cooperative lock (default pubkey otherwise)
+    pub partner: Pubkey,
 }
 
-/// Emitted when a player extracts from dungeon entrance stairs.
+/// Emitted when a player extracts from entrance stairs or a deep extraction room.
 #[event]
 pub struct DungeonExited {
     pub player: Pubkey,
@@ -103,6 +108,9 @@ pub struct DungeonExited {
     pub extracted_item_units: u32,
     pub total_score: u64,
     pub run_duration_slots: u64,
+    pub deep_exit: bool,
+    /// Multiplier applied to `run_score` (10_000 = unchanged)
+    pub score_bps: u16,
 }
 
 /// Emitted for each scored inventory stack during extraction.
@@ -159,6 +167,53 @@ pub struct InventoryItemRemoved {
     pub amount: u32,
 }
 
+/// Emitted when a stack is moved from storage into the run inventory
+#[event]
+pub struct StorageItemWithdrawn {
+    pub player: Pubkey,
+    pub item_id: u16,
+    pub amount: u32,
+    pub durability: u16,
+}
+
+/// Emitted when a stack is moved from the run inventory into storage
+#[event]
+pub struct StorageItemDeposited {
+    pub player: Pubkey,
+    pub item_id: u16,
+    pub amount: u32,
+    pub durability: u16,
+}
+
+/// Emitted when a player buys gear from the surface shop
+#[event]
+pub struct ShopItemBought {
+    pub player: Pubkey,
+    pub item_id: u16,
+    pub amount: u32,
+    pub total_price: u64,
+    pub burned: bool,
+}
+
+/// Emitted when a player sells banked valuables to the surface shop
+#[event]
+pub struct ValuableSold {
+    pub player: Pubkey,
+    pub item_id: u16,
+    pub amount: u32,
+    pub payout: u64,
+}
+
+/// Emitted when admin changes a shop listing or sell price (price 0 = removed)
+#[event]
+pub struct ShopConfigUpdated {
+    pub admin: Pubkey,
+    pub item_id: u16,
+    pub is_listing: bool,
+    pub price: u64,
+    pub stock_per_season: u32,
+}
+
 #[event]
 pub struct ItemEquipped {
     pub player: Pubkey,
@@ -204,6 +259,7 @@ pub struct PlayerDamaged {
     pub max_hp: u16,
 }
 
+/// `boss_id` is 0 when the player died to a trap
 #[event]
 pub struct PlayerDied {
     pub player: Pubkey,
@@ -267,6 +323,324 @@ pub struct DuelSettled {
     pub opponent_hits: Vec<u8>,
 }
 
+/// Emitted when a player exports an item stack unit as an NFT
+#[event]
+pub struct ItemExported {
+    pub player: Pubkey,
+    pub mint: Pubkey,
+    pub item_id: u16,
+    pub durability: u16,
+}
+
+/// Emitted when a player burns an item NFT to restore it in-game
+#[event]
+pub struct ItemImported {
+    pub player: Pubkey,
+    pub mint: Pubkey,
+    pub item_id: u16,
+    pub durability: u16,
+}
+
+/// Emitted when XP pushes a player to a new level
+#[event]
+pub struct PlayerLeveledUp {
+    pub player: Pubkey,
+    pub level: u16,
+    pub xp: u64,
+    pub max_hp: u16,
+}
+
+/// Emitted when a player account is upgraded to the current data layout
+#[event]
+pub struct PlayerAccountMigrated {
+    pub player: Pubkey,
+    pub from_version: u16,
+    pub to_version: u16,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+/// Emitted when a global created before the latest fields is grown to fit them
+#[event]
+pub struct GlobalAccountMigrated {
+    pub payer: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+/// Emitted when a legacy inventory is grown to fit the current layout
+#[event]
+pub struct InventoryAccountMigrated {
+    pub player: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+/// Emitted when a player buys extra inventory or storage slots
+#[event]
+pub struct ItemCapacityExpanded {
+    pub player: Pubkey,
+    pub is_storage: bool,
+    pub slot_capacity: u16,
+    pub price: u64,
+}
+
+/// Emitted for each loot stack that did not fit in a full inventory
+#[event]
+pub struct LootOverflowed {
+    pub player: Pubkey,
+    pub room_x: i8,
+    pub room_y: i8,
+    pub item_id: u16,
+    pub amount: u32,
+    pub durability: u16,
+}
+
+/// Emitted on every move with the player's current load
+#[event]
+pub struct PlayerEncumbrance {
+    pub player: Pubkey,
+    pub carry_weight: u64,
+    pub max_carry_weight: u64,
+}
+
+/// Emitted when a player consumes a usable item
+#[event]
+pub struct ItemUsed {
+    pub player: Pubkey,
+    pub item_id: u16,
+}
+
+/// Emitted when a Rusted Compass points at a depth's forced key chest
+#[event]
+pub struct ForcedKeyChestRevealed {
+    pub player: Pubkey,
+    pub floor: u8,
+    pub depth: u32,
+    pub room_x: i8,
+    pub room_y: i8,
+}
+
+/// Emitted when admin changes the deep extraction score multiplier
+#[event]
+pub struct DeepExitScoreUpdated {
+    pub admin: Pubkey,
+    pub score_bps: u16,
+}
+
+/// Emitted when admin picks the room grid for the next season
+#[event]
+pub struct SeasonGridScheduled {
+    pub admin: Pubkey,
+    pub width: u8,
+    pub height: u8,
+    pub start_x: i8,
+    pub start_y: i8,
+}
+
+/// Emitted when a player walks into an armed trap room
+#[event]
+pub struct TrapTriggered {
+    pub player: Pubkey,
+    pub room_x: i8,
+    pub room_y: i8,
+    pub trap_id: u16,
+    pub damage: u16,
+    pub current_hp: u16,
+    pub max_hp: u16,
+}
+
+/// Emitted when a player disarms a trap with a Trap Kit
+#[event]
+pub struct TrapDisarmed {
+    pub player: Pubkey,
+    pub room_x: i8,
+    pub room_y: i8,
+}
+
+/// Emitted when a player is healed at a shrine (tribute_item_id 0 = free shrine)
+#[event]
+pub struct ShrinePrayed {
+    pub player: Pubkey,
+    pub room_x: i8,
+    pub room_y: i8,
+    pub healed: u16,
+    pub current_hp: u16,
+    pub tribute_item_id: u16,
+}
+
+/// Emitted when a player buys from a merchant room with valuables
+#[event]
+pub struct MerchantTraded {
+    pub player: Pubkey,
+    pub room_x: i8,
+    pub room_y: i8,
+    pub item_id: u16,
+    pub durability: u16,
+    pub payment_item_id: u16,
+    pub payment_amount: u32,
+}
+
+/// Emitted when a player takes stairs between floors
+#[event]
+pub struct PlayerChangedFloor {
+    pub player: Pubkey,
+    pub from_floor: u8,
+    pub to_floor: u8,
+    pub from_x: i8,
+    pub from_y: i8,
+    pub to_x: i8,
+    pub to_y: i8,
+}
+
+/// Emitted when the season's world boss is spawned
+#[event]
+pub struct WorldBossSpawned {
+    pub season_seed: u64,
+    pub boss_id: u16,
+    pub max_hp: u64,
+    pub spawned_by: Pubkey,
+}
+
+/// Emitted when a player joins or leaves the world boss fight
+#[event]
+pub struct WorldBossFightChanged {
+    pub player: Pubkey,
+    pub joined: bool,
+    pub dps: u64,
+    pub damage_dealt: u64,
+    pub fighter_count: u32,
+    pub current_hp: u64,
+}
+
+/// Emitted once, by the first reward claim after the world boss dies
+#[event]
+pub struct WorldBossDefeated {
+    pub season_seed: u64,
+    pub defeated_slot: u64,
+    pub participants: u32,
+    pub total_damage: u64,
+    pub prize_amount: u64,
+}
+
+/// Emitted when a fighter claims their share of the world boss prize
+#[event]
+pub struct WorldBossRewardClaimed {
+    pub player: Pubkey,
+    pub damage_dealt: u64,
+    pub payout: u64,
+}
+
+/// Emitted when a room's discoverer earns a royalty from someone else's activity there
+#[event]
+pub struct DiscoveryRoyaltyAccrued {
+    pub discoverer: Pubkey,
+    pub room_x: i8,
+    pub room_y: i8,
+    /// See `royalty_sources`
+    pub source: u8,
+    pub amount: u64,
+}
+
+/// Emitted when a discoverer claims accrued royalties
+#[event]
+pub struct DiscoveryRoyaltiesClaimed {
+    pub player: Pubkey,
+    pub payout: u64,
+    pub total_claimed: u64,
+}
+
+/// Emitted when an admin proposes a season reset or config change
+#[event]
+pub struct AdminActionProposed {
+    pub proposer: Pubkey,
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub execute_after_slot: u64,
+    pub approvals_required: u8,
+}
+
+/// Emitted when an admin approves a pending admin action
+#[event]
+pub struct AdminActionApproved {
+    pub proposer: Pubkey,
+    pub action_id: u64,
+    pub approver: Pubkey,
+    pub approval_count: u8,
+}
+
+/// Emitted when an admin cancels a pending admin action
+#[event]
+pub struct AdminActionCancelled {
+    pub proposer: Pubkey,
+    pub action_id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+/// Emitted when a pending admin action runs
+#[event]
+pub struct AdminActionExecuted {
+    pub proposer: Pubkey,
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub executed_by: Pubkey,
+    pub approval_count: u8,
+}
+
+/// Emitted when the pauser switches subsystems on or off
+#[event]
+pub struct PauseFlagsUpdated {
+    pub pauser: Pubkey,
+    /// See `pause_flags`
+    pub paused_flags: u16,
+}
+
+/// Emitted when a role is offered to a new key
+#[event]
+pub struct RoleHandoverProposed {
+    /// See `roles`
+    pub role: u8,
+    pub current_holder: Pubkey,
+    pub proposed_holder: Pubkey,
+    pub proposed_by: Pubkey,
+}
+
+/// Emitted when a role changes hands: the offered key accepts it, or the admin set assigns it
+#[event]
+pub struct RoleAccepted {
+    pub role: u8,
+    pub previous_holder: Pubkey,
+    pub holder: Pubkey,
+}
+
+/// Emitted when the treasury pays a new room's rent back to the explorer
+#[event]
+pub struct RoomRentReimbursed {
+    pub explorer: Pubkey,
+    pub amount: u64,
+    pub season_rent_reimbursed: u64,
+    /// Treasury lamports left above its own rent exemption
+    pub treasury_spare: u64,
+}
+
+/// Low-balance alert: the treasury is nearly out, or just made an explorer pay their own rent
+#[event]
+pub struct TreasuryLow {
+    pub treasury_spare: u64,
+    pub season_budget_remaining: u64,
+    /// True when this room's rent was left to the explorer
+    pub explorer_paid: bool,
+}
+
+/// Emitted when someone tops up the treasury that reimburses room rent
+#[event]
+pub struct TreasuryFunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub treasury_spare: u64,
+    pub total_treasury_funded: u64,
+}
+
 /// Item types for loot
 pub mod item_types {
     pub const ORE: u8 = 0;
@@ -39,7 +39,8 @@ pub struct AbandonJob<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[room.x as u8],
-            &[room.y as u8]
+            &[room.y as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
@@ -52,6 +53,7 @@ pub struct AbandonJob<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -6,7 +6,7 @@ use ephemeral_vrf_sdk::types::SerializableAccountMeta;
 
 use crate::errors::ChainDepthError;
 use crate::events::DuelChallengeAccepted;
-use crate::state::{DuelChallenge, GlobalAccount, PlayerAccount};
+use crate::state::{pause_flags, roles, DuelChallenge, GlobalAccount, PlayerAccount};
 
 #[vrf]
 #[derive(Accounts)]
@@ -74,7 +74,7 @@ pub struct AcceptDuelChallenge<'info> {
     #[account(
         mut,
         constraint = dev_treasury_token_account.mint == global.skr_mint,
-        constraint = dev_treasury_token_account.owner == global.admin
+        constraint = dev_treasury_token_account.owner == global.role_holder(roles::TREASURER)
     )]
     pub dev_treasury_token_account: Box<Account<'info, TokenAccount>>,
 
@@ -86,6 +86,8 @@ pub struct AcceptDuelChallenge<'info> {
 }
 
 pub fn handler(ctx: Context<AcceptDuelChallenge>, _challenge_seed: u64) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::DUELS)?;
+
     let duel_challenge = &ctx.accounts.duel_challenge;
     require!(
         duel_challenge.status == DuelChallenge::STATUS_OPEN,
@@ -114,8 +116,10 @@ pub fn handler(ctx: Context<AcceptDuelChallenge>, _challenge_seed: u64) -> Resul
         ChainDepthError::DuelChallengeExpired
     );
     require!(
-        ctx.accounts.challenger_player_account.current_room_x
-            == ctx.accounts.opponent_player_account.current_room_x
+        ctx.accounts.challenger_player_account.current_floor
+            == ctx.accounts.opponent_player_account.current_floor
+            && ctx.accounts.challenger_player_account.current_room_x
+                == ctx.accounts.opponent_player_account.current_room_x
             && ctx.accounts.challenger_player_account.current_room_y
                 == ctx.accounts.opponent_player_account.current_room_y
             && ctx.accounts.challenger_player_account.current_room_x == duel_challenge.room_x
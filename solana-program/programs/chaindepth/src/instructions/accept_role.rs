@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::RoleAccepted;
+use crate::state::{roles, GlobalAccount};
+
+#[derive(Accounts)]
+pub struct AcceptRole<'info> {
+    pub new_holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+}
+
+pub fn handler(ctx: Context<AcceptRole>, role: u8) -> Result<()> {
+    require!(roles::is_valid(role), ChainDepthError::InvalidRole);
+
+    let global = &mut ctx.accounts.global;
+    let new_holder = ctx.accounts.new_holder.key();
+    let index = usize::from(role);
+    require!(
+        global.pending_role_holders[index] == new_holder,
+        ChainDepthError::NotPendingRoleHolder
+    );
+
+    let previous_holder = global.role_holder(role);
+    global.role_holders[index] = new_holder;
+    global.pending_role_holders[index] = Pubkey::default();
+
+    emit!(RoleAccepted {
+        role,
+        previous_holder,
+        holder: new_holder,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Deserialize an account, zero-extending buffers shorter than `base_len` so
+/// fields appended after it was created read as zero.
+pub(crate) fn decode_padded<T: AccountDeserialize>(
+    account_info: &AccountInfo<'_>,
+    base_len: usize,
+) -> Result<T> {
+    let data = account_info.try_borrow_data()?;
+    if data.len() >= base_len {
+        return T::try_deserialize(&mut &data[..]);
+    }
+    let mut padded = data.to_vec();
+    padded.resize(base_len, 0);
+    T::try_deserialize(&mut &padded[..])
+}
+
+/// Grow a program-owned account, topping up rent from `payer` first.
+pub(crate) fn resize_with_rent<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let top_up = required_lamports.saturating_sub(account_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    if account_info.data_len() < new_len {
+        account_info.resize(new_len)?;
+    }
+    Ok(())
+}
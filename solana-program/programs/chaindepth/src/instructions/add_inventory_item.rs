@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
 use crate::events::InventoryItemAdded;
-use crate::state::InventoryAccount;
+use crate::state::{GrantedItems, InventoryAccount};
 
 #[derive(Accounts)]
 pub struct AddInventoryItem<'info> {
@@ -18,6 +18,16 @@ pub struct AddInventoryItem<'info> {
     )]
     pub inventory: Account<'info, InventoryAccount>,
 
+    /// Marks the wallet so granted items are never minted as NFTs
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = GrantedItems::DISCRIMINATOR.len() + GrantedItems::INIT_SPACE,
+        seeds = [GrantedItems::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub granted_items: Account<'info, GrantedItems>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -26,6 +36,8 @@ pub fn handler(ctx: Context<AddInventoryItem>, item_id: u16, amount: u32, durabi
     require!(amount > 0, ChainDepthError::InvalidItemAmount);
 
     let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
     if inventory.owner == Pubkey::default() {
         inventory.owner = ctx.accounts.player.key();
         inventory.items = Vec::new();
@@ -34,6 +46,16 @@ pub fn handler(ctx: Context<AddInventoryItem>, item_id: u16, amount: u32, durabi
 
     inventory.add_item(item_id, amount, durability)?;
 
+    let granted_items = &mut ctx.accounts.granted_items;
+    if granted_items.owner == Pubkey::default() {
+        granted_items.owner = ctx.accounts.player.key();
+        granted_items.bump = ctx.bumps.granted_items;
+    }
+    granted_items.granted_units = granted_items
+        .granted_units
+        .checked_add(u64::from(amount))
+        .ok_or(ChainDepthError::Overflow)?;
+
     emit!(InventoryItemAdded {
         player: ctx.accounts.player.key(),
         item_id,
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::AdminActionApproved;
+use crate::state::{GlobalAccount, PendingAdminAction};
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingAdminAction::SEED_PREFIX,
+            pending_action.proposer.as_ref(),
+            &pending_action.action_id.to_le_bytes()
+        ],
+        bump = pending_action.bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    require!(
+        ctx.accounts.global.is_admin_signer(&approver),
+        ChainDepthError::NotInAdminSet
+    );
+
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.approve(approver, &ctx.accounts.global)?;
+
+    emit!(AdminActionApproved {
+        proposer: pending_action.proposer,
+        action_id: pending_action.action_id,
+        approver,
+        approval_count: pending_action.approval_count,
+    });
+
+    Ok(())
+}
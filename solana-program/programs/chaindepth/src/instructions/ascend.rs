@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::PlayerChangedFloor;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, session_instruction_bits, GlobalAccount, PlayerAccount, RoomAccount, RoomPresence,
+    SessionAuthority, CENTER_STAIRS_DOWN,
+};
+
+#[derive(Accounts)]
+pub struct Ascend<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Stairs-down room on the floor above; it exists because someone descended through it
+    #[account(
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[stairs_room.x as u8],
+            &[stairs_room.y as u8],
+            RoomAccount::floor_seed(&stairs_room.floor)
+        ],
+        bump
+    )]
+    pub stairs_room: Account<'info, RoomAccount>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump = current_presence.bump
+    )]
+    pub current_presence: Account<'info, RoomPresence>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoomPresence::DISCRIMINATOR.len() + RoomPresence::INIT_SPACE,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[stairs_room.x as u8],
+            &[stairs_room.y as u8],
+            RoomAccount::floor_seed(&stairs_room.floor),
+            player.key().as_ref()
+        ],
+        bump
+    )]
+    pub stairs_presence: Account<'info, RoomPresence>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Ascend>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::CHANGE_FLOOR,
+        0,
+    )?;
+
+    let player_key = ctx.accounts.player.key();
+    let grid = ctx.accounts.global.grid();
+    let clock = Clock::get()?;
+    let player_account = &mut ctx.accounts.player_account;
+    let stairs_room = &ctx.accounts.stairs_room;
+    player_account.require_in_dungeon()?;
+    require!(
+        player_account.current_floor > 0
+            && player_account.is_at_room(grid.start_x, grid.start_y),
+        ChainDepthError::NoStairsUp
+    );
+    require!(
+        stairs_room.center_type == CENTER_STAIRS_DOWN
+            && stairs_room.floor + 1 == player_account.current_floor,
+        ChainDepthError::NoStairsDown
+    );
+    require!(
+        player_account.active_jobs.is_empty(),
+        ChainDepthError::FloorChangeBlockedByActiveJobs
+    );
+    require!(
+        ctx.accounts.current_presence.activity != RoomPresence::ACTIVITY_BOSS_FIGHT,
+        ChainDepthError::AlreadyFightingBoss
+    );
+
+    let from_floor = player_account.current_floor;
+    let from_x = player_account.current_room_x;
+    let from_y = player_account.current_room_y;
+    player_account.current_room_x = stairs_room.x;
+    player_account.current_room_y = stairs_room.y;
+    player_account.current_floor = stairs_room.floor;
+    player_account.mark_active(clock.slot);
+
+    let stairs_presence = &mut ctx.accounts.stairs_presence;
+    if stairs_presence.player == Pubkey::default() {
+        stairs_presence.player = player_key;
+        stairs_presence.season_seed = ctx.accounts.global.season_seed;
+        stairs_presence.room_x = stairs_room.x;
+        stairs_presence.room_y = stairs_room.y;
+        stairs_presence.floor = stairs_room.floor;
+        stairs_presence.bump = ctx.bumps.stairs_presence;
+    }
+    stairs_presence.skin_id = ctx.accounts.current_presence.skin_id;
+    stairs_presence.equipped_item_id = player_account.equipped_item_id;
+    stairs_presence.is_current = true;
+    stairs_presence.set_idle();
+
+    emit!(PlayerChangedFloor {
+        player: player_key,
+        from_floor,
+        to_floor: stairs_room.floor,
+        from_x,
+        from_y,
+        to_x: stairs_room.x,
+        to_y: stairs_room.y,
+    });
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Approve, Token, TokenAccount};
 
 use crate::errors::ChainDepthError;
-use crate::state::{GlobalAccount, PlayerAccount, SessionAuthority};
+use crate::state::{pause_flags, GlobalAccount, PlayerAccount, SessionAuthority};
 
 const MAX_SESSION_DURATION_SLOTS: u64 = 216_000;
 const MAX_SESSION_DURATION_SECONDS: i64 = 86_400;
@@ -57,6 +57,8 @@ pub fn handler(
     instruction_allowlist: u64,
     max_token_spend: u64,
 ) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::SESSIONS)?;
+
     let clock = Clock::get()?;
 
     require!(
@@ -4,7 +4,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::errors::ChainDepthError;
 use crate::events::JobBoosted;
 use crate::instructions::session_auth::authorize_player_action;
-use crate::state::{session_instruction_bits, GlobalAccount, RoomAccount, SessionAuthority};
+use crate::state::{
+    pause_flags, session_instruction_bits, GlobalAccount, RoomAccount, SessionAuthority,
+};
 
 #[derive(Accounts)]
 #[instruction(direction: u8, boost_amount: u64)]
@@ -28,7 +30,8 @@ pub struct BoostJob<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[room.x as u8],
-            &[room.y as u8]
+            &[room.y as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
@@ -64,6 +67,8 @@ pub struct BoostJob<'info> {
 }
 
 pub fn handler(ctx: Context<BoostJob>, direction: u8, boost_amount: u64) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::JOBS)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
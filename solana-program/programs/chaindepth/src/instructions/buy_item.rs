@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ChainDepthError;
+use crate::events::ShopItemBought;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount,
+    SessionAuthority, ShopConfig,
+};
+
+#[derive(Accounts)]
+pub struct BuyItem<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Box<Account<'info, PlayerAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ShopConfig::SEED_PREFIX],
+        bump = shop_config.bump
+    )]
+    pub shop_config: Box<Account<'info, ShopConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = InventoryAccount::DISCRIMINATOR.len() + InventoryAccount::INIT_SPACE,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub inventory: Box<Account<'info, InventoryAccount>>,
+
+    /// Prize pool receives payments unless the shop burns them
+    #[account(
+        mut,
+        constraint = prize_pool.key() == global.prize_pool
+    )]
+    pub prize_pool: Box<Account<'info, TokenAccount>>,
+
+    /// Player's SKR token account
+    #[account(
+        mut,
+        constraint = player_token_account.mint == global.skr_mint,
+        constraint = player_token_account.owner == player.key()
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// SKR mint (mutable for burns)
+    #[account(
+        mut,
+        constraint = skr_mint.key() == global.skr_mint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BuyItem>, item_id: u16, amount: u32) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    let shop_config = &mut ctx.accounts.shop_config;
+    shop_config.sync_season(ctx.accounts.global.season_seed);
+    let (durability, total_price) = shop_config.reserve_purchase(item_id, amount)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::BUY_ITEM,
+        total_price,
+    )?;
+
+    ctx.accounts.player_account.require_out_of_dungeon()?;
+
+    let player_key = ctx.accounts.player.key();
+    let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
+    if inventory.owner == Pubkey::default() {
+        inventory.owner = player_key;
+        inventory.items = Vec::new();
+        inventory.bump = ctx.bumps.inventory;
+    }
+    require!(inventory.owner == player_key, ChainDepthError::Unauthorized);
+    inventory.add_item(item_id, amount, durability)?;
+
+    let burned = ctx.accounts.shop_config.burn_payments;
+    if burned {
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.skr_mint.to_account_info(),
+                from: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        );
+        token::burn(burn_ctx, total_price)?;
+    } else {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_token_account.to_account_info(),
+                to: ctx.accounts.prize_pool.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, total_price)?;
+    }
+
+    emit!(ShopItemBought {
+        player: player_key,
+        item_id,
+        amount,
+        total_price,
+        burned,
+    });
+
+    Ok(())
+}
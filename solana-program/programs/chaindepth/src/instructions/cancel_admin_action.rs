@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::AdminActionCancelled;
+use crate::state::{GlobalAccount, PendingAdminAction};
+
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    /// Any admin can cancel: one veto is enough to stop a bad proposal
+    pub canceller: Signer<'info>,
+
+    /// CHECK: proposer receives the pending action's rent back
+    #[account(mut, address = pending_action.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PendingAdminAction::SEED_PREFIX,
+            pending_action.proposer.as_ref(),
+            &pending_action.action_id.to_le_bytes()
+        ],
+        bump = pending_action.bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+}
+
+pub fn handler(ctx: Context<CancelAdminAction>) -> Result<()> {
+    let canceller = ctx.accounts.canceller.key();
+    let pending_action = &ctx.accounts.pending_action;
+    // The proposer may withdraw even after leaving the admin set.
+    require!(
+        canceller == pending_action.proposer || ctx.accounts.global.is_admin_signer(&canceller),
+        ChainDepthError::NotInAdminSet
+    );
+
+    emit!(AdminActionCancelled {
+        proposer: pending_action.proposer,
+        action_id: pending_action.action_id,
+        cancelled_by: canceller,
+    });
+
+    Ok(())
+}
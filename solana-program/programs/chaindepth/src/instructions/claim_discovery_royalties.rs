@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ChainDepthError;
+use crate::events::{DiscoveryRoyaltiesClaimed, DiscoveryRoyaltyAccrued};
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, session_instruction_bits, DiscoveryRoyaltyAccount, GlobalAccount, RoomAccount,
+    SessionAuthority,
+};
+
+#[derive(Accounts)]
+pub struct ClaimDiscoveryRoyalties<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose royalties are being claimed
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    /// Normally opened on the player's first move; a claim opens it otherwise
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DiscoveryRoyaltyAccount::DISCRIMINATOR.len() + DiscoveryRoyaltyAccount::INIT_SPACE,
+        seeds = [DiscoveryRoyaltyAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub discovery_royalty: Box<Account<'info, DiscoveryRoyaltyAccount>>,
+
+    /// Prize pool pays out royalties
+    #[account(
+        mut,
+        constraint = prize_pool.key() == global.prize_pool
+    )]
+    pub prize_pool: Box<Account<'info, TokenAccount>>,
+
+    /// Player's SKR token account
+    #[account(
+        mut,
+        constraint = player_token_account.mint == global.skr_mint,
+        constraint = player_token_account.owner == player.key()
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimDiscoveryRoyalties>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::CLAIM_DISCOVERY_ROYALTIES,
+        0,
+    )?;
+
+    let player_key = ctx.accounts.player.key();
+    let royalty = &mut ctx.accounts.discovery_royalty;
+    royalty.open(
+        player_key,
+        ctx.accounts.global.season_seed,
+        ctx.bumps.discovery_royalty,
+    );
+
+    // A drained pool pays what it can; the rest stays pending for a later claim.
+    let payout = royalty.pending.min(ctx.accounts.prize_pool.amount);
+    royalty.pending -= payout;
+    royalty.total_claimed = royalty
+        .total_claimed
+        .checked_add(payout)
+        .ok_or(ChainDepthError::Overflow)?;
+
+    if payout > 0 {
+        let global_bump = ctx.accounts.global.bump;
+        let global_seeds = &[GlobalAccount::SEED_PREFIX, &[global_bump]];
+        let global_signer = &[&global_seeds[..]];
+        let payout_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.prize_pool.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.global.to_account_info(),
+            },
+            global_signer,
+        );
+        token::transfer(payout_ctx, payout)?;
+    }
+
+    emit!(DiscoveryRoyaltiesClaimed {
+        player: player_key,
+        payout,
+        total_claimed: ctx.accounts.discovery_royalty.total_claimed,
+    });
+
+    Ok(())
+}
+
+/// Credit the discoverer of `room` for someone else's activity there.
+/// Discoverers earn nothing in their own rooms. The royalty account is opened here
+/// if its owner never got one (rooms discovered before royalties, or the start room).
+pub(crate) fn accrue_discovery_royalty(
+    royalty: &mut Account<DiscoveryRoyaltyAccount>,
+    royalty_bump: u8,
+    room: &RoomAccount,
+    actor: Pubkey,
+    source: u8,
+    amount: u64,
+) {
+    royalty.open(room.created_by, room.season_seed, royalty_bump);
+    if room.created_by == actor {
+        return;
+    }
+
+    let accrued = royalty.accrue(room.season_seed, amount);
+    if accrued > 0 {
+        emit!(DiscoveryRoyaltyAccrued {
+            discoverer: royalty.owner,
+            room_x: room.x,
+            room_y: room.y,
+            source,
+            amount: accrued,
+        });
+    }
+}
@@ -2,11 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::ChainDepthError;
-use crate::events::JobRewardClaimed;
+use crate::events::{JobRewardClaimed, PlayerLeveledUp};
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
     session_instruction_bits, GlobalAccount, HelperStake, PlayerAccount, RoomAccount, RoomPresence,
-    SessionAuthority,
+    SessionAuthority, XP_PER_JOB_COMPLETED,
 };
 
 #[derive(Accounts)]
@@ -38,7 +38,8 @@ pub struct ClaimJobReward<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[room.x as u8],
-            &[room.y as u8]
+            &[room.y as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
@@ -51,6 +52,7 @@ pub struct ClaimJobReward<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -146,6 +148,14 @@ pub fn handler(ctx: Context<ClaimJobReward>, direction: u8) -> Result<()> {
 
     player_account.remove_job(room.x, room.y, direction);
     player_account.mark_active(clock.slot);
+    if player_account.grant_xp(XP_PER_JOB_COMPLETED) {
+        emit!(PlayerLeveledUp {
+            player: ctx.accounts.player.key(),
+            level: player_account.level,
+            xp: player_account.xp,
+            max_hp: player_account.max_hp,
+        });
+    }
     ctx.accounts.room_presence.set_idle();
 
     let room_key = room.key();
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ChainDepthError;
+use crate::events::{WorldBossDefeated, WorldBossRewardClaimed};
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, session_instruction_bits, BossFightAccount, GlobalAccount, SeasonHistoryAccount,
+    SessionAuthority, WorldBossAccount,
+};
+
+#[derive(Accounts)]
+pub struct ClaimWorldBossReward<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose reward is being claimed
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    /// Seeded by its own season so rewards stay claimable after a season reset
+    #[account(
+        mut,
+        seeds = [WorldBossAccount::SEED_PREFIX, &world_boss.season_seed.to_le_bytes()],
+        bump = world_boss.bump
+    )]
+    pub world_boss: Box<Account<'info, WorldBossAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SeasonHistoryAccount::SEED_PREFIX, &world_boss.season_seed.to_le_bytes()],
+        bump = season_history.bump
+    )]
+    pub season_history: Box<Account<'info, SeasonHistoryAccount>>,
+
+    #[account(
+        mut,
+        seeds = [BossFightAccount::SEED_PREFIX, world_boss.key().as_ref(), player.key().as_ref()],
+        bump = boss_fight.bump
+    )]
+    pub boss_fight: Box<Account<'info, BossFightAccount>>,
+
+    /// Prize pool pays out the world boss reward
+    #[account(
+        mut,
+        constraint = prize_pool.key() == global.prize_pool
+    )]
+    pub prize_pool: Box<Account<'info, TokenAccount>>,
+
+    /// Player's SKR token account
+    #[account(
+        mut,
+        constraint = player_token_account.mint == global.skr_mint,
+        constraint = player_token_account.owner == player.key()
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimWorldBossReward>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::BOSSES)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::CLAIM_WORLD_BOSS_REWARD,
+        0,
+    )?;
+
+    let clock = Clock::get()?;
+    let world_boss = &mut ctx.accounts.world_boss;
+    world_boss.apply_damage(clock.slot)?;
+    require!(world_boss.defeated, ChainDepthError::BossNotDefeated);
+
+    let boss_fight = &mut ctx.accounts.boss_fight;
+    require!(
+        !boss_fight.is_active,
+        ChainDepthError::LeaveWorldBossBeforeClaiming
+    );
+    require!(
+        !boss_fight.reward_claimed,
+        ChainDepthError::WorldBossRewardClaimed
+    );
+
+    // The first claim fixes the prize from the pool as it stands and records the kill.
+    let season_history = &mut ctx.accounts.season_history;
+    if !season_history.world_boss_defeated {
+        world_boss.prize_amount = WorldBossAccount::prize_for_pool(ctx.accounts.prize_pool.amount);
+        season_history.record_world_boss_defeat(world_boss);
+        emit!(WorldBossDefeated {
+            season_seed: world_boss.season_seed,
+            defeated_slot: world_boss.defeated_slot,
+            participants: world_boss.participant_count,
+            total_damage: world_boss.total_damage,
+            prize_amount: world_boss.prize_amount,
+        });
+    }
+
+    let payout = world_boss.reward_for_damage(boss_fight.damage_dealt);
+    boss_fight.reward_claimed = true;
+    world_boss.prize_paid = world_boss
+        .prize_paid
+        .checked_add(payout)
+        .ok_or(ChainDepthError::Overflow)?;
+
+    if payout > 0 {
+        require!(
+            ctx.accounts.prize_pool.amount >= payout,
+            ChainDepthError::PrizePoolInsufficientFunds
+        );
+        let global_bump = ctx.accounts.global.bump;
+        let global_seeds = &[GlobalAccount::SEED_PREFIX, &[global_bump]];
+        let global_signer = &[&global_seeds[..]];
+        let payout_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.prize_pool.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.global.to_account_info(),
+            },
+            global_signer,
+        );
+        token::transfer(payout_ctx, payout)?;
+    }
+
+    emit!(WorldBossRewardClaimed {
+        player: ctx.accounts.player.key(),
+        damage_dealt: boss_fight.damage_dealt,
+        payout,
+    });
+
+    Ok(())
+}
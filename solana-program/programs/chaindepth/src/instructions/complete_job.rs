@@ -3,11 +3,14 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::ChainDepthError;
 use crate::events::JobCompleted;
+use crate::instructions::claim_discovery_royalties::accrue_discovery_royalty;
+use crate::instructions::fund_treasury::reimburse_room_rent;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    calculate_depth, enforce_special_room_topology, initialize_discovered_room,
-    session_instruction_bits, GlobalAccount, HelperStake, PlayerAccount, RoomAccount,
-    SessionAuthority, LOCK_KIND_NONE, WALL_OPEN,
+    enforce_special_room_topology, floor_depth, initialize_discovered_room, pause_flags,
+    royalty_sources, session_instruction_bits, DiscoveryRoyaltyAccount, FloorConnectivity,
+    GlobalAccount, HelperStake, PlayerAccount, RoomAccount, RoomLocation, SessionAuthority,
+    LOCK_KIND_NONE, WALL_OPEN,
 };
 
 #[derive(Accounts)]
@@ -40,7 +43,8 @@ pub struct CompleteJob<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[room.x as u8],
-            &[room.y as u8]
+            &[room.y as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
@@ -67,12 +71,27 @@ pub struct CompleteJob<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[adjacent_x(room.x, direction) as u8],
-            &[adjacent_y(room.y, direction) as u8]
+            &[adjacent_y(room.y, direction) as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
     pub adjacent_room: Box<Account<'info, RoomAccount>>,
 
+    /// Connectivity repairs of this floor; the first discovery on the floor fills them in
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = FloorConnectivity::DISCRIMINATOR.len() + FloorConnectivity::INIT_SPACE,
+        seeds = [
+            FloorConnectivity::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[room.floor]
+        ],
+        bump
+    )]
+    pub floor_connectivity: Box<Account<'info, FloorConnectivity>>,
+
     /// Escrow holding staked SKR (and bonus after completion)
     #[account(
         mut,
@@ -88,6 +107,16 @@ pub struct CompleteJob<'info> {
     )]
     pub prize_pool: Box<Account<'info, TokenAccount>>,
 
+    /// Royalty account of the room's discoverer, opened by the caller if it is missing
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DiscoveryRoyaltyAccount::DISCRIMINATOR.len() + DiscoveryRoyaltyAccount::INIT_SPACE,
+        seeds = [DiscoveryRoyaltyAccount::SEED_PREFIX, room.created_by.as_ref()],
+        bump
+    )]
+    pub discoverer_royalty: Box<Account<'info, DiscoveryRoyaltyAccount>>,
+
     #[account(
         mut,
         seeds = [
@@ -120,6 +149,8 @@ fn adjacent_y(y: i8, direction: u8) -> i8 {
 }
 
 pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::JOBS)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -174,8 +205,10 @@ pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
     let global_account_info = ctx.accounts.global.to_account_info();
     let room_x = ctx.accounts.room.x;
     let room_y = ctx.accounts.room.y;
+    let floor = ctx.accounts.room.floor;
     let helper_count = ctx.accounts.room.helper_counts[dir_idx] as u64;
     let season_seed = ctx.accounts.global.season_seed;
+    let grid = ctx.accounts.global.grid();
     let global_bump = ctx.accounts.global.bump;
 
     {
@@ -199,12 +232,20 @@ pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
         is_new_adjacent_room = adjacent.season_seed == 0;
 
         if is_new_adjacent_room {
+            let (adjacent_room_x, adjacent_room_y) =
+                (adjacent_x(room_x, direction), adjacent_y(room_y, direction));
+            let floor_connectivity = &mut ctx.accounts.floor_connectivity;
+            floor_connectivity.ensure_analyzed(
+                season_seed,
+                floor,
+                grid,
+                ctx.bumps.floor_connectivity,
+            );
             initialize_discovered_room(
                 adjacent,
-                season_seed,
-                adjacent_x(room_x, direction),
-                adjacent_y(room_y, direction),
+                RoomLocation::new(season_seed, grid, floor, adjacent_room_x, adjacent_room_y),
                 opposite_dir,
+                floor_connectivity.repaired_directions(grid, adjacent_room_x, adjacent_room_y),
                 ctx.accounts.player.key(),
                 clock.slot,
                 ctx.bumps.adjacent_room,
@@ -213,7 +254,7 @@ pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
 
         adjacent.walls[opposite_dir as usize] = WALL_OPEN;
         adjacent.door_lock_kinds[opposite_dir as usize] = LOCK_KIND_NONE;
-        enforce_special_room_topology(adjacent);
+        enforce_special_room_topology(adjacent, grid);
         let return_wall_state = adjacent.walls[opposite_dir as usize];
         msg!(
             "complete_job_topology from=({}, {}) to=({}, {}) dir={} return_dir={} return_wall_state={}",
@@ -232,7 +273,7 @@ pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
     }
 
     // --- Token bonus transfer (CPI) BEFORE lamport manipulation ---
-    let new_depth = calculate_depth(ctx.accounts.adjacent_room.x, ctx.accounts.adjacent_room.y);
+    let new_depth = floor_depth(grid, floor, ctx.accounts.adjacent_room.x, ctx.accounts.adjacent_room.y);
     {
         let global = &mut ctx.accounts.global;
         if new_depth > global.depth {
@@ -266,33 +307,10 @@ pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
     // --- Reimburse authority for adjacent room rent (manual lamport transfer) ---
     // Done AFTER all CPIs to avoid interference with runtime balance tracking.
     if is_new_adjacent_room {
-        let room_space = 8 + RoomAccount::INIT_SPACE;
-        let rent_cost = Rent::get()?.minimum_balance(room_space);
-
-        let global_info = ctx.accounts.global.to_account_info();
-        let authority_info = ctx.accounts.authority.to_account_info();
-
-        msg!(
-            "reimburse: rent_cost={} global_before={} auth_before={}",
-            rent_cost,
-            global_info.lamports(),
-            authority_info.lamports()
-        );
-
-        **global_info.try_borrow_mut_lamports()? = global_info
-            .lamports()
-            .checked_sub(rent_cost)
-            .ok_or(ChainDepthError::TreasuryInsufficientFunds)?;
-        **authority_info.try_borrow_mut_lamports()? = authority_info
-            .lamports()
-            .checked_add(rent_cost)
-            .ok_or(ChainDepthError::Overflow)?;
-
-        msg!(
-            "reimburse: global_after={} auth_after={}",
-            global_info.lamports(),
-            authority_info.lamports()
-        );
+        reimburse_room_rent(
+            &mut ctx.accounts.global,
+            &ctx.accounts.authority.to_account_info(),
+        )?;
     }
 
     let bonus_per_helper = bonus_total / helper_count;
@@ -300,6 +318,14 @@ pub fn handler(ctx: Context<CompleteJob>, direction: u8) -> Result<()> {
         let room = &mut ctx.accounts.room;
         room.bonus_per_helper[dir_idx] = bonus_per_helper;
     }
+    accrue_discovery_royalty(
+        &mut ctx.accounts.discoverer_royalty,
+        ctx.bumps.discoverer_royalty,
+        &ctx.accounts.room,
+        ctx.accounts.player.key(),
+        royalty_sources::JOB_COMPLETED,
+        DiscoveryRoyaltyAccount::JOB_COMPLETED_ROYALTY,
+    );
 
     emit!(JobCompleted {
         room_x,
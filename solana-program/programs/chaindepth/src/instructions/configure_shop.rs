@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::state::{roles, GlobalAccount, ShopConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureShop<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump,
+        constraint = global.is_role_holder(roles::CONFIG_EDITOR, &admin.key()) @ ChainDepthError::Unauthorized,
+        constraint = !global.has_admin_set() @ ChainDepthError::AdminActionRequiresProposal
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ShopConfig::DISCRIMINATOR.len() + ShopConfig::INIT_SPACE,
+        seeds = [ShopConfig::SEED_PREFIX],
+        bump
+    )]
+    pub shop_config: Account<'info, ShopConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConfigureShop>, burn_payments: bool) -> Result<()> {
+    let shop_config = &mut ctx.accounts.shop_config;
+    shop_config.init_if_new(ctx.accounts.global.season_seed, ctx.bumps.shop_config);
+    shop_config.burn_payments = burn_payments;
+
+    Ok(())
+}
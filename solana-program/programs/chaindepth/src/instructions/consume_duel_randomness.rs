@@ -3,7 +3,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::ChainDepthError;
 use crate::events::DuelSettled;
-use crate::state::{DuelChallenge, GlobalAccount, MAX_DUEL_HITS_PER_PLAYER};
+use crate::state::{
+    pause_flags, roles, DuelChallenge, GlobalAccount, MAX_DUEL_HITS_PER_PLAYER,
+};
 
 const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
 const DUEL_WINNER_TAX_BASIS_POINTS: u64 = 200;
@@ -38,7 +40,7 @@ pub struct ConsumeDuelRandomness<'info> {
     #[account(
         mut,
         constraint = dev_treasury_token_account.mint == global.skr_mint,
-        constraint = dev_treasury_token_account.owner == global.admin
+        constraint = dev_treasury_token_account.owner == global.role_holder(roles::TREASURER)
     )]
     pub dev_treasury_token_account: Account<'info, TokenAccount>,
 
@@ -49,6 +51,8 @@ pub fn handler(
     ctx: Context<ConsumeDuelRandomness>,
     randomness: [u8; 32],
 ) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::DUELS)?;
+
     let duel_challenge = &mut ctx.accounts.duel_challenge;
     require!(
         duel_challenge.status == DuelChallenge::STATUS_PENDING_RANDOMNESS,
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::BossTicked;
+use crate::instructions::join_boss_fight::{apply_boss_damage, resolve_player_boss_damage};
+use crate::state::{
+    pause_flags, BossFightAccount, GlobalAccount, InventoryAccount, PlayerAccount, RoomAccount,
+    RoomPresence, CENTER_BOSS,
+};
+
+#[derive(Accounts)]
+pub struct CrankBossFight<'info> {
+    pub cranker: Signer<'info>,
+
+    /// CHECK: fighter being settled; only used as a PDA seed
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
+        ],
+        bump
+    )]
+    pub room: Account<'info, RoomAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump = room_presence.bump
+    )]
+    pub room_presence: Account<'info, RoomPresence>,
+
+    #[account(
+        mut,
+        seeds = [BossFightAccount::SEED_PREFIX, room.key().as_ref(), player.key().as_ref()],
+        bump = boss_fight.bump
+    )]
+    pub boss_fight: Account<'info, BossFightAccount>,
+
+    /// Joining a boss fight creates the inventory, so it always exists here
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = inventory.bump
+    )]
+    pub inventory: Account<'info, InventoryAccount>,
+}
+
+pub fn handler(ctx: Context<CrankBossFight>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::BOSSES)?;
+
+    let grid = ctx.accounts.global.grid();
+    let room = &mut ctx.accounts.room;
+    let clock = Clock::get()?;
+    ctx.accounts.player_account.require_in_dungeon()?;
+    require!(room.center_type == CENTER_BOSS, ChainDepthError::NoBoss);
+    require!(!room.boss_defeated, ChainDepthError::BossAlreadyDefeated);
+    require!(ctx.accounts.boss_fight.is_active, ChainDepthError::NotBossFighter);
+
+    // Not marked active: the fighter did nothing, someone else settled them.
+    resolve_player_boss_damage(
+        room,
+        grid,
+        &mut ctx.accounts.player_account,
+        &mut ctx.accounts.room_presence,
+        &mut ctx.accounts.boss_fight,
+        &mut ctx.accounts.inventory,
+        clock.slot,
+    )?;
+    apply_boss_damage(room, clock.slot)?;
+
+    emit!(BossTicked {
+        room_x: room.x,
+        room_y: room.y,
+        boss_id: room.center_id,
+        current_hp: room.boss_current_hp,
+        max_hp: room.boss_max_hp,
+        fighter_count: room.boss_fighter_count,
+    });
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::ChainDepthError;
 use crate::events::DuelChallengeCreated;
-use crate::state::{DuelChallenge, GlobalAccount, PlayerAccount, PlayerProfile};
+use crate::state::{pause_flags, DuelChallenge, GlobalAccount, PlayerAccount, PlayerProfile};
 
 #[derive(Accounts)]
 #[instruction(challenge_seed: u64)]
@@ -92,6 +92,8 @@ pub fn handler(
     stake_amount: u64,
     expires_at_slot: u64,
 ) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::DUELS)?;
+
     let challenger = ctx.accounts.challenger.key();
     let opponent = ctx.accounts.opponent.key();
     require!(challenger != opponent, ChainDepthError::InvalidDuelOpponent);
@@ -109,7 +111,8 @@ pub fn handler(
     challenger_player_account.require_in_dungeon()?;
     opponent_player_account.require_in_dungeon()?;
     require!(
-        challenger_player_account.current_room_x == opponent_player_account.current_room_x
+        challenger_player_account.current_floor == opponent_player_account.current_floor
+            && challenger_player_account.current_room_x == opponent_player_account.current_room_x
             && challenger_player_account.current_room_y == opponent_player_account.current_room_y,
         ChainDepthError::PlayersNotInSameRoom
     );
@@ -4,7 +4,7 @@ use crate::errors::ChainDepthError;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
     item_ids, session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount,
-    PlayerProfile, RoomPresence, SessionAuthority,
+    PlayerProfile, RoomAccount, RoomPresence, SessionAuthority,
 };
 
 #[derive(Accounts)]
@@ -54,6 +54,7 @@ pub struct CreatePlayerProfile<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -88,6 +89,8 @@ pub fn handler(ctx: Context<CreatePlayerProfile>, skin_id: u16, display_name: St
     let player_key = ctx.accounts.player.key();
     let profile = &mut ctx.accounts.profile;
     let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
     let room_presence = &mut ctx.accounts.room_presence;
     let player_account = &mut ctx.accounts.player_account;
 
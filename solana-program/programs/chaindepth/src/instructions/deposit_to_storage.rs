@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::StorageItemDeposited;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount,
+    SessionAuthority, StorageAccount,
+};
+
+#[derive(Accounts)]
+pub struct DepositToStorage<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = inventory.bump,
+        constraint = inventory.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub inventory: Account<'info, InventoryAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = StorageAccount::DISCRIMINATOR.len() + StorageAccount::INIT_SPACE,
+        seeds = [StorageAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub storage: Account<'info, StorageAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<DepositToStorage>,
+    item_id: u16,
+    amount: u32,
+    durability: u16,
+) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::DEPOSIT_TO_STORAGE,
+        0,
+    )?;
+
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.require_out_of_dungeon()?;
+
+    let player_key = ctx.accounts.player.key();
+    let storage = &mut ctx.accounts.storage;
+    let storage_len = storage.to_account_info().data_len();
+    storage.reset_stale_bonus_slots(storage_len);
+    if storage.owner == Pubkey::default() {
+        storage.owner = player_key;
+        storage.items = Vec::new();
+        storage.bump = ctx.bumps.storage;
+    }
+    require!(storage.owner == player_key, ChainDepthError::Unauthorized);
+
+    let inventory = &mut ctx.accounts.inventory;
+    inventory.remove_stack(item_id, durability, amount)?;
+    storage.add_item(item_id, amount, durability)?;
+
+    // Banking the last equipped weapon leaves nothing to fight with; unequip it.
+    if player_account.equipped_item_id == item_id && inventory.item_count(item_id) == 0 {
+        player_account.equipped_item_id = 0;
+    }
+
+    emit!(StorageItemDeposited {
+        player: player_key,
+        item_id,
+        amount,
+        durability,
+    });
+
+    Ok(())
+}
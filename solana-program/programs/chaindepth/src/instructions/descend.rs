@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::PlayerChangedFloor;
+use crate::instructions::fund_treasury::reimburse_room_rent;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    floor_depth, initialize_floor_arrival_room, pause_flags, session_instruction_bits,
+    GlobalAccount, PlayerAccount, RoomAccount, RoomPresence, SessionAuthority, CENTER_STAIRS_DOWN,
+};
+
+#[derive(Accounts)]
+pub struct Descend<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    /// Global game state - also acts as the SOL treasury for room creation rent
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Stairs-down room the player is standing in
+    #[account(
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
+        ],
+        bump
+    )]
+    pub current_room: Account<'info, RoomAccount>,
+
+    /// Arrival room at the center of the floor below (initialized on first descent)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RoomAccount::INIT_SPACE,
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8],
+            &[player_account.current_floor.saturating_add(1)]
+        ],
+        bump
+    )]
+    pub arrival_room: Account<'info, RoomAccount>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump = current_presence.bump
+    )]
+    pub current_presence: Account<'info, RoomPresence>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoomPresence::DISCRIMINATOR.len() + RoomPresence::INIT_SPACE,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8],
+            &[player_account.current_floor.saturating_add(1)],
+            player.key().as_ref()
+        ],
+        bump
+    )]
+    pub arrival_presence: Account<'info, RoomPresence>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Descend>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::CHANGE_FLOOR,
+        0,
+    )?;
+
+    let player_key = ctx.accounts.player.key();
+    let grid = ctx.accounts.global.grid();
+    let clock = Clock::get()?;
+    let player_account = &mut ctx.accounts.player_account;
+    let current_room = &ctx.accounts.current_room;
+    player_account.require_in_dungeon()?;
+    require!(
+        current_room.center_type == CENTER_STAIRS_DOWN
+            && player_account.current_floor < GlobalAccount::MAX_FLOOR,
+        ChainDepthError::NoStairsDown
+    );
+    require!(
+        player_account.active_jobs.is_empty(),
+        ChainDepthError::FloorChangeBlockedByActiveJobs
+    );
+    require!(
+        ctx.accounts.current_presence.activity != RoomPresence::ACTIVITY_BOSS_FIGHT,
+        ChainDepthError::AlreadyFightingBoss
+    );
+
+    let from_floor = player_account.current_floor;
+    let to_floor = from_floor + 1;
+    let arrival_room = &mut ctx.accounts.arrival_room;
+    let is_new_room = arrival_room.season_seed == 0;
+    if is_new_room {
+        initialize_floor_arrival_room(
+            arrival_room,
+            ctx.accounts.global.season_seed,
+            grid,
+            to_floor,
+            player_key,
+            clock.slot,
+            ctx.bumps.arrival_room,
+        );
+
+        // Reimburse authority for room creation rent from treasury, as in move_player.
+        reimburse_room_rent(
+            &mut ctx.accounts.global,
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+    }
+
+    let arrival_depth = floor_depth(grid, to_floor, grid.start_x, grid.start_y);
+    if arrival_depth > ctx.accounts.global.depth {
+        ctx.accounts.global.depth = arrival_depth;
+    }
+
+    let from_x = player_account.current_room_x;
+    let from_y = player_account.current_room_y;
+    player_account.current_room_x = grid.start_x;
+    player_account.current_room_y = grid.start_y;
+    player_account.current_floor = to_floor;
+    player_account.mark_active(clock.slot);
+
+    let arrival_presence = &mut ctx.accounts.arrival_presence;
+    if arrival_presence.player == Pubkey::default() {
+        arrival_presence.player = player_key;
+        arrival_presence.season_seed = ctx.accounts.global.season_seed;
+        arrival_presence.room_x = grid.start_x;
+        arrival_presence.room_y = grid.start_y;
+        arrival_presence.floor = to_floor;
+        arrival_presence.bump = ctx.bumps.arrival_presence;
+    }
+    arrival_presence.skin_id = ctx.accounts.current_presence.skin_id;
+    arrival_presence.equipped_item_id = player_account.equipped_item_id;
+    arrival_presence.is_current = true;
+    arrival_presence.set_idle();
+
+    emit!(PlayerChangedFloor {
+        player: player_key,
+        from_floor,
+        to_floor,
+        from_x,
+        from_y,
+        to_x: grid.start_x,
+        to_y: grid.start_y,
+    });
+
+    Ok(())
+}
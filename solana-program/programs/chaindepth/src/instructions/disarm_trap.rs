@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::TrapDisarmed;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    item_ids, pause_flags, session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount,
+    RoomAccount, SessionAuthority, WALL_OPEN,
+};
+
+#[derive(Accounts)]
+#[instruction(direction: u8)]
+pub struct DisarmTrap<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
+        ],
+        bump
+    )]
+    pub room: Account<'info, RoomAccount>,
+
+    /// Trap room on the other side of an open door
+    #[account(
+        mut,
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[RoomAccount::adjacent_coords(room.x, room.y, direction).0 as u8],
+            &[RoomAccount::adjacent_coords(room.x, room.y, direction).1 as u8],
+            RoomAccount::floor_seed(&room.floor)
+        ],
+        bump
+    )]
+    pub trap_room: Account<'info, RoomAccount>,
+
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = inventory.bump,
+        constraint = inventory.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub inventory: Account<'info, InventoryAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+}
+
+pub fn handler(ctx: Context<DisarmTrap>, direction: u8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::DISARM_TRAP,
+        0,
+    )?;
+
+    require!(
+        RoomAccount::is_valid_direction(direction),
+        ChainDepthError::InvalidDirection
+    );
+
+    let room = &ctx.accounts.room;
+    let player_account = &ctx.accounts.player_account;
+    player_account.require_in_dungeon()?;
+    require!(
+        player_account.is_at_room(room.x, room.y),
+        ChainDepthError::NotInRoom
+    );
+    require!(
+        room.walls[direction as usize] == WALL_OPEN,
+        ChainDepthError::WallNotOpen
+    );
+
+    let trap_room = &mut ctx.accounts.trap_room;
+    require!(trap_room.is_trap_armed(), ChainDepthError::NoArmedTrap);
+
+    ctx.accounts.inventory.remove_item(item_ids::TRAP_KIT, 1)?;
+    trap_room.disarm_trap();
+
+    emit!(TrapDisarmed {
+        player: ctx.accounts.player.key(),
+        room_x: trap_room.x,
+        room_y: trap_room.y,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
-use crate::state::{GlobalAccount, RoomAccount, CENTER_EMPTY};
+use crate::state::{biome_for_depth, GlobalAccount, RoomAccount, CENTER_EMPTY};
 
 #[derive(Accounts)]
 pub struct EnsureStartRoom<'info> {
@@ -22,8 +22,8 @@ pub struct EnsureStartRoom<'info> {
         seeds = [
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
-            &[GlobalAccount::START_X as u8],
-            &[GlobalAccount::START_Y as u8]
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8]
         ],
         bump
     )]
@@ -43,13 +43,15 @@ pub fn handler(ctx: Context<EnsureStartRoom>) -> Result<()> {
     }
 
     let clock = Clock::get()?;
-    start_room.x = GlobalAccount::START_X;
-    start_room.y = GlobalAccount::START_Y;
+    let grid = ctx.accounts.global.grid();
+    start_room.x = grid.start_x;
+    start_room.y = grid.start_y;
     start_room.season_seed = ctx.accounts.global.season_seed;
     start_room.walls = RoomAccount::generate_start_walls(
         ctx.accounts.global.season_seed,
-        GlobalAccount::START_X,
-        GlobalAccount::START_Y,
+        grid,
+        grid.start_x,
+        grid.start_y,
     );
     start_room.helper_counts = [0; 4];
     start_room.progress = [0; 4];
@@ -63,12 +65,16 @@ pub fn handler(ctx: Context<EnsureStartRoom>) -> Result<()> {
     start_room.forced_key_drop = false;
     start_room.center_type = CENTER_EMPTY;
     start_room.center_id = 0;
+    start_room.biome_id = biome_for_depth(0).id;
     start_room.boss_max_hp = 0;
     start_room.boss_current_hp = 0;
     start_room.boss_last_update_slot = clock.slot;
     start_room.boss_total_dps = 0;
     start_room.boss_fighter_count = 0;
     start_room.boss_defeated = false;
+    start_room.boss_modifiers = 0;
+    start_room.boss_engaged_slot = 0;
+    start_room.trap_disarmed = false;
     start_room.looted_count = 0;
     start_room.created_by = ctx.accounts.authority.key();
     start_room.created_slot = clock.slot;
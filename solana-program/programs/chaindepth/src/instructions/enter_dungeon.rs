@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use crate::events::PlayerMoved;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    session_instruction_bits, GlobalAccount, PlayerAccount, PlayerProfile, RoomAccount,
-    RoomPresence, SessionAuthority, DEFAULT_PLAYER_MAX_HP,
+    pause_flags, session_instruction_bits, GlobalAccount, PlayerAccount, PlayerProfile,
+    RoomAccount, RoomPresence, SessionAuthority, DEFAULT_PLAYER_MAX_HP,
 };
 
 #[derive(Accounts)]
@@ -43,8 +43,8 @@ pub struct EnterDungeon<'info> {
         seeds = [
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
-            &[GlobalAccount::START_X as u8],
-            &[GlobalAccount::START_Y as u8]
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8]
         ],
         bump
     )]
@@ -57,8 +57,8 @@ pub struct EnterDungeon<'info> {
         seeds = [
             RoomPresence::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
-            &[GlobalAccount::START_X as u8],
-            &[GlobalAccount::START_Y as u8],
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8],
             player.key().as_ref()
         ],
         bump
@@ -80,6 +80,8 @@ pub struct EnterDungeon<'info> {
 }
 
 pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -90,6 +92,7 @@ pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
 
     let clock = Clock::get()?;
     let global = &ctx.accounts.global;
+    let grid = global.grid();
     let player_key = ctx.accounts.player.key();
     let player = &mut ctx.accounts.player_account;
     let profile = &mut ctx.accounts.profile;
@@ -102,8 +105,8 @@ pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
 
     if !was_initialized {
         player.owner = player_key;
-        player.current_room_x = GlobalAccount::START_X;
-        player.current_room_y = GlobalAccount::START_Y;
+        player.current_room_x = grid.start_x;
+        player.current_room_y = grid.start_y;
         player.active_jobs = Vec::new();
         player.jobs_completed = 0;
         player.chests_looted = 0;
@@ -118,16 +121,23 @@ pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
         player.data_version = PlayerAccount::CURRENT_DATA_VERSION;
         player.last_active_slot = clock.slot;
         player.bump = player_bump;
+        player.xp = 0;
+        player.level = 1;
+        player.current_floor = 0;
     } else {
+        // Legacy layouts cannot be read safely here; migrate_player_account upgrades them.
+        player.require_current_version()?;
+
         // Explicit enter is only for starting a run when currently out of dungeon.
         // If already in-dungeon, do not reset HP/position/run state.
         if player.in_dungeon {
             return Ok(());
         }
 
-        // Any explicit enter starts/restarts the run at room (10,10).
-        player.current_room_x = GlobalAccount::START_X;
-        player.current_room_y = GlobalAccount::START_Y;
+        // Any explicit enter starts/restarts the run at room (10,10) on the surface floor.
+        player.current_room_x = grid.start_x;
+        player.current_room_y = grid.start_y;
+        player.current_floor = 0;
         player.in_dungeon = true;
         player.current_run_start_slot = clock.slot;
         player.current_hp = player.max_hp.max(DEFAULT_PLAYER_MAX_HP);
@@ -136,7 +146,6 @@ pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
         }
         player.active_jobs = Vec::new();
         player.last_active_slot = clock.slot;
-        player.data_version = PlayerAccount::CURRENT_DATA_VERSION;
     }
 
     if profile.owner == Pubkey::default() {
@@ -149,8 +158,8 @@ pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
 
     room_presence.player = player_key;
     room_presence.season_seed = global.season_seed;
-    room_presence.room_x = GlobalAccount::START_X;
-    room_presence.room_y = GlobalAccount::START_Y;
+    room_presence.room_x = grid.start_x;
+    room_presence.room_y = grid.start_y;
     room_presence.skin_id = profile.skin_id;
     room_presence.equipped_item_id = player.equipped_item_id;
     room_presence.set_idle();
@@ -162,15 +171,15 @@ pub fn handler(ctx: Context<EnterDungeon>) -> Result<()> {
         from_x: if was_initialized {
             previous_x
         } else {
-            GlobalAccount::START_X
+            grid.start_x
         },
         from_y: if was_initialized {
             previous_y
         } else {
-            GlobalAccount::START_Y
+            grid.start_y
         },
-        to_x: GlobalAccount::START_X,
-        to_y: GlobalAccount::START_Y,
+        to_x: grid.start_x,
+        to_y: grid.start_y,
     });
 
     // Make start_room a required, validated account in the context.
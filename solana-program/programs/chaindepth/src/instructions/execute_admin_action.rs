@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::{
+    AdminActionExecuted, DeepExitScoreUpdated, RoleAccepted, SeasonGridScheduled, SeasonReset,
+    ShopConfigUpdated,
+};
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::instructions::reset_season::apply_season_reset;
+use crate::state::{AdminAction, GlobalAccount, PendingAdminAction, ShopConfig};
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: proposer receives the pending action's rent back
+    #[account(mut, address = pending_action.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: decoded manually so a global created before the latest fields
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PendingAdminAction::SEED_PREFIX,
+            pending_action.proposer.as_ref(),
+            &pending_action.action_id.to_le_bytes()
+        ],
+        bump = pending_action.bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    /// Only needed for shop actions
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = ShopConfig::DISCRIMINATOR.len() + ShopConfig::INIT_SPACE,
+        seeds = [ShopConfig::SEED_PREFIX],
+        bump
+    )]
+    pub shop_config: Option<Account<'info, ShopConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let mut global: GlobalAccount = decode_padded(&global_info, global_space)?;
+    let executor = ctx.accounts.executor.key();
+    require!(
+        global.is_admin_signer(&executor),
+        ChainDepthError::NotInAdminSet
+    );
+
+    let clock = Clock::get()?;
+    let pending_action = &ctx.accounts.pending_action;
+    require!(
+        clock.slot >= pending_action.execute_after_slot,
+        ChainDepthError::AdminActionTimelocked
+    );
+    // Approvals are counted against the admin set as it is now, not when they were given.
+    let approval_count = global.count_admin_approvals(pending_action.approvals());
+    require!(
+        approval_count >= global.admin_threshold(),
+        ChainDepthError::AdminActionNeedsApprovals
+    );
+
+    let action = pending_action.action;
+    action.validate()?;
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.executor,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+
+    if action.is_shop_action() {
+        let shop_config = ctx
+            .accounts
+            .shop_config
+            .as_mut()
+            .ok_or(ChainDepthError::ShopConfigRequired)?;
+        shop_config.init_if_new(global.season_seed, ctx.bumps.shop_config.unwrap_or_default());
+        action.apply_shop(shop_config)?;
+    }
+
+    match action {
+        AdminAction::ForceResetSeason => {
+            let (old_seed, new_seed, old_depth, end_slot) =
+                apply_season_reset(&mut global, clock.slot);
+            emit!(SeasonReset {
+                old_seed,
+                new_seed,
+                old_depth,
+                end_slot,
+            });
+        }
+        AdminAction::SetDeepExitScore { score_bps } => {
+            action.apply_config(&mut global);
+            emit!(DeepExitScoreUpdated {
+                admin: executor,
+                score_bps,
+            });
+        }
+        AdminAction::SetSeasonGrid { grid } => {
+            action.apply_config(&mut global);
+            emit!(SeasonGridScheduled {
+                admin: executor,
+                width: grid.width,
+                height: grid.height,
+                start_x: grid.start_x,
+                start_y: grid.start_y,
+            });
+        }
+        AdminAction::SetRole { role, .. } => {
+            let previous_holder = global.role_holder(role);
+            action.apply_config(&mut global);
+            emit!(RoleAccepted {
+                role,
+                previous_holder,
+                holder: global.role_holder(role),
+            });
+        }
+        AdminAction::SetAdminSet { .. } | AdminAction::SetRentBudget { .. } => {
+            action.apply_config(&mut global)
+        }
+        AdminAction::ConfigureShop { .. } => {}
+        AdminAction::SetShopListing {
+            item_id,
+            price,
+            stock_per_season,
+            ..
+        } => emit!(ShopConfigUpdated {
+            admin: executor,
+            item_id,
+            is_listing: true,
+            price,
+            stock_per_season,
+        }),
+        AdminAction::SetValuableSellPrice { item_id, price } => emit!(ShopConfigUpdated {
+            admin: executor,
+            item_id,
+            is_listing: false,
+            price,
+            stock_per_season: 0,
+        }),
+    }
+
+    {
+        let mut data = global_info.try_borrow_mut_data()?;
+        global.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(AdminActionExecuted {
+        proposer: pending_action.proposer,
+        action_id: pending_action.action_id,
+        action,
+        executed_by: executor,
+        approval_count,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
-use crate::events::{DungeonExitItemScored, DungeonExited};
+use crate::events::{DungeonExitItemScored, DungeonExited, PlayerLeveledUp};
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    compute_time_bonus, is_scored_loot_item, score_value_for_item, session_instruction_bits,
-    GlobalAccount, InventoryAccount, PlayerAccount, RoomAccount, RoomPresence, SessionAuthority,
-    StorageAccount, DIRECTION_SOUTH, WALL_ENTRANCE_STAIRS,
+    compute_time_bonus, is_scored_loot_item, pause_flags, score_value_for_item,
+    session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount, RoomAccount,
+    RoomPresence, SessionAuthority, StorageAccount, CENTER_EXTRACTION_STAIRS, DIRECTION_SOUTH,
+    WALL_ENTRANCE_STAIRS, XP_PER_EXTRACTION,
 };
 
 #[derive(Accounts)]
@@ -36,7 +37,8 @@ pub struct ExitDungeon<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -67,6 +69,7 @@ pub struct ExitDungeon<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -88,6 +91,8 @@ pub struct ExitDungeon<'info> {
 }
 
 pub fn handler(ctx: Context<ExitDungeon>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::EXTRACTION)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -104,18 +109,27 @@ pub fn handler(ctx: Context<ExitDungeon>) -> Result<()> {
         player.is_at_room(room.x, room.y),
         ChainDepthError::NotInRoom
     );
-    require!(
-        room.x == GlobalAccount::START_X && room.y == GlobalAccount::START_Y,
-        ChainDepthError::NotAtEntranceRoom
-    );
-    require!(
-        room.walls[DIRECTION_SOUTH as usize] == WALL_ENTRANCE_STAIRS,
-        ChainDepthError::EntranceStairsRequired
-    );
+    // Deep extraction-stair rooms are an alternative to walking back to the entrance.
+    let deep_exit = room.center_type == CENTER_EXTRACTION_STAIRS;
+    if !deep_exit {
+        require!(
+            room.floor == 0 && ctx.accounts.global.grid().is_start(room.x, room.y),
+            ChainDepthError::NotAtEntranceRoom
+        );
+        require!(
+            room.walls[DIRECTION_SOUTH as usize] == WALL_ENTRANCE_STAIRS,
+            ChainDepthError::EntranceStairsRequired
+        );
+    }
     require!(
         player.active_jobs.is_empty(),
         ChainDepthError::CannotExitWithActiveJobs
     );
+    // Room and world boss fights both leave the presence in boss-fight activity.
+    require!(
+        ctx.accounts.room_presence.activity != RoomPresence::ACTIVITY_BOSS_FIGHT,
+        ChainDepthError::AlreadyFightingBoss
+    );
 
     let inventory = &mut ctx.accounts.inventory;
     if inventory.owner == Pubkey::default() {
@@ -128,6 +142,8 @@ pub fn handler(ctx: Context<ExitDungeon>) -> Result<()> {
         ChainDepthError::Unauthorized
     );
     let storage = &mut ctx.accounts.storage;
+    let storage_len = storage.to_account_info().data_len();
+    storage.reset_stale_bonus_slots(storage_len);
     if storage.owner == Pubkey::default() {
         storage.owner = player_key;
         storage.items = Vec::new();
@@ -178,9 +194,16 @@ pub fn handler(ctx: Context<ExitDungeon>) -> Result<()> {
     };
     let run_duration_slots = now_slot.saturating_sub(run_start_slot);
     let time_score = compute_time_bonus(run_duration_slots, loot_score);
-    let run_score = loot_score
+    let mut run_score = loot_score
         .checked_add(time_score)
         .ok_or(ChainDepthError::Overflow)?;
+    let score_bps = if deep_exit {
+        let global = &ctx.accounts.global;
+        run_score = global.apply_deep_exit_score(run_score);
+        global.deep_exit_score_bps()
+    } else {
+        GlobalAccount::SCORE_BPS_DENOMINATOR as u16
+    };
 
     player.total_score = player
         .total_score
@@ -198,6 +221,14 @@ pub fn handler(ctx: Context<ExitDungeon>) -> Result<()> {
         player.max_hp = crate::state::DEFAULT_PLAYER_MAX_HP;
     }
     player.current_hp = player.max_hp;
+    if player.grant_xp(XP_PER_EXTRACTION) {
+        emit!(PlayerLeveledUp {
+            player: player_key,
+            level: player.level,
+            xp: player.xp,
+            max_hp: player.max_hp,
+        });
+    }
 
     let room_presence = &mut ctx.accounts.room_presence;
     room_presence.is_current = true;
@@ -212,6 +243,8 @@ pub fn handler(ctx: Context<ExitDungeon>) -> Result<()> {
         extracted_item_units,
         total_score: player.total_score,
         run_duration_slots,
+        deep_exit,
+        score_bps,
     });
 
     Ok(())
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ChainDepthError;
+use crate::events::ItemCapacityExpanded;
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::instructions::expand_inventory::pay_capacity_upgrade;
+use crate::state::{
+    next_capacity_upgrade, GlobalAccount, PlayerAccount, StorageAccount, SLOTS_PER_CAPACITY_UPGRADE,
+};
+
+#[derive(Accounts)]
+pub struct ExpandStorage<'info> {
+    /// Pays the SKR price and the extra rent
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Box<Account<'info, PlayerAccount>>,
+
+    #[account(
+        mut,
+        seeds = [StorageAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    /// CHECK: PDA seed validation guarantees this is the caller's storage PDA.
+    /// Deserialized manually so accounts from before `bonus_slots` can be grown too.
+    pub storage: UncheckedAccount<'info>,
+
+    /// Prize pool receives the upgrade price
+    #[account(
+        mut,
+        constraint = prize_pool.key() == global.prize_pool
+    )]
+    pub prize_pool: Box<Account<'info, TokenAccount>>,
+
+    /// Player's SKR token account
+    #[account(
+        mut,
+        constraint = player_token_account.mint == global.skr_mint,
+        constraint = player_token_account.owner == player.key()
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExpandStorage>) -> Result<()> {
+    let account_info = ctx.accounts.storage.to_account_info();
+    require_keys_eq!(
+        *account_info.owner,
+        crate::ID,
+        ChainDepthError::Unauthorized
+    );
+
+    let mut storage: StorageAccount =
+        decode_padded(&account_info, StorageAccount::space_for_bonus_slots(0))?;
+    storage.reset_stale_bonus_slots(account_info.data_len());
+    require!(
+        storage.owner == ctx.accounts.player.key(),
+        ChainDepthError::Unauthorized
+    );
+
+    let (required_level, price) =
+        next_capacity_upgrade(storage.bonus_slots).ok_or(ChainDepthError::CapacityUpgradeLimit)?;
+    require!(
+        ctx.accounts.player_account.level >= required_level,
+        ChainDepthError::LevelTooLow
+    );
+
+    pay_capacity_upgrade(
+        &ctx.accounts.token_program,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.prize_pool,
+        &ctx.accounts.player,
+        price,
+    )?;
+
+    storage.bonus_slots = storage
+        .bonus_slots
+        .checked_add(SLOTS_PER_CAPACITY_UPGRADE)
+        .ok_or(ChainDepthError::Overflow)?;
+    resize_with_rent(
+        &account_info,
+        &ctx.accounts.player,
+        &ctx.accounts.system_program,
+        StorageAccount::space_for_bonus_slots(storage.bonus_slots),
+    )?;
+    {
+        let mut data = account_info.try_borrow_mut_data()?;
+        storage.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(ItemCapacityExpanded {
+        player: ctx.accounts.player.key(),
+        is_storage: true,
+        slot_capacity: storage.slot_capacity() as u16,
+        price,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::Field;
+use anchor_spl::token_interface::{
+    self, spl_token_metadata_interface::state::TokenMetadata, Mint, MintTo, SetAuthority,
+    Token2022, TokenAccount, TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+
+use crate::errors::ChainDepthError;
+use crate::events::ItemExported;
+use crate::state::{
+    exported_item_name, is_scored_loot_item, pause_flags, ExportedItem, GlobalAccount,
+    GrantedItems, InventoryAccount, PlayerAccount, StorageAccount, EXPORTED_ITEM_DURABILITY_KEY,
+    EXPORTED_ITEM_ID_KEY, EXPORTED_ITEM_SYMBOL,
+};
+
+#[derive(Accounts)]
+pub struct ExportItem<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Box<Account<'info, PlayerAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = InventoryAccount::DISCRIMINATOR.len() + InventoryAccount::INIT_SPACE,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub inventory: Box<Account<'info, InventoryAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = StorageAccount::DISCRIMINATOR.len() + StorageAccount::INIT_SPACE,
+        seeds = [StorageAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub storage: Box<Account<'info, StorageAccount>>,
+
+    /// CHECK: PDA seed validation guarantees this is the caller's grant record; it only
+    /// exists once `add_inventory_item` has granted them something.
+    #[account(
+        seeds = [GrantedItems::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub granted_items: UncheckedAccount<'info>,
+
+    /// Fresh keypair for the NFT mint; metadata lives on the mint itself
+    #[account(
+        init,
+        payer = player,
+        mint::decimals = 0,
+        mint::authority = global,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = global,
+        extensions::metadata_pointer::metadata_address = item_mint,
+    )]
+    pub item_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = player,
+        associated_token::mint = item_mint,
+        associated_token::authority = player,
+        associated_token::token_program = token_program,
+    )]
+    pub player_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = player,
+        space = ExportedItem::DISCRIMINATOR.len() + ExportedItem::INIT_SPACE,
+        seeds = [ExportedItem::SEED_PREFIX, item_mint.key().as_ref()],
+        bump
+    )]
+    pub exported_item: Box<Account<'info, ExportedItem>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExportItem>, item_id: u16, durability: u16) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::EXTRACTION)?;
+
+    let name = exported_item_name(item_id).ok_or(ChainDepthError::ItemNotExportable)?;
+
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.require_out_of_dungeon()?;
+
+    let player_key = ctx.accounts.player.key();
+    let inventory = &mut ctx.accounts.inventory;
+    if inventory.owner == Pubkey::default() {
+        inventory.owner = player_key;
+        inventory.items = Vec::new();
+        inventory.bump = ctx.bumps.inventory;
+    }
+    require!(inventory.owner == player_key, ChainDepthError::Unauthorized);
+
+    let storage = &mut ctx.accounts.storage;
+    if storage.owner == Pubkey::default() {
+        storage.owner = player_key;
+        storage.items = Vec::new();
+        storage.bump = ctx.bumps.storage;
+    }
+    require!(storage.owner == player_key, ChainDepthError::Unauthorized);
+
+    // Granted items could have been traded into anything this wallet holds.
+    let granted_info = ctx.accounts.granted_items.to_account_info();
+    if *granted_info.owner == crate::ID {
+        let granted = GrantedItems::try_deserialize(&mut &granted_info.try_borrow_data()?[..])?;
+        require!(
+            granted.granted_units == 0,
+            ChainDepthError::GrantedItemNotExportable
+        );
+    }
+
+    // Valuables are banked in storage after extraction; gear stays in the inventory.
+    if is_scored_loot_item(item_id) {
+        storage.remove_stack(item_id, durability, 1)?;
+    } else {
+        inventory.remove_stack(item_id, durability, 1)?;
+        if player_account.equipped_item_id == item_id && inventory.item_count(item_id) == 0 {
+            player_account.equipped_item_id = 0;
+        }
+    }
+
+    let global_key = ctx.accounts.global.key();
+    let mint_key = ctx.accounts.item_mint.key();
+    let global_bump = ctx.accounts.global.bump;
+    let global_seeds = &[GlobalAccount::SEED_PREFIX, &[global_bump]];
+    let global_signer = &[&global_seeds[..]];
+
+    let name = name.to_string();
+    let symbol = EXPORTED_ITEM_SYMBOL.to_string();
+    let uri = String::new();
+    let item_id_value = item_id.to_string();
+    let durability_value = durability.to_string();
+
+    // Token-2022 reallocs the mint for metadata but does not fund it; top up rent first.
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(global_key),
+        mint: mint_key,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![
+            (EXPORTED_ITEM_ID_KEY.to_string(), item_id_value.clone()),
+            (
+                EXPORTED_ITEM_DURABILITY_KEY.to_string(),
+                durability_value.clone(),
+            ),
+        ],
+    };
+    let metadata_len = metadata.tlv_size_of()?;
+    let mint_info = ctx.accounts.item_mint.to_account_info();
+    let required_lamports =
+        Rent::get()?.minimum_balance(mint_info.data_len().saturating_add(metadata_len));
+    let top_up = required_lamports.saturating_sub(mint_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: ctx.accounts.global.to_account_info(),
+                mint_authority: ctx.accounts.global.to_account_info(),
+                mint: mint_info.clone(),
+            },
+            global_signer,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    for (key, value) in [
+        (EXPORTED_ITEM_ID_KEY, item_id_value),
+        (EXPORTED_ITEM_DURABILITY_KEY, durability_value),
+    ] {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: ctx.accounts.global.to_account_info(),
+                },
+                global_signer,
+            ),
+            Field::Key(key.to_string()),
+            value,
+        )?;
+    }
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: mint_info.clone(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.global.to_account_info(),
+            },
+            global_signer,
+        ),
+        1,
+    )?;
+
+    // Drop the mint authority so supply stays fixed at one.
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.global.to_account_info(),
+                account_or_mint: mint_info,
+            },
+            global_signer,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let exported_item = &mut ctx.accounts.exported_item;
+    exported_item.mint = mint_key;
+    exported_item.item_id = item_id;
+    exported_item.durability = durability;
+    exported_item.exporter = player_key;
+    exported_item.exported_slot = Clock::get()?.slot;
+    exported_item.bump = ctx.bumps.exported_item;
+
+    emit!(ItemExported {
+        player: player_key,
+        mint: mint_key,
+        item_id,
+        durability,
+    });
+
+    Ok(())
+}
@@ -35,7 +35,8 @@ pub struct ForceExitOnDeath<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -57,6 +58,7 @@ pub struct ForceExitOnDeath<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -130,9 +132,9 @@ pub struct DeathOutcome {
 }
 
 pub fn apply_death_outcome(
-    player: &mut Account<PlayerAccount>,
-    inventory: &mut Account<InventoryAccount>,
-    room_presence: &mut Account<RoomPresence>,
+    player: &mut PlayerAccount,
+    inventory: &mut InventoryAccount,
+    room_presence: &mut RoomPresence,
     now_slot: u64,
 ) -> Result<DeathOutcome> {
     let mut lost_item_stacks = 0u32;
@@ -3,18 +3,19 @@ use anchor_lang::prelude::*;
 use crate::errors::ChainDepthError;
 use crate::events::SeasonReset;
 use crate::instructions::reset_season::apply_season_reset;
-use crate::state::GlobalAccount;
+use crate::state::{roles, GlobalAccount};
 
 #[derive(Accounts)]
 pub struct ForceResetSeason<'info> {
-    /// Admin override authority
+    /// Season operator override
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [GlobalAccount::SEED_PREFIX],
         bump = global.bump,
-        constraint = global.admin == authority.key() @ ChainDepthError::Unauthorized
+        constraint = global.is_role_holder(roles::SEASON_OPERATOR, &authority.key()) @ ChainDepthError::Unauthorized,
+        constraint = !global.has_admin_set() @ ChainDepthError::AdminActionRequiresProposal
     )]
     pub global: Account<'info, GlobalAccount>,
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ChainDepthError;
+use crate::events::{RoomRentReimbursed, TreasuryFunded, TreasuryLow};
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::{GlobalAccount, RoomAccount};
+
+#[derive(Accounts)]
+pub struct FundTreasury<'info> {
+    /// Anyone can fund room rent reimbursements
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// CHECK: decoded manually so a global created before the treasury fields
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, ChainDepthError::InvalidFundingAmount);
+
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let mut global: GlobalAccount = decode_padded(&global_info, global_space)?;
+
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: global_info.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    global.total_treasury_funded = global
+        .total_treasury_funded
+        .checked_add(amount)
+        .ok_or(ChainDepthError::Overflow)?;
+    {
+        let mut data = global_info.try_borrow_mut_data()?;
+        global.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(TreasuryFunded {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        treasury_spare: treasury_spare(&global_info)?,
+        total_treasury_funded: global.total_treasury_funded,
+    });
+
+    Ok(())
+}
+
+/// Treasury lamports above the global account's own rent exemption.
+fn treasury_spare(global_info: &AccountInfo<'_>) -> Result<u64> {
+    let reserved = Rent::get()?.minimum_balance(global_info.data_len());
+    Ok(global_info.lamports().saturating_sub(reserved))
+}
+
+/// Pay a new room's rent back to `explorer` from the treasury (the global PDA's
+/// spare lamports). Once the season budget or the spare balance runs out the
+/// explorer keeps paying instead of exploration failing. Moves lamports directly,
+/// so call it after every CPI in the instruction. Returns the amount reimbursed.
+pub(crate) fn reimburse_room_rent<'info>(
+    global: &mut Account<'info, GlobalAccount>,
+    explorer: &AccountInfo<'info>,
+) -> Result<u64> {
+    let rent_cost = Rent::get()?.minimum_balance(8 + RoomAccount::INIT_SPACE);
+    let global_info = global.to_account_info();
+    let spare = treasury_spare(&global_info)?;
+
+    let reimbursed = if global.can_reimburse_rent(rent_cost, spare) {
+        **global_info.try_borrow_mut_lamports()? -= rent_cost;
+        **explorer.try_borrow_mut_lamports()? = explorer
+            .lamports()
+            .checked_add(rent_cost)
+            .ok_or(ChainDepthError::Overflow)?;
+        global.record_rent_reimbursement(rent_cost);
+        emit!(RoomRentReimbursed {
+            explorer: explorer.key(),
+            amount: rent_cost,
+            season_rent_reimbursed: global.season_rent_reimbursed,
+            treasury_spare: spare - rent_cost,
+        });
+        rent_cost
+    } else {
+        0
+    };
+
+    let spare_after = spare - reimbursed;
+    let low_mark = rent_cost.saturating_mul(GlobalAccount::LOW_TREASURY_ROOMS);
+    if reimbursed == 0 || spare_after < low_mark {
+        emit!(TreasuryLow {
+            treasury_spare: spare_after,
+            season_budget_remaining: global.season_rent_budget_remaining(),
+            explorer_paid: reimbursed == 0,
+        });
+    }
+
+    Ok(reimbursed)
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, Token2022, TokenAccount};
+
+use crate::errors::ChainDepthError;
+use crate::events::ItemImported;
+use crate::state::{
+    is_scored_loot_item, pause_flags, ExportedItem, GlobalAccount, InventoryAccount, PlayerAccount,
+    StorageAccount,
+};
+
+#[derive(Accounts)]
+pub struct ImportItem<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Box<Account<'info, PlayerAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = InventoryAccount::DISCRIMINATOR.len() + InventoryAccount::INIT_SPACE,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub inventory: Box<Account<'info, InventoryAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = StorageAccount::DISCRIMINATOR.len() + StorageAccount::INIT_SPACE,
+        seeds = [StorageAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub storage: Box<Account<'info, StorageAccount>>,
+
+    #[account(mut, mint::token_program = token_program)]
+    pub item_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Any token account of the player holding the NFT
+    #[account(
+        mut,
+        token::mint = item_mint,
+        token::authority = player,
+        token::token_program = token_program,
+    )]
+    pub player_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Export record; closing it refunds its rent to the importer
+    #[account(
+        mut,
+        close = player,
+        seeds = [ExportedItem::SEED_PREFIX, item_mint.key().as_ref()],
+        bump = exported_item.bump,
+        constraint = exported_item.mint == item_mint.key() @ ChainDepthError::ExportedItemMismatch
+    )]
+    pub exported_item: Box<Account<'info, ExportedItem>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ImportItem>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::EXTRACTION)?;
+
+    ctx.accounts.player_account.require_out_of_dungeon()?;
+
+    let item_id = ctx.accounts.exported_item.item_id;
+    let durability = ctx.accounts.exported_item.durability;
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.item_mint.to_account_info(),
+                from: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    // The emptied token account has no further use; return its rent.
+    ctx.accounts.player_token_account.reload()?;
+    if ctx.accounts.player_token_account.amount == 0 {
+        token_interface::close_account(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.player_token_account.to_account_info(),
+                destination: ctx.accounts.player.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        ))?;
+    }
+
+    let player_key = ctx.accounts.player.key();
+    if is_scored_loot_item(item_id) {
+        // Valuables go back to storage so they cannot be scored a second time.
+        let storage = &mut ctx.accounts.storage;
+        let storage_len = storage.to_account_info().data_len();
+        storage.reset_stale_bonus_slots(storage_len);
+        if storage.owner == Pubkey::default() {
+            storage.owner = player_key;
+            storage.items = Vec::new();
+            storage.bump = ctx.bumps.storage;
+        }
+        require!(storage.owner == player_key, ChainDepthError::Unauthorized);
+        storage.add_item(item_id, 1, durability)?;
+    } else {
+        let inventory = &mut ctx.accounts.inventory;
+        let inventory_len = inventory.to_account_info().data_len();
+        inventory.reset_stale_bonus_slots(inventory_len);
+        if inventory.owner == Pubkey::default() {
+            inventory.owner = player_key;
+            inventory.items = Vec::new();
+            inventory.bump = ctx.bumps.inventory;
+        }
+        require!(inventory.owner == player_key, ChainDepthError::Unauthorized);
+        inventory.add_item(item_id, 1, durability)?;
+    }
+
+    emit!(ItemImported {
+        player: player_key,
+        mint: ctx.accounts.item_mint.key(),
+        item_id,
+        durability,
+    });
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::events::GlobalInitialized;
-use crate::state::{GlobalAccount, RoomAccount, CENTER_EMPTY};
+use crate::state::{biome_for_depth, DungeonGrid, GlobalAccount, RoomAccount, CENTER_EMPTY};
 
 #[derive(Accounts)]
 #[instruction(initial_prize_pool_amount: u64, season_seed: u64)]
@@ -41,7 +41,7 @@ pub struct InitGlobal<'info> {
     )]
     pub admin_token_account: Account<'info, TokenAccount>,
 
-    /// Starting room at (START_X, START_Y) - uses season_seed passed as instruction arg
+    /// Starting room of the legacy grid - uses season_seed passed as instruction arg
     #[account(
         init,
         payer = admin,
@@ -49,8 +49,8 @@ pub struct InitGlobal<'info> {
         seeds = [
             RoomAccount::SEED_PREFIX,
             &season_seed.to_le_bytes(),
-            &[GlobalAccount::START_X as u8],
-            &[GlobalAccount::START_Y as u8]
+            &[DungeonGrid::LEGACY.start_x as u8],
+            &[DungeonGrid::LEGACY.start_y as u8]
         ],
         bump
     )]
@@ -73,17 +73,22 @@ pub fn handler(ctx: Context<InitGlobal>, initial_prize_pool_amount: u64, season_
     global.end_slot = clock.slot + GlobalAccount::SEASON_DURATION_SLOTS;
     global.jobs_completed = 0;
     global.bump = ctx.bumps.global;
+    global.deep_exit_score_bps = GlobalAccount::DEFAULT_DEEP_EXIT_SCORE_BPS;
+    global.grid = DungeonGrid::LEGACY;
+    global.next_grid = DungeonGrid::LEGACY;
+    let grid = global.grid();
 
     // Initialize starting room with fixed extraction topology.
     let start_room = &mut ctx.accounts.start_room;
-    start_room.x = GlobalAccount::START_X;
-    start_room.y = GlobalAccount::START_Y;
+    start_room.x = grid.start_x;
+    start_room.y = grid.start_y;
     start_room.season_seed = season_seed;
     
     start_room.walls = RoomAccount::generate_start_walls(
         season_seed,
-        GlobalAccount::START_X,
-        GlobalAccount::START_Y,
+        grid,
+        grid.start_x,
+        grid.start_y,
     );
     
     // Initialize directional job state
@@ -100,12 +105,16 @@ pub fn handler(ctx: Context<InitGlobal>, initial_prize_pool_amount: u64, season_
     start_room.forced_key_drop = false;
     start_room.center_type = CENTER_EMPTY;
     start_room.center_id = 0;
+    start_room.biome_id = biome_for_depth(0).id;
     start_room.boss_max_hp = 0;
     start_room.boss_current_hp = 0;
     start_room.boss_last_update_slot = clock.slot;
     start_room.boss_total_dps = 0;
     start_room.boss_fighter_count = 0;
     start_room.boss_defeated = false;
+    start_room.boss_modifiers = 0;
+    start_room.boss_engaged_slot = 0;
+    start_room.trap_disarmed = false;
     start_room.looted_count = 0;
     start_room.created_by = ctx.accounts.admin.key();
     start_room.created_slot = clock.slot;
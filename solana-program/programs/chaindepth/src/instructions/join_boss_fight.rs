@@ -5,9 +5,9 @@ use crate::events::{BossFightJoined, BossTicked, PlayerDamaged, PlayerDied};
 use crate::instructions::force_exit_on_death::apply_death_outcome;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    item_ids, session_instruction_bits, BossFightAccount, GlobalAccount, PlayerAccount,
-    PlayerProfile, RoomAccount, RoomPresence, SessionAuthority, InventoryAccount, CENTER_BOSS,
-    calculate_depth,
+    floor_depth, item_ids, level_dps_bonus, pause_flags, session_instruction_bits, BossFightAccount,
+    DungeonGrid, GlobalAccount, InventoryAccount, PlayerAccount, PlayerProfile, RoomAccount,
+    RoomPresence, SessionAuthority, CENTER_BOSS,
 };
 
 pub const PLAYER_BOSS_DAMAGE_SLOT_STEP: u64 = 50;
@@ -46,7 +46,8 @@ pub struct JoinBossFight<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -61,6 +62,7 @@ pub struct JoinBossFight<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump
@@ -100,6 +102,8 @@ pub struct JoinBossFight<'info> {
 }
 
 pub fn handler(ctx: Context<JoinBossFight>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::BOSSES)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -132,7 +136,8 @@ pub fn handler(ctx: Context<JoinBossFight>) -> Result<()> {
         ChainDepthError::PlayerDead
     );
 
-    let fighter_dps = weapon_dps(player_account.equipped_item_id);
+    let fighter_dps = weapon_dps(player_account.equipped_item_id)
+        .saturating_add(level_dps_bonus(player_account.level));
     player_account.mark_active(clock.slot);
 
     let boss_fight = &mut ctx.accounts.boss_fight;
@@ -140,6 +145,9 @@ pub fn handler(ctx: Context<JoinBossFight>) -> Result<()> {
         return err!(ChainDepthError::AlreadyFightingBoss);
     }
 
+    if room.boss_fighter_count == 0 {
+        room.boss_engaged_slot = clock.slot;
+    }
     room.boss_fighter_count = room
         .boss_fighter_count
         .checked_add(1)
@@ -163,6 +171,7 @@ pub fn handler(ctx: Context<JoinBossFight>) -> Result<()> {
         room_presence.season_seed = ctx.accounts.global.season_seed;
         room_presence.room_x = room.x;
         room_presence.room_y = room.y;
+        room_presence.floor = room.floor;
         room_presence.skin_id = ctx.accounts.profile.skin_id;
         room_presence.equipped_item_id = player_account.equipped_item_id;
         room_presence.set_idle();
@@ -187,25 +196,52 @@ pub fn handler(ctx: Context<JoinBossFight>) -> Result<()> {
 
 pub fn boss_damage_per_tick(depth: u32, boss_id: u16) -> u16 {
     let depth_multiplier = 1 + (depth / 4) as u64;
-    let id_multiplier = 1 + (boss_id % 5) as u64;
+    let id_multiplier = RoomAccount::boss_stat_multiplier(boss_id);
     let scaled = u64::from(PLAYER_BOSS_BASE_DAMAGE_PER_TICK)
         .saturating_mul(depth_multiplier)
         .saturating_mul(id_multiplier);
     scaled.min(u64::from(u16::MAX)) as u16
 }
 
+/// Ticks until `hp` runs out when the first `calm_ticks` of `ticks` deal `per_tick` and
+/// the rest deal `enraged_per_tick`; `None` if the fighter survives all of them.
+pub fn ticks_until_defeat(
+    hp: u16,
+    ticks: u64,
+    calm_ticks: u64,
+    per_tick: u64,
+    enraged_per_tick: u64,
+) -> Option<u64> {
+    let hp = u64::from(hp);
+    let calm_ticks = calm_ticks.min(ticks);
+    let calm_damage = per_tick.saturating_mul(calm_ticks);
+    if per_tick > 0 && hp <= calm_damage {
+        return Some(hp.div_ceil(per_tick));
+    }
+    if enraged_per_tick == 0 {
+        return None;
+    }
+    let needed = calm_ticks.saturating_add((hp - calm_damage).div_ceil(enraged_per_tick));
+    (needed <= ticks).then_some(needed)
+}
+
+/// Settle boss hits on one fighter since their last settlement. Call it before
+/// `apply_boss_damage`: a fighter who dies is settled up to their death slot, and their
+/// DPS stops counting toward the boss from that slot on. The fighter is `boss_fight.player`,
+/// whose key every caller's `boss_fight` PDA is derived from.
 pub fn resolve_player_boss_damage(
     room: &mut Account<RoomAccount>,
+    grid: DungeonGrid,
     player_account: &mut Account<PlayerAccount>,
     room_presence: &mut Account<RoomPresence>,
     boss_fight: &mut Account<BossFightAccount>,
     inventory: &mut Account<InventoryAccount>,
-    player_key: Pubkey,
     now_slot: u64,
 ) -> Result<bool> {
-    if !boss_fight.is_active || boss_fight.player != player_key {
+    if !boss_fight.is_active {
         return Ok(false);
     }
+    let player_key = boss_fight.player;
 
     let elapsed_slots = now_slot.saturating_sub(boss_fight.last_damage_slot);
     if elapsed_slots < PLAYER_BOSS_DAMAGE_SLOT_STEP {
@@ -217,15 +253,32 @@ pub fn resolve_player_boss_damage(
         return Ok(false);
     }
 
-    let depth = calculate_depth(room.x, room.y);
-    let per_tick_damage = boss_damage_per_tick(depth, room.center_id);
-    let total_damage = u64::from(per_tick_damage)
-        .saturating_mul(ticks)
-        .min(u64::from(u16::MAX)) as u16;
-
+    let depth = floor_depth(grid, room.floor, room.x, room.y);
+    let per_tick_damage = u64::from(boss_damage_per_tick(depth, room.center_id));
+    let enraged_per_tick =
+        per_tick_damage.saturating_mul(RoomAccount::ELITE_ENRAGE_DAMAGE_MULTIPLIER);
+    let enraged_ticks =
+        room.enraged_boss_ticks(boss_fight.last_damage_slot, ticks, PLAYER_BOSS_DAMAGE_SLOT_STEP);
+    let calm_ticks = ticks - enraged_ticks;
+    let death_tick = ticks_until_defeat(
+        player_account.current_hp,
+        ticks,
+        calm_ticks,
+        per_tick_damage,
+        enraged_per_tick,
+    );
+    let total_damage = match death_tick {
+        Some(_) => player_account.current_hp,
+        None => per_tick_damage
+            .saturating_mul(calm_ticks)
+            .saturating_add(enraged_per_tick.saturating_mul(enraged_ticks))
+            .min(u64::from(u16::MAX)) as u16,
+    };
+
+    let settled_ticks = death_tick.unwrap_or(ticks);
     boss_fight.last_damage_slot = boss_fight
         .last_damage_slot
-        .saturating_add(ticks.saturating_mul(PLAYER_BOSS_DAMAGE_SLOT_STEP));
+        .saturating_add(settled_ticks.saturating_mul(PLAYER_BOSS_DAMAGE_SLOT_STEP));
 
     let previous_hp = player_account.current_hp;
     player_account.current_hp = previous_hp.saturating_sub(total_damage);
@@ -254,6 +307,19 @@ pub fn resolve_player_boss_damage(
 
     let death_outcome = apply_death_outcome(player_account, inventory, room_presence, now_slot)?;
 
+    // The fighter's DPS counts toward the boss only until the slot they died.
+    let death_slot = boss_fight.last_damage_slot;
+    if death_slot >= room.boss_last_update_slot {
+        apply_boss_damage(room, death_slot)?;
+    } else if !room.boss_defeated && !room.is_boss_shield_up() {
+        // An earlier transaction already counted them past their death; give that damage back.
+        let overcounted = (room.boss_last_update_slot - death_slot).saturating_mul(boss_fight.dps);
+        room.boss_current_hp = room
+            .boss_current_hp
+            .saturating_add(overcounted)
+            .min(room.boss_max_hp);
+    }
+
     if room.boss_fighter_count > 0 {
         room.boss_fighter_count = room.boss_fighter_count.saturating_sub(1);
     }
@@ -284,13 +350,24 @@ pub fn resolve_player_boss_damage(
 }
 
 pub(crate) fn apply_boss_damage(room: &mut Account<RoomAccount>, current_slot: u64) -> Result<()> {
-    if room.center_type != CENTER_BOSS || room.boss_defeated || room.boss_fighter_count == 0 {
+    if room.center_type != CENTER_BOSS || room.boss_defeated {
         room.boss_last_update_slot = current_slot;
         return Ok(());
     }
 
     let elapsed_slots = current_slot.saturating_sub(room.boss_last_update_slot);
-    if elapsed_slots == 0 || room.boss_total_dps == 0 {
+    if room.boss_fighter_count == 0 {
+        // Regenerating elites heal while the room is empty.
+        let regen = room.boss_regen_for_slots(elapsed_slots);
+        room.boss_current_hp = room
+            .boss_current_hp
+            .saturating_add(regen)
+            .min(room.boss_max_hp);
+        room.boss_last_update_slot = current_slot;
+        return Ok(());
+    }
+
+    if elapsed_slots == 0 || room.boss_total_dps == 0 || room.is_boss_shield_up() {
         room.boss_last_update_slot = current_slot;
         return Ok(());
     }
@@ -308,7 +385,7 @@ pub(crate) fn apply_boss_damage(room: &mut Account<RoomAccount>, current_slot: u
     Ok(())
 }
 
-fn weapon_dps(item_id: u16) -> u64 {
+pub(crate) fn weapon_dps(item_id: u16) -> u64 {
     match item_id {
         // Legacy tool id retained for old inventories.
         2 => 5,
@@ -328,3 +405,23 @@ fn weapon_dps(item_id: u16) -> u64 {
         _ => 1,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defeat_tick_accounts_for_enrage() {
+        // 100 HP, 10 per calm tick: dies on the 10th tick, or survives 9.
+        assert_eq!(ticks_until_defeat(100, 12, 12, 10, 20), Some(10));
+        assert_eq!(ticks_until_defeat(100, 9, 9, 10, 20), None);
+        assert_eq!(ticks_until_defeat(95, 12, 12, 10, 20), Some(10));
+
+        // Two calm ticks (20), then 20 per enraged tick: 80 HP left needs 4 more.
+        assert_eq!(ticks_until_defeat(100, 6, 2, 10, 20), Some(6));
+        assert_eq!(ticks_until_defeat(100, 5, 2, 10, 20), None);
+        assert_eq!(ticks_until_defeat(100, 8, 0, 10, 20), Some(5));
+
+        assert_eq!(ticks_until_defeat(100, 50, 50, 0, 0), None);
+    }
+}
@@ -3,7 +3,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::ChainDepthError;
 use crate::events::JobJoined;
-use crate::state::{GlobalAccount, HelperStake, PlayerAccount, RoomAccount, RoomPresence};
+use crate::state::{
+    pause_flags, GlobalAccount, HelperStake, PlayerAccount, RoomAccount, RoomPresence,
+};
 
 #[derive(Accounts)]
 #[instruction(direction: u8)]
@@ -32,7 +34,8 @@ pub struct JoinJob<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -47,6 +50,7 @@ pub struct JoinJob<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump
@@ -96,6 +100,8 @@ pub struct JoinJob<'info> {
 }
 
 pub fn handler(ctx: Context<JoinJob>, direction: u8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::JOBS)?;
+
     require!(
         RoomAccount::is_valid_direction(direction),
         ChainDepthError::InvalidDirection
@@ -149,6 +155,7 @@ pub fn handler(ctx: Context<JoinJob>, direction: u8) -> Result<()> {
         ctx.accounts.room_presence.season_seed = ctx.accounts.global.season_seed;
         ctx.accounts.room_presence.room_x = room.x;
         ctx.accounts.room_presence.room_y = room.y;
+        ctx.accounts.room_presence.floor = room.floor;
         ctx.accounts.room_presence.skin_id = 0;
         ctx.accounts.room_presence.equipped_item_id = player_account.equipped_item_id;
         ctx.accounts.room_presence.is_current = true;
@@ -5,8 +5,8 @@ use crate::errors::ChainDepthError;
 use crate::events::JobJoined;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    session_instruction_bits, GlobalAccount, HelperStake, PlayerAccount, RoomAccount, RoomPresence,
-    SessionAuthority,
+    pause_flags, session_instruction_bits, GlobalAccount, HelperStake, PlayerAccount, RoomAccount,
+    RoomPresence, SessionAuthority,
 };
 
 #[derive(Accounts)]
@@ -39,7 +39,8 @@ pub struct JoinJobWithSession<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -54,6 +55,7 @@ pub struct JoinJobWithSession<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump
@@ -114,6 +116,8 @@ pub struct JoinJobWithSession<'info> {
 }
 
 pub fn handler(ctx: Context<JoinJobWithSession>, direction: u8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::JOBS)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -178,6 +182,7 @@ pub fn handler(ctx: Context<JoinJobWithSession>, direction: u8) -> Result<()> {
         ctx.accounts.room_presence.season_seed = ctx.accounts.global.season_seed;
         ctx.accounts.room_presence.room_x = room.x;
         ctx.accounts.room_presence.room_y = room.y;
+        ctx.accounts.room_presence.floor = room.floor;
         ctx.accounts.room_presence.skin_id = 0;
         ctx.accounts.room_presence.equipped_item_id = player_account.equipped_item_id;
         ctx.accounts.room_presence.is_current = true;
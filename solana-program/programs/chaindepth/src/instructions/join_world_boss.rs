@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::WorldBossFightChanged;
+use crate::instructions::join_boss_fight::weapon_dps;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    level_dps_bonus, pause_flags, session_instruction_bits, BossFightAccount, GlobalAccount,
+    PlayerAccount, RoomAccount, RoomPresence, SessionAuthority, WorldBossAccount,
+};
+
+#[derive(Accounts)]
+pub struct JoinWorldBoss<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump = room_presence.bump
+    )]
+    pub room_presence: Account<'info, RoomPresence>,
+
+    #[account(
+        mut,
+        seeds = [WorldBossAccount::SEED_PREFIX, &global.season_seed.to_le_bytes()],
+        bump = world_boss.bump
+    )]
+    pub world_boss: Account<'info, WorldBossAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BossFightAccount::DISCRIMINATOR.len() + BossFightAccount::INIT_SPACE,
+        seeds = [BossFightAccount::SEED_PREFIX, world_boss.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub boss_fight: Account<'info, BossFightAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<JoinWorldBoss>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::BOSSES)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::JOIN_BOSS_FIGHT,
+        0,
+    )?;
+
+    let grid = ctx.accounts.global.grid();
+    let player_key = ctx.accounts.player.key();
+    let world_boss_key = ctx.accounts.world_boss.key();
+    let clock = Clock::get()?;
+    let player_account = &mut ctx.accounts.player_account;
+    let world_boss = &mut ctx.accounts.world_boss;
+    player_account.require_in_dungeon()?;
+
+    require!(
+        WorldBossAccount::is_arena_room(
+            grid,
+            player_account.current_floor,
+            player_account.current_room_x,
+            player_account.current_room_y,
+        ),
+        ChainDepthError::NotAtWorldBossArena
+    );
+    require!(
+        ctx.accounts.room_presence.activity != RoomPresence::ACTIVITY_BOSS_FIGHT,
+        ChainDepthError::AlreadyFightingBoss
+    );
+    world_boss.apply_damage(clock.slot)?;
+    require!(!world_boss.defeated, ChainDepthError::BossAlreadyDefeated);
+    require!(player_account.current_hp > 0, ChainDepthError::PlayerDead);
+
+    let boss_fight = &mut ctx.accounts.boss_fight;
+    require!(!boss_fight.is_active, ChainDepthError::AlreadyFightingBoss);
+
+    let fighter_dps = weapon_dps(player_account.equipped_item_id)
+        .saturating_add(level_dps_bonus(player_account.level));
+    player_account.mark_active(clock.slot);
+
+    if boss_fight.player == Pubkey::default() {
+        boss_fight.player = player_key;
+        boss_fight.room = world_boss_key;
+        boss_fight.bump = ctx.bumps.boss_fight;
+        world_boss.participant_count = world_boss
+            .participant_count
+            .checked_add(1)
+            .ok_or(ChainDepthError::Overflow)?;
+    }
+    world_boss.fighter_count = world_boss
+        .fighter_count
+        .checked_add(1)
+        .ok_or(ChainDepthError::Overflow)?;
+    world_boss.total_dps = world_boss
+        .total_dps
+        .checked_add(fighter_dps)
+        .ok_or(ChainDepthError::Overflow)?;
+
+    boss_fight.dps = fighter_dps;
+    boss_fight.joined_slot = clock.slot;
+    boss_fight.last_damage_slot = clock.slot;
+    boss_fight.is_active = true;
+
+    ctx.accounts.room_presence.set_boss_fight();
+
+    emit!(WorldBossFightChanged {
+        player: player_key,
+        joined: true,
+        dps: fighter_dps,
+        damage_dealt: boss_fight.damage_dealt,
+        fighter_count: world_boss.fighter_count,
+        current_hp: world_boss.current_hp,
+    });
+
+    Ok(())
+}
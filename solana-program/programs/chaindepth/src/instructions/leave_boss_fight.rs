@@ -37,7 +37,8 @@ pub struct LeaveBossFight<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -50,6 +51,7 @@ pub struct LeaveBossFight<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -95,6 +97,7 @@ pub fn handler(ctx: Context<LeaveBossFight>) -> Result<()> {
         0,
     )?;
 
+    let grid = ctx.accounts.global.grid();
     let room = &mut ctx.accounts.room;
     let clock = Clock::get()?;
     ctx.accounts.player_account.require_in_dungeon()?;
@@ -108,16 +111,16 @@ pub fn handler(ctx: Context<LeaveBossFight>) -> Result<()> {
     );
     require!(ctx.accounts.boss_fight.is_active, ChainDepthError::NotBossFighter);
 
-    apply_boss_damage(room, clock.slot)?;
     let died = resolve_player_boss_damage(
         room,
+        grid,
         &mut ctx.accounts.player_account,
         &mut ctx.accounts.room_presence,
         &mut ctx.accounts.boss_fight,
         &mut ctx.accounts.inventory,
-        ctx.accounts.player.key(),
         clock.slot,
     )?;
+    apply_boss_damage(room, clock.slot)?;
 
     if !died && ctx.accounts.boss_fight.is_active {
         if room.boss_fighter_count > 0 {
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::WorldBossFightChanged;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    session_instruction_bits, BossFightAccount, GlobalAccount, PlayerAccount, RoomAccount,
+    RoomPresence, SessionAuthority, WorldBossAccount,
+};
+
+#[derive(Accounts)]
+pub struct LeaveWorldBoss<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Required while the world boss's season is running; omit it after a season reset
+    #[account(
+        mut,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &world_boss.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump = room_presence.bump
+    )]
+    pub room_presence: Option<Account<'info, RoomPresence>>,
+
+    /// Seeded by its own season so fighters can still leave after a season reset
+    #[account(
+        mut,
+        seeds = [WorldBossAccount::SEED_PREFIX, &world_boss.season_seed.to_le_bytes()],
+        bump = world_boss.bump
+    )]
+    pub world_boss: Account<'info, WorldBossAccount>,
+
+    #[account(
+        mut,
+        seeds = [BossFightAccount::SEED_PREFIX, world_boss.key().as_ref(), player.key().as_ref()],
+        bump = boss_fight.bump
+    )]
+    pub boss_fight: Account<'info, BossFightAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+}
+
+pub fn handler(ctx: Context<LeaveWorldBoss>) -> Result<()> {
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::LEAVE_BOSS_FIGHT,
+        0,
+    )?;
+
+    let clock = Clock::get()?;
+    let world_boss = &mut ctx.accounts.world_boss;
+    let boss_fight = &mut ctx.accounts.boss_fight;
+    require!(boss_fight.is_active, ChainDepthError::NotBossFighter);
+
+    // Credit damage up to now (or the kill) before this fighter's DPS stops counting.
+    world_boss.apply_damage(clock.slot)?;
+    world_boss.settle_fighter(boss_fight, clock.slot);
+
+    world_boss.fighter_count = world_boss.fighter_count.saturating_sub(1);
+    world_boss.total_dps = world_boss.total_dps.saturating_sub(boss_fight.dps);
+    boss_fight.is_active = false;
+    boss_fight.dps = 0;
+    match ctx.accounts.room_presence.as_mut() {
+        Some(room_presence) => room_presence.set_idle(),
+        // After a reset the old season's presence no longer matters.
+        None => require!(
+            world_boss.season_seed != ctx.accounts.global.season_seed,
+            ChainDepthError::WorldBossPresenceRequired
+        ),
+    }
+    ctx.accounts.player_account.mark_active(clock.slot);
+
+    emit!(WorldBossFightChanged {
+        player: ctx.accounts.player.key(),
+        joined: false,
+        dps: 0,
+        damage_dealt: boss_fight.damage_dealt,
+        fighter_count: world_boss.fighter_count,
+        current_hp: world_boss.current_hp,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
-use crate::events::{item_types, BossLooted};
+use crate::events::{item_types, BossLooted, LootOverflowed, PlayerLeveledUp};
+use crate::instructions::claim_discovery_royalties::accrue_discovery_royalty;
 use crate::instructions::join_boss_fight::apply_boss_damage;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    item_ids, session_instruction_bits, BossFightAccount, GlobalAccount, InventoryAccount,
-    LootReceipt, PlayerAccount, RoomAccount, RoomPresence, SessionAuthority, CENTER_BOSS,
+    item_ids, pause_flags, royalty_sources, session_instruction_bits, BossFightAccount,
+    DiscoveryRoyaltyAccount, GlobalAccount, InventoryAccount, LootReceipt, LootRng, PlayerAccount,
+    RoomAccount, RoomPresence, SessionAuthority, CENTER_BOSS, XP_PER_BOSS_KILL,
 };
 
 #[derive(Accounts)]
@@ -38,7 +40,8 @@ pub struct LootBoss<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -51,6 +54,7 @@ pub struct LootBoss<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -82,12 +86,23 @@ pub struct LootBoss<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump
     )]
     pub loot_receipt: Account<'info, LootReceipt>,
 
+    /// Royalty account of the room's discoverer, opened by the caller if it is missing
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DiscoveryRoyaltyAccount::DISCRIMINATOR.len() + DiscoveryRoyaltyAccount::INIT_SPACE,
+        seeds = [DiscoveryRoyaltyAccount::SEED_PREFIX, room.created_by.as_ref()],
+        bump
+    )]
+    pub discoverer_royalty: Box<Account<'info, DiscoveryRoyaltyAccount>>,
+
     #[account(
         mut,
         seeds = [
@@ -103,6 +118,8 @@ pub struct LootBoss<'info> {
 }
 
 pub fn handler(ctx: Context<LootBoss>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -114,6 +131,8 @@ pub fn handler(ctx: Context<LootBoss>) -> Result<()> {
     let room = &mut ctx.accounts.room;
     let player_account = &mut ctx.accounts.player_account;
     let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
     let player_key = ctx.accounts.player.key();
     let clock = Clock::get()?;
     player_account.require_in_dungeon()?;
@@ -146,6 +165,14 @@ pub fn handler(ctx: Context<LootBoss>) -> Result<()> {
     room.looted_count += 1;
     player_account.chests_looted += 1;
     player_account.mark_active(clock.slot);
+    if player_account.grant_xp(XP_PER_BOSS_KILL) {
+        emit!(PlayerLeveledUp {
+            player: player_key,
+            level: player_account.level,
+            xp: player_account.xp,
+            max_hp: player_account.max_hp,
+        });
+    }
     if room.boss_fighter_count > 0 {
         room.boss_fighter_count = room.boss_fighter_count.saturating_sub(1);
     }
@@ -153,9 +180,17 @@ pub fn handler(ctx: Context<LootBoss>) -> Result<()> {
     ctx.accounts.boss_fight.is_active = false;
     ctx.accounts.boss_fight.dps = 0;
     ctx.accounts.room_presence.set_idle();
+    accrue_discovery_royalty(
+        &mut ctx.accounts.discoverer_royalty,
+        ctx.bumps.discoverer_royalty,
+        room,
+        player_key,
+        royalty_sources::BOSS_LOOTED,
+        DiscoveryRoyaltyAccount::BOSS_LOOTED_ROYALTY,
+    );
 
     let loot_hash = generate_loot_hash(clock.slot, &player_key, room.center_id);
-    let loot_bundle = build_boss_loot_bundle(loot_hash, room.center_id);
+    let loot_bundle = build_boss_loot_bundle(loot_hash, room.center_id, room.is_elite());
 
     if inventory.owner == Pubkey::default() {
         inventory.owner = player_key;
@@ -166,7 +201,18 @@ pub fn handler(ctx: Context<LootBoss>) -> Result<()> {
     let mut event_item_type = item_types::TOOL;
     let mut event_item_amount_total = 0u32;
     for stack in loot_bundle.iter() {
-        inventory.add_item(stack.item_id, stack.amount, stack.durability)?;
+        // A full inventory forfeits the stack instead of reverting the whole loot.
+        if !inventory.try_add_item(stack.item_id, stack.amount, stack.durability)? {
+            emit!(LootOverflowed {
+                player: player_key,
+                room_x: room.x,
+                room_y: room.y,
+                item_id: stack.item_id,
+                amount: stack.amount,
+                durability: stack.durability,
+            });
+            continue;
+        }
         event_item_amount_total = event_item_amount_total.saturating_add(stack.amount);
         event_item_type = stack.item_type;
     }
@@ -212,31 +258,6 @@ struct LootStack {
     item_type: u8,
 }
 
-struct LootRng {
-    state: u64,
-}
-
-impl LootRng {
-    fn new(seed: u64) -> Self {
-        Self { state: seed ^ 0x9E37_79B9_7F4A_7C15 }
-    }
-
-    fn next_u64(&mut self) -> u64 {
-        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
-        let mut z = self.state;
-        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
-        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
-        z ^ (z >> 31)
-    }
-
-    fn range_u32(&mut self, upper_exclusive: u32) -> u32 {
-        if upper_exclusive <= 1 {
-            return 0;
-        }
-        (self.next_u64() % u64::from(upper_exclusive)) as u32
-    }
-}
-
 const BOSS_WEAPONS: [LootSpec; 9] = [
     LootSpec { item_id: item_ids::IRON_PICKAXE, weight: 12, min_amount: 1, max_amount: 1 },
     LootSpec { item_id: item_ids::IRON_SWORD, weight: 13, min_amount: 1, max_amount: 1 },
@@ -249,6 +270,14 @@ const BOSS_WEAPONS: [LootSpec; 9] = [
     LootSpec { item_id: item_ids::WOODEN_TANKARD, weight: 9, min_amount: 1, max_amount: 1 },
 ];
 
+/// Elite bosses draw their weapon from the upper half of `BOSS_WEAPONS`.
+const ELITE_BOSS_WEAPONS: [LootSpec; 4] = [
+    LootSpec { item_id: item_ids::IRON_SWORD, weight: 10, min_amount: 1, max_amount: 1 },
+    LootSpec { item_id: item_ids::DIAMOND_SWORD, weight: 8, min_amount: 1, max_amount: 1 },
+    LootSpec { item_id: item_ids::NOKIA_3310, weight: 5, min_amount: 1, max_amount: 1 },
+    LootSpec { item_id: item_ids::IRON_SCIMITAR, weight: 10, min_amount: 1, max_amount: 1 },
+];
+
 const BOSS_VALUABLES: [LootSpec; 15] = [
     LootSpec { item_id: item_ids::GOLD_COIN, weight: 19, min_amount: 6, max_amount: 18 },
     LootSpec { item_id: item_ids::GOLD_BAR, weight: 14, min_amount: 1, max_amount: 3 },
@@ -267,16 +296,17 @@ const BOSS_VALUABLES: [LootSpec; 15] = [
     LootSpec { item_id: item_ids::SKELETON_KEY, weight: 3, min_amount: 1, max_amount: 1 },
 ];
 
-fn build_boss_loot_bundle(seed: u64, boss_id: u16) -> Vec<LootStack> {
+fn build_boss_loot_bundle(seed: u64, boss_id: u16, is_elite: bool) -> Vec<LootStack> {
     let mut rng = LootRng::new(seed);
     let mut drops = Vec::<LootStack>::new();
     let is_skeleton_boss = boss_id == 11;
 
-    // Boss always drops a weapon.
-    append_single_roll(&mut drops, &BOSS_WEAPONS, item_types::TOOL, &mut rng);
+    // Boss always drops a weapon; elites always drop a good one.
+    let weapons: &[LootSpec] = if is_elite { &ELITE_BOSS_WEAPONS } else { &BOSS_WEAPONS };
+    append_single_roll(&mut drops, weapons, item_types::TOOL, &mut rng);
 
-    // Boss drops 2-4 valuable stacks; skeleton boss 11 drops 3-5.
-    let valuable_stacks = if is_skeleton_boss {
+    // Boss drops 2-4 valuable stacks; skeleton boss 11 drops 3-5; elites one more.
+    let base_valuable_stacks = if is_skeleton_boss {
         let valuable_roll = rng.range_u32(100);
         if valuable_roll < 40 {
             3
@@ -295,6 +325,7 @@ fn build_boss_loot_bundle(seed: u64, boss_id: u16) -> Vec<LootStack> {
             4
         }
     };
+    let valuable_stacks = base_valuable_stacks + usize::from(is_elite);
     append_unique_rolls(
         &mut drops,
         &BOSS_VALUABLES,
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
-use crate::events::{item_types, ChestLooted};
+use crate::events::{item_types, ChestLooted, LootOverflowed, PlayerLeveledUp};
+use crate::instructions::claim_discovery_royalties::accrue_discovery_royalty;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    item_ids, session_instruction_bits, GlobalAccount, InventoryAccount, LootReceipt,
-    PlayerAccount, RoomAccount, SessionAuthority, CENTER_BONE_CHEST, CENTER_CHEST,
-    CENTER_GILDED_CHEST, CENTER_SARCOPHAGUS_CHEST,
+    floor_depth, floor_season_seed, forced_key_drop_for_room, item_ids, pause_flags,
+    royalty_sources, session_instruction_bits, DiscoveryRoyaltyAccount, GlobalAccount,
+    InventoryAccount, LootReceipt, LootRng, PlayerAccount, RoomAccount, SessionAuthority,
+    CENTER_BONE_CHEST, CENTER_CHEST, CENTER_GILDED_CHEST, CENTER_SARCOPHAGUS_CHEST,
+    XP_PER_CHEST_LOOTED,
 };
 
 #[derive(Accounts)]
@@ -39,7 +42,8 @@ pub struct LootChest<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -64,12 +68,23 @@ pub struct LootChest<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump
     )]
     pub loot_receipt: Account<'info, LootReceipt>,
 
+    /// Royalty account of the room's discoverer, opened by the caller if it is missing
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DiscoveryRoyaltyAccount::DISCRIMINATOR.len() + DiscoveryRoyaltyAccount::INIT_SPACE,
+        seeds = [DiscoveryRoyaltyAccount::SEED_PREFIX, room.created_by.as_ref()],
+        bump
+    )]
+    pub discoverer_royalty: Box<Account<'info, DiscoveryRoyaltyAccount>>,
+
     #[account(
         mut,
         seeds = [
@@ -85,6 +100,8 @@ pub struct LootChest<'info> {
 }
 
 pub fn handler(ctx: Context<LootChest>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -93,9 +110,12 @@ pub fn handler(ctx: Context<LootChest>) -> Result<()> {
         0,
     )?;
 
+    let grid = ctx.accounts.global.grid();
     let room = &mut ctx.accounts.room;
     let player_account = &mut ctx.accounts.player_account;
     let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
     let loot_receipt = &mut ctx.accounts.loot_receipt;
     let player_key = ctx.accounts.player.key();
     let clock = Clock::get()?;
@@ -132,6 +152,22 @@ pub fn handler(ctx: Context<LootChest>) -> Result<()> {
     room.looted_count += 1;
     player_account.chests_looted += 1;
     player_account.mark_active(clock.slot);
+    if player_account.grant_xp(XP_PER_CHEST_LOOTED) {
+        emit!(PlayerLeveledUp {
+            player: player_key,
+            level: player_account.level,
+            xp: player_account.xp,
+            max_hp: player_account.max_hp,
+        });
+    }
+    accrue_discovery_royalty(
+        &mut ctx.accounts.discoverer_royalty,
+        ctx.bumps.discoverer_royalty,
+        room,
+        player_key,
+        royalty_sources::CHEST_LOOTED,
+        DiscoveryRoyaltyAccount::CHEST_LOOTED_ROYALTY,
+    );
 
     // Generate deterministic loot bundle based on slot + player pubkey
     let loot_hash = generate_loot_hash(clock.slot, &player_key);
@@ -146,14 +182,43 @@ pub fn handler(ctx: Context<LootChest>) -> Result<()> {
     let mut event_item_type = item_types::ORE;
     let mut event_item_amount_total = 0u32;
     for stack in loot_bundle.iter() {
-        inventory.add_item(stack.item_id, stack.amount, stack.durability)?;
+        // A full inventory forfeits the stack instead of reverting the whole loot.
+        if !inventory.try_add_item(stack.item_id, stack.amount, stack.durability)? {
+            emit!(LootOverflowed {
+                player: player_key,
+                room_x: room.x,
+                room_y: room.y,
+                item_id: stack.item_id,
+                amount: stack.amount,
+                durability: stack.durability,
+            });
+            continue;
+        }
         event_item_amount_total = event_item_amount_total.saturating_add(stack.amount);
         event_item_type = stack.item_type;
     }
 
     if room.forced_key_drop {
-        inventory.add_item(item_ids::SKELETON_KEY, 1, 0)?;
-        event_item_amount_total = event_item_amount_total.saturating_add(1);
+        let (key_item_id, key_amount) = forced_key_drop_for_room(
+            floor_season_seed(room.season_seed, room.floor),
+            grid,
+            room.x,
+            room.y,
+            floor_depth(grid, room.floor, room.x, room.y),
+        )
+        .unwrap_or((item_ids::SKELETON_KEY, 1));
+        if inventory.try_add_item(key_item_id, key_amount, 0)? {
+            event_item_amount_total = event_item_amount_total.saturating_add(key_amount);
+        } else {
+            emit!(LootOverflowed {
+                player: player_key,
+                room_x: room.x,
+                room_y: room.y,
+                item_id: key_item_id,
+                amount: key_amount,
+                durability: 0,
+            });
+        }
     }
 
     emit!(ChestLooted {
@@ -207,31 +272,8 @@ struct LootStack {
     item_type: u8,
 }
 
-struct LootRng {
-    state: u64,
-}
-
-impl LootRng {
-    fn new(seed: u64) -> Self {
-        // Avoid zero-lock and keep deterministic progression.
-        Self { state: seed ^ 0x9E37_79B9_7F4A_7C15 }
-    }
-
-    fn next_u64(&mut self) -> u64 {
-        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
-        let mut z = self.state;
-        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
-        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
-        z ^ (z >> 31)
-    }
-
-    fn range_u32(&mut self, upper_exclusive: u32) -> u32 {
-        if upper_exclusive <= 1 {
-            return 0;
-        }
-        (self.next_u64() % u64::from(upper_exclusive)) as u32
-    }
-}
+/// Percent of chests that also hold a Trap Kit.
+const TRAP_KIT_DROP_CHANCE: u32 = 12;
 
 // Chest valuables lean common, with occasional key and uncommon trinkets.
 const CHEST_VALUABLES: [LootSpec; 12] = [
@@ -401,6 +443,16 @@ fn build_chest_loot_bundle(seed: u64, center_type: u8) -> Vec<LootStack> {
         append_single_roll(&mut drops, weapon_pool, item_types::TOOL, &mut rng);
     }
 
+    // Occasional Trap Kit so players can disarm the traps they run into.
+    if rng.range_u32(100) < TRAP_KIT_DROP_CHANCE {
+        drops.push(LootStack {
+            item_id: item_ids::TRAP_KIT,
+            amount: 1,
+            durability: 0,
+            item_type: item_types::TOOL,
+        });
+    }
+
     drops
 }
 
@@ -424,6 +476,18 @@ mod tests {
         assert!(!gilded.is_empty());
         assert!(!sarcophagus.is_empty());
     }
+
+    #[test]
+    fn chests_sometimes_drop_trap_kits() {
+        let drops = (0u64..500)
+            .filter(|seed| {
+                build_chest_loot_bundle(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15), CENTER_CHEST)
+                    .iter()
+                    .any(|stack| stack.item_id == item_ids::TRAP_KIT)
+            })
+            .count();
+        assert!((20..=110).contains(&drops), "Trap Kit dropped from {drops} of 500 chests");
+    }
 }
 
 fn append_single_roll(
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::events::GlobalAccountMigrated;
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::GlobalAccount;
+
+#[derive(Accounts)]
+pub struct MigrateGlobal<'info> {
+    /// Anyone can pay the rent for the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: decoded manually so a global created before the latest fields
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGlobal>) -> Result<()> {
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let old_len = global_info.data_len();
+    // Appended fields read as zero, which every one of them treats as "unset".
+    let global: GlobalAccount = decode_padded(&global_info, global_space)?;
+
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+    {
+        let mut data = global_info.try_borrow_mut_data()?;
+        global.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(GlobalAccountMigrated {
+        payer: ctx.accounts.payer.key(),
+        old_len: old_len as u32,
+        new_len: global_info.data_len() as u32,
+    });
+
+    Ok(())
+}
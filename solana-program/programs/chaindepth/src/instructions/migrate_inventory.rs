@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::InventoryAccountMigrated;
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::InventoryAccount;
+
+#[derive(Accounts)]
+pub struct MigrateInventory<'info> {
+    /// Pays any rent needed for the larger layout
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    /// CHECK: PDA seed validation guarantees this is the caller's inventory PDA.
+    /// Deserialized manually because a full legacy inventory has no room for `bonus_slots`.
+    pub inventory: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateInventory>) -> Result<()> {
+    let account_info = ctx.accounts.inventory.to_account_info();
+    require_keys_eq!(*account_info.owner, crate::ID, ChainDepthError::Unauthorized);
+
+    let old_len = account_info.data_len();
+    let mut inventory: InventoryAccount =
+        decode_padded(&account_info, InventoryAccount::space_for_bonus_slots(0))?;
+    inventory.reset_stale_bonus_slots(old_len);
+    require_keys_eq!(
+        inventory.owner,
+        ctx.accounts.player.key(),
+        ChainDepthError::Unauthorized
+    );
+
+    resize_with_rent(
+        &account_info,
+        &ctx.accounts.player,
+        &ctx.accounts.system_program,
+        InventoryAccount::space_for_bonus_slots(inventory.bonus_slots),
+    )?;
+    {
+        let mut data = account_info.try_borrow_mut_data()?;
+        inventory.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(InventoryAccountMigrated {
+        player: ctx.accounts.player.key(),
+        old_len: old_len as u32,
+        new_len: account_info.data_len() as u32,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ChainDepthError;
+use crate::events::PlayerAccountMigrated;
+use crate::state::{
+    decode_player_account, player_account_len_for_version, upgrade_player_account, PlayerAccount,
+};
+
+#[derive(Accounts)]
+pub struct MigratePlayerAccount<'info> {
+    /// Pays any rent needed for the larger layout
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    /// CHECK: PDA seed validation guarantees this is the caller's canonical player_account PDA.
+    /// Deserialized manually because older layouts are shorter than the current one.
+    pub player_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePlayerAccount>) -> Result<()> {
+    let account_info = ctx.accounts.player_account.to_account_info();
+    require_keys_eq!(*account_info.owner, crate::ID, ChainDepthError::Unauthorized);
+
+    let old_len = account_info.data_len();
+    let mut player_account = {
+        let data = account_info.try_borrow_data()?;
+        decode_player_account(&data)?
+    };
+    require_keys_eq!(
+        player_account.owner,
+        ctx.accounts.player.key(),
+        ChainDepthError::Unauthorized
+    );
+
+    let from_version = upgrade_player_account(&mut player_account)?;
+
+    let new_len = player_account_len_for_version(PlayerAccount::CURRENT_DATA_VERSION);
+    if old_len < new_len {
+        let required_lamports = Rent::get()?.minimum_balance(new_len);
+        let top_up = required_lamports.saturating_sub(account_info.lamports());
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.player.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        account_info.resize(new_len)?;
+    }
+
+    {
+        let mut data = account_info.try_borrow_mut_data()?;
+        player_account.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(PlayerAccountMigrated {
+        player: ctx.accounts.player.key(),
+        from_version,
+        to_version: PlayerAccount::CURRENT_DATA_VERSION,
+        old_len: old_len as u32,
+        new_len: account_info.data_len() as u32,
+    });
+
+    Ok(())
+}
@@ -1,72 +1,145 @@
 pub mod abandon_job;
+pub mod accept_role;
+pub mod account_resize;
 pub mod add_inventory_item;
+pub mod approve_admin_action;
+pub mod ascend;
 pub mod begin_session;
 pub mod boost_job;
+pub mod buy_item;
+pub mod cancel_admin_action;
+pub mod claim_discovery_royalties;
 pub mod claim_job_reward;
+pub mod claim_world_boss_reward;
 pub mod complete_job;
+pub mod configure_shop;
 pub mod create_player_profile;
+pub mod crank_boss_fight;
 pub mod create_duel_challenge;
+pub mod deposit_to_storage;
+pub mod descend;
+pub mod disarm_trap;
 pub mod accept_duel_challenge;
 pub mod consume_duel_randomness;
 pub mod decline_duel_challenge;
+pub mod execute_admin_action;
 pub mod expire_duel_challenge;
 pub mod end_session;
 pub mod enter_dungeon;
 pub mod ensure_start_room;
 pub mod exit_dungeon;
+pub mod expand_inventory;
+pub mod expand_storage;
+pub mod export_item;
 pub mod equip_item;
 pub mod force_reset_season;
 pub mod force_exit_on_death;
+pub mod fund_treasury;
+pub mod import_item;
 pub mod init_global;
 pub mod join_boss_fight;
 pub mod join_job;
 pub mod join_job_with_session;
+pub mod join_world_boss;
 pub mod loot_boss;
 pub mod loot_chest;
+pub mod migrate_global;
+pub mod migrate_inventory;
+pub mod migrate_player_account;
 pub mod leave_boss_fight;
+pub mod leave_world_boss;
 pub mod move_player;
+pub mod pray_at_shrine;
+pub mod propose_admin_action;
 pub mod remove_inventory_item;
 pub mod reset_season;
 pub mod reset_player_for_testing;
 pub mod reset_my_player;
+pub mod sell_valuable;
+pub mod set_deep_exit_score;
+pub mod set_role;
+pub mod set_season_grid;
 pub mod session_auth;
+pub mod set_shop_listing;
+pub mod set_valuable_sell_price;
+pub mod spawn_world_boss;
+pub mod set_pause_flags;
 pub mod set_player_skin;
 pub mod tick_boss_fight;
 pub mod tick_job;
+pub mod trade_with_merchant;
 pub mod unlock_door;
+pub mod use_item;
+pub mod withdraw_from_storage;
 
 pub use abandon_job::*;
+pub use accept_role::*;
 pub use add_inventory_item::*;
+pub use approve_admin_action::*;
+pub use ascend::*;
 pub use begin_session::*;
 pub use boost_job::*;
+pub use buy_item::*;
+pub use cancel_admin_action::*;
+pub use claim_discovery_royalties::*;
 pub use claim_job_reward::*;
+pub use claim_world_boss_reward::*;
 pub use complete_job::*;
+pub use configure_shop::*;
 pub use create_player_profile::*;
+pub use crank_boss_fight::*;
 pub use create_duel_challenge::*;
+pub use deposit_to_storage::*;
+pub use descend::*;
+pub use disarm_trap::*;
 pub use accept_duel_challenge::*;
 pub use consume_duel_randomness::*;
 pub use decline_duel_challenge::*;
+pub use execute_admin_action::*;
 pub use expire_duel_challenge::*;
 pub use end_session::*;
 pub use enter_dungeon::*;
 pub use ensure_start_room::*;
 pub use exit_dungeon::*;
+pub use expand_inventory::*;
+pub use expand_storage::*;
+pub use export_item::*;
 pub use equip_item::*;
 pub use force_reset_season::*;
 pub use force_exit_on_death::*;
+pub use fund_treasury::*;
+pub use import_item::*;
 pub use init_global::*;
 pub use join_boss_fight::*;
 pub use join_job::*;
 pub use join_job_with_session::*;
+pub use join_world_boss::*;
 pub use loot_boss::*;
 pub use loot_chest::*;
+pub use migrate_global::*;
+pub use migrate_inventory::*;
+pub use migrate_player_account::*;
 pub use leave_boss_fight::*;
+pub use leave_world_boss::*;
 pub use move_player::*;
+pub use pray_at_shrine::*;
+pub use propose_admin_action::*;
 pub use remove_inventory_item::*;
 pub use reset_season::*;
 pub use reset_player_for_testing::*;
 pub use reset_my_player::*;
+pub use sell_valuable::*;
+pub use set_deep_exit_score::*;
+pub use set_role::*;
+pub use set_season_grid::*;
+pub use set_pause_flags::*;
 pub use set_player_skin::*;
+pub use set_shop_listing::*;
+pub use set_valuable_sell_price::*;
+pub use spawn_world_boss::*;
 pub use tick_boss_fight::*;
 pub use tick_job::*;
+pub use trade_with_merchant::*;
 pub use unlock_door::*;
+pub use use_item::*;
+pub use withdraw_from_storage::*;
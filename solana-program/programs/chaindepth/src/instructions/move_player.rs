@@ -3,11 +3,15 @@ use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::ChainDepthError;
-use crate::events::PlayerMoved;
+use crate::events::{PlayerDied, PlayerEncumbrance, PlayerMoved, TrapTriggered};
+use crate::instructions::account_resize::decode_padded;
+use crate::instructions::force_exit_on_death::apply_death_outcome;
+use crate::instructions::fund_treasury::reimburse_room_rent;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    calculate_depth, enforce_special_room_topology, initialize_discovered_room,
-    session_instruction_bits, GlobalAccount, PlayerAccount, PlayerProfile, RoomAccount,
+    enforce_special_room_topology, floor_depth, initialize_discovered_room, max_carry_weight,
+    pause_flags, session_instruction_bits, DiscoveryRoyaltyAccount, FloorConnectivity,
+    GlobalAccount, InventoryAccount, PlayerAccount, PlayerProfile, RoomAccount, RoomLocation,
     RoomPresence, SessionAuthority, LOCK_KIND_NONE, WALL_OPEN,
 };
 
@@ -54,7 +58,8 @@ pub struct MovePlayer<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -69,12 +74,27 @@ pub struct MovePlayer<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[new_x as u8],
-            &[new_y as u8]
+            &[new_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
     pub target_room: Account<'info, RoomAccount>,
 
+    /// Connectivity repairs of this floor; the first discovery on the floor fills them in
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = FloorConnectivity::DISCRIMINATOR.len() + FloorConnectivity::INIT_SPACE,
+        seeds = [
+            FloorConnectivity::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_floor]
+        ],
+        bump
+    )]
+    pub floor_connectivity: Box<Account<'info, FloorConnectivity>>,
+
     /// Closed on move so rent returns to the current fee payer (authority)
     #[account(
         mut,
@@ -84,6 +104,7 @@ pub struct MovePlayer<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = current_presence.bump
@@ -99,12 +120,33 @@ pub struct MovePlayer<'info> {
             &global.season_seed.to_le_bytes(),
             &[new_x as u8],
             &[new_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump
     )]
     pub target_presence: Account<'info, RoomPresence>,
 
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    /// CHECK: PDA seed validation guarantees this is the player's inventory PDA.
+    /// May not exist yet, in which case the player carries nothing.
+    /// Written back only when a trap kills the player and loot is lost.
+    pub inventory: UncheckedAccount<'info>,
+
+    /// Opened on the player's first move so the rooms they discover can pay them royalties
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DiscoveryRoyaltyAccount::DISCRIMINATOR.len() + DiscoveryRoyaltyAccount::INIT_SPACE,
+        seeds = [DiscoveryRoyaltyAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub discovery_royalty: Box<Account<'info, DiscoveryRoyaltyAccount>>,
+
     #[account(
         mut,
         seeds = [
@@ -120,6 +162,8 @@ pub struct MovePlayer<'info> {
 }
 
 pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -132,6 +176,7 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
     let current_room = &ctx.accounts.current_room;
     let season_seed = ctx.accounts.global.season_seed;
+    let grid = ctx.accounts.global.grid();
     let player_key = ctx.accounts.player.key();
     let clock = Clock::get()?;
 
@@ -142,22 +187,20 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
         profile.starter_pickaxe_granted = false;
         profile.bump = ctx.bumps.profile;
     }
+    ctx.accounts.discovery_royalty.open(
+        player_key,
+        season_seed,
+        ctx.bumps.discovery_royalty,
+    );
 
     // Check bounds
-    require!(
-        new_x >= GlobalAccount::MIN_COORD && new_x <= GlobalAccount::MAX_COORD,
-        ChainDepthError::OutOfBounds
-    );
-    require!(
-        new_y >= GlobalAccount::MIN_COORD && new_y <= GlobalAccount::MAX_COORD,
-        ChainDepthError::OutOfBounds
-    );
+    require!(grid.contains(new_x, new_y), ChainDepthError::OutOfBounds);
 
     // Initialize player if first time (new player starts at spawn)
     if player_account.owner == Pubkey::default() {
         player_account.owner = player_key;
-        player_account.current_room_x = GlobalAccount::START_X;
-        player_account.current_room_y = GlobalAccount::START_Y;
+        player_account.current_room_x = grid.start_x;
+        player_account.current_room_y = grid.start_y;
         player_account.active_jobs = Vec::new();
         player_account.jobs_completed = 0;
         player_account.chests_looted = 0;
@@ -172,10 +215,31 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
         player_account.data_version = PlayerAccount::CURRENT_DATA_VERSION;
         player_account.last_active_slot = clock.slot;
         player_account.bump = ctx.bumps.player_account;
+        player_account.xp = 0;
+        player_account.level = 1;
+        player_account.current_floor = 0;
     }
+    // Legacy layouts cannot be read safely here; migrate_player_account upgrades them.
+    player_account.require_current_version()?;
 
     player_account.require_in_dungeon()?;
 
+    let inventory_info = ctx.accounts.inventory.to_account_info();
+    let inventory = if *inventory_info.owner == crate::ID {
+        let base_len = InventoryAccount::space_for_bonus_slots(0);
+        Some(decode_padded::<InventoryAccount>(&inventory_info, base_len)?)
+    } else {
+        None
+    };
+    let carry_weight = inventory.as_ref().map_or(0, InventoryAccount::carry_weight);
+    let max_carry = max_carry_weight(player_account.level);
+    require!(carry_weight <= max_carry, ChainDepthError::OverEncumbered);
+    emit!(PlayerEncumbrance {
+        player: player_key,
+        carry_weight,
+        max_carry_weight: max_carry,
+    });
+
     let from_x = player_account.current_room_x;
     let from_y = player_account.current_room_y;
 
@@ -226,12 +290,24 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
     let target_room = &mut ctx.accounts.target_room;
     let is_new_room = target_room.season_seed == 0;
     if is_new_room {
+        let floor_connectivity = &mut ctx.accounts.floor_connectivity;
+        floor_connectivity.ensure_analyzed(
+            season_seed,
+            player_account.current_floor,
+            grid,
+            ctx.bumps.floor_connectivity,
+        );
         initialize_discovered_room(
             target_room,
-            season_seed,
-            new_x,
-            new_y,
+            RoomLocation::new(
+                season_seed,
+                grid,
+                player_account.current_floor,
+                new_x,
+                new_y,
+            ),
             opposite_direction,
+            floor_connectivity.repaired_directions(grid, new_x, new_y),
             player_key,
             clock.slot,
             ctx.bumps.target_room,
@@ -239,7 +315,7 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
     }
     target_room.walls[opposite_direction as usize] = WALL_OPEN;
     target_room.door_lock_kinds[opposite_direction as usize] = LOCK_KIND_NONE;
-    enforce_special_room_topology(target_room);
+    enforce_special_room_topology(target_room, grid);
     let return_wall_state = target_room.walls[opposite_direction as usize];
     msg!(
         "move_topology target=({}, {}) return_dir={} return_wall_state={}",
@@ -253,32 +329,23 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
         ChainDepthError::WallNotOpen
     );
 
-    let room_depth = calculate_depth(new_x, new_y);
+    let room_depth = floor_depth(grid, player_account.current_floor, new_x, new_y);
     if room_depth > ctx.accounts.global.depth {
         ctx.accounts.global.depth = room_depth;
     }
 
     // Reimburse authority for room creation rent from treasury (manual lamport transfer)
     if is_new_room {
-        let room_space = 8 + RoomAccount::INIT_SPACE;
-        let rent_cost = Rent::get()?.minimum_balance(room_space);
-        let global_info = ctx.accounts.global.to_account_info();
-        let authority_info = ctx.accounts.authority.to_account_info();
-        **global_info.try_borrow_mut_lamports()? = global_info
-            .lamports()
-            .checked_sub(rent_cost)
-            .ok_or(ChainDepthError::TreasuryInsufficientFunds)?;
-        **authority_info.try_borrow_mut_lamports()? = authority_info
-            .lamports()
-            .checked_add(rent_cost)
-            .ok_or(ChainDepthError::Overflow)?;
+        reimburse_room_rent(
+            &mut ctx.accounts.global,
+            &ctx.accounts.authority.to_account_info(),
+        )?;
     }
 
     // Update player position
     player_account.current_room_x = new_x;
     player_account.current_room_y = new_y;
     player_account.in_dungeon = true;
-    player_account.data_version = PlayerAccount::CURRENT_DATA_VERSION;
 
     upsert_presence(
         &mut ctx.accounts.target_presence,
@@ -290,6 +357,7 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
         player_account.equipped_item_id,
         ctx.bumps.target_presence,
     );
+    ctx.accounts.target_presence.floor = player_account.current_floor;
     ctx.accounts.target_presence.is_current = true;
     ctx.accounts.target_presence.set_idle();
 
@@ -301,6 +369,56 @@ pub fn handler(ctx: Context<MovePlayer>, new_x: i8, new_y: i8) -> Result<()> {
         to_y: new_y,
     });
 
+    // Armed traps hit whoever walks in; a lethal hit ends the run like a boss death.
+    let target_room = &ctx.accounts.target_room;
+    if target_room.is_trap_armed() {
+        let previous_hp = player_account.current_hp;
+        player_account.current_hp =
+            previous_hp.saturating_sub(RoomAccount::trap_damage_for_depth(room_depth));
+        emit!(TrapTriggered {
+            player: player_key,
+            room_x: new_x,
+            room_y: new_y,
+            trap_id: target_room.center_id,
+            damage: previous_hp.saturating_sub(player_account.current_hp),
+            current_hp: player_account.current_hp,
+            max_hp: player_account.max_hp,
+        });
+
+        if player_account.current_hp == 0 {
+            let mut inventory = inventory.unwrap_or(InventoryAccount {
+                owner: player_key,
+                items: Vec::new(),
+                bump: 0,
+                bonus_slots: 0,
+            });
+            let death_outcome = apply_death_outcome(
+                player_account,
+                &mut inventory,
+                &mut ctx.accounts.target_presence,
+                clock.slot,
+            )?;
+            if death_outcome.lost_item_stacks > 0 {
+                let mut data = inventory_info.try_borrow_mut_data()?;
+                inventory.try_serialize(&mut &mut data[..])?;
+            }
+            // Out of the dungeon the player stands at the entrance, not in the trap room.
+            player_account.current_room_x = grid.start_x;
+            player_account.current_room_y = grid.start_y;
+            player_account.current_floor = 0;
+            ctx.accounts.target_presence.is_current = false;
+
+            emit!(PlayerDied {
+                player: player_key,
+                room_x: new_x,
+                room_y: new_y,
+                boss_id: 0,
+                lost_item_stacks: death_outcome.lost_item_stacks,
+                lost_item_units: death_outcome.lost_item_units,
+            });
+        }
+    }
+
     Ok(())
 }
 
@@ -348,8 +466,8 @@ pub struct InitPlayer<'info> {
         seeds = [
             RoomPresence::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
-            &[GlobalAccount::START_X as u8],
-            &[GlobalAccount::START_Y as u8],
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8],
             player.key().as_ref()
         ],
         bump
@@ -377,12 +495,13 @@ pub fn init_player_handler(ctx: Context<InitPlayer>) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
     let room_presence = &mut ctx.accounts.room_presence;
     let global = &ctx.accounts.global;
+    let grid = global.grid();
     let player_key = ctx.accounts.player.key();
     let clock = Clock::get()?;
 
     player_account.owner = player_key;
-    player_account.current_room_x = GlobalAccount::START_X;
-    player_account.current_room_y = GlobalAccount::START_Y;
+    player_account.current_room_x = grid.start_x;
+    player_account.current_room_y = grid.start_y;
     player_account.active_jobs = Vec::new();
     player_account.jobs_completed = 0;
     player_account.chests_looted = 0;
@@ -397,6 +516,9 @@ pub fn init_player_handler(ctx: Context<InitPlayer>) -> Result<()> {
     player_account.data_version = PlayerAccount::CURRENT_DATA_VERSION;
     player_account.last_active_slot = clock.slot;
     player_account.bump = ctx.bumps.player_account;
+    player_account.xp = 0;
+    player_account.level = 1;
+    player_account.current_floor = 0;
 
     profile.owner = player_key;
     profile.skin_id = PlayerProfile::DEFAULT_SKIN_ID;
@@ -406,8 +528,8 @@ pub fn init_player_handler(ctx: Context<InitPlayer>) -> Result<()> {
 
     room_presence.player = player_key;
     room_presence.season_seed = global.season_seed;
-    room_presence.room_x = GlobalAccount::START_X;
-    room_presence.room_y = GlobalAccount::START_Y;
+    room_presence.room_x = grid.start_x;
+    room_presence.room_y = grid.start_y;
     room_presence.skin_id = profile.skin_id;
     room_presence.equipped_item_id = 0;
     room_presence.set_idle();
@@ -433,8 +555,8 @@ pub fn init_player_handler(ctx: Context<InitPlayer>) -> Result<()> {
         player: ctx.accounts.player.key(),
         from_x: 0,
         from_y: 0,
-        to_x: GlobalAccount::START_X,
-        to_y: GlobalAccount::START_Y,
+        to_x: grid.start_x,
+        to_y: grid.start_y,
     });
 
     Ok(())
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::ShrinePrayed;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    is_scored_loot_item, pause_flags, session_instruction_bits, GlobalAccount, InventoryAccount,
+    PlayerAccount, RoomAccount, SessionAuthority, ShrineReceipt, CENTER_SHRINE, SHRINE_TRIBUTE,
+};
+
+#[derive(Accounts)]
+pub struct PrayAtShrine<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Room with the shrine
+    #[account(
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
+        ],
+        bump
+    )]
+    pub room: Account<'info, RoomAccount>,
+
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = inventory.bump,
+        constraint = inventory.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub inventory: Account<'info, InventoryAccount>,
+
+    /// Per-player shrine receipt - existence proves player already prayed here
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ShrineReceipt::DISCRIMINATOR.len() + ShrineReceipt::INIT_SPACE,
+        seeds = [
+            ShrineReceipt::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump
+    )]
+    pub shrine_receipt: Account<'info, ShrineReceipt>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PrayAtShrine>, tribute_item_id: u16) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::PRAY_AT_SHRINE,
+        0,
+    )?;
+
+    let room = &ctx.accounts.room;
+    let player_account = &mut ctx.accounts.player_account;
+    let shrine_receipt = &mut ctx.accounts.shrine_receipt;
+    let player_key = ctx.accounts.player.key();
+    let clock = Clock::get()?;
+    player_account.require_in_dungeon()?;
+
+    require!(room.center_type == CENTER_SHRINE, ChainDepthError::NoShrine);
+    require!(
+        player_account.is_at_room(room.x, room.y),
+        ChainDepthError::NotInRoom
+    );
+    require!(
+        shrine_receipt.player == Pubkey::default(),
+        ChainDepthError::ShrineAlreadyUsed
+    );
+    require!(
+        player_account.current_hp < player_account.max_hp,
+        ChainDepthError::AlreadyAtFullHp
+    );
+
+    // Tribute shrines take one carried valuable; free shrines ignore the argument.
+    let tribute_item_id = if room.center_id == SHRINE_TRIBUTE {
+        require!(
+            is_scored_loot_item(tribute_item_id),
+            ChainDepthError::InvalidTribute
+        );
+        ctx.accounts.inventory.remove_item(tribute_item_id, 1)?;
+        tribute_item_id
+    } else {
+        0
+    };
+
+    shrine_receipt.player = player_key;
+    shrine_receipt.season_seed = ctx.accounts.global.season_seed;
+    shrine_receipt.room_x = room.x;
+    shrine_receipt.room_y = room.y;
+    shrine_receipt.bump = ctx.bumps.shrine_receipt;
+
+    let healed = player_account.max_hp - player_account.current_hp;
+    player_account.current_hp = player_account.max_hp;
+    player_account.mark_active(clock.slot);
+
+    emit!(ShrinePrayed {
+        player: player_key,
+        room_x: room.x,
+        room_y: room.y,
+        healed,
+        current_hp: player_account.current_hp,
+        tribute_item_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::{AdminActionApproved, AdminActionProposed};
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::{AdminAction, GlobalAccount, PendingAdminAction};
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ProposeAdminAction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// CHECK: decoded manually so a global created before the admin set fields
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingAdminAction::DISCRIMINATOR.len() + PendingAdminAction::INIT_SPACE,
+        seeds = [
+            PendingAdminAction::SEED_PREFIX,
+            proposer.key().as_ref(),
+            &action_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeAdminAction>, action_id: u64, action: AdminAction) -> Result<()> {
+    action.validate()?;
+
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let global: GlobalAccount = decode_padded(&global_info, global_space)?;
+    let proposer = ctx.accounts.proposer.key();
+    require!(
+        global.is_admin_signer(&proposer),
+        ChainDepthError::NotInAdminSet
+    );
+
+    // Grow the global now so executing the action never has to.
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.proposer,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+    {
+        let mut data = global_info.try_borrow_mut_data()?;
+        global.try_serialize(&mut &mut data[..])?;
+    }
+
+    let clock = Clock::get()?;
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.proposer = proposer;
+    pending_action.action_id = action_id;
+    pending_action.action = action;
+    pending_action.proposed_slot = clock.slot;
+    pending_action.execute_after_slot = clock
+        .slot
+        .checked_add(global.admin_timelock_slots())
+        .ok_or(ChainDepthError::Overflow)?;
+    pending_action.bump = ctx.bumps.pending_action;
+    pending_action.approve(proposer, &global)?;
+
+    emit!(AdminActionProposed {
+        proposer,
+        action_id,
+        action,
+        execute_after_slot: pending_action.execute_after_slot,
+        approvals_required: global.admin_threshold(),
+    });
+    emit!(AdminActionApproved {
+        proposer,
+        action_id,
+        approver: proposer,
+        approval_count: pending_action.approval_count,
+    });
+
+    Ok(())
+}
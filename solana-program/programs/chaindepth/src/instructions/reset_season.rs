@@ -2,18 +2,18 @@ use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
 use crate::events::SeasonReset;
-use crate::state::GlobalAccount;
+use crate::state::{roles, GlobalAccount};
 
 #[derive(Accounts)]
 pub struct ResetSeason<'info> {
-    /// Admin or authorized relayer
+    /// Season operator
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [GlobalAccount::SEED_PREFIX],
         bump = global.bump,
-        constraint = global.admin == authority.key() @ ChainDepthError::Unauthorized
+        constraint = global.is_role_holder(roles::SEASON_OPERATOR, &authority.key()) @ ChainDepthError::Unauthorized
     )]
     pub global: Account<'info, GlobalAccount>,
 }
@@ -51,9 +51,11 @@ pub(crate) fn apply_season_reset(global: &mut GlobalAccount, current_slot: u64)
     let end_slot = current_slot + GlobalAccount::SEASON_DURATION_SLOTS;
 
     global.season_seed = new_seed;
+    global.grid = global.next_grid();
     global.depth = 0;
     global.jobs_completed = 0;
     global.end_slot = end_slot;
+    global.season_rent_reimbursed = 0;
 
     (old_seed, new_seed, old_depth, end_slot)
 }
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ChainDepthError;
+use crate::events::ValuableSold;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, session_instruction_bits, GlobalAccount, PlayerAccount, SessionAuthority,
+    ShopConfig, StorageAccount,
+};
+
+#[derive(Accounts)]
+pub struct SellValuable<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Box<Account<'info, PlayerAccount>>,
+
+    #[account(
+        seeds = [ShopConfig::SEED_PREFIX],
+        bump = shop_config.bump
+    )]
+    pub shop_config: Box<Account<'info, ShopConfig>>,
+
+    #[account(
+        mut,
+        seeds = [StorageAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = storage.bump,
+        constraint = storage.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub storage: Box<Account<'info, StorageAccount>>,
+
+    /// Prize pool pays out buy-back SKR
+    #[account(
+        mut,
+        constraint = prize_pool.key() == global.prize_pool
+    )]
+    pub prize_pool: Box<Account<'info, TokenAccount>>,
+
+    /// Player's SKR token account
+    #[account(
+        mut,
+        constraint = player_token_account.mint == global.skr_mint,
+        constraint = player_token_account.owner == player.key()
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SellValuable>, item_id: u16, amount: u32) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::SELL_VALUABLE,
+        0,
+    )?;
+
+    ctx.accounts.player_account.require_out_of_dungeon()?;
+
+    let payout = ctx.accounts.shop_config.sell_payout(item_id, amount)?;
+    require!(
+        ctx.accounts.prize_pool.amount >= payout,
+        ChainDepthError::PrizePoolInsufficientFunds
+    );
+
+    // Valuables are always banked with zero durability.
+    ctx.accounts.storage.remove_stack(item_id, 0, amount)?;
+
+    let global_bump = ctx.accounts.global.bump;
+    let global_seeds = &[GlobalAccount::SEED_PREFIX, &[global_bump]];
+    let global_signer = &[&global_seeds[..]];
+    let payout_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.prize_pool.to_account_info(),
+            to: ctx.accounts.player_token_account.to_account_info(),
+            authority: ctx.accounts.global.to_account_info(),
+        },
+        global_signer,
+    );
+    token::transfer(payout_ctx, payout)?;
+
+    emit!(ValuableSold {
+        player: ctx.accounts.player.key(),
+        item_id,
+        amount,
+        payout,
+    });
+
+    Ok(())
+}
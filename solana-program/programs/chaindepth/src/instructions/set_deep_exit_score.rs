@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::DeepExitScoreUpdated;
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::{roles, GlobalAccount};
+
+#[derive(Accounts)]
+pub struct SetDeepExitScore<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: decoded manually so a global created before `deep_exit_score_bps`
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetDeepExitScore>, score_bps: u16) -> Result<()> {
+    require!(
+        score_bps > 0 && score_bps <= GlobalAccount::MAX_DEEP_EXIT_SCORE_BPS,
+        ChainDepthError::InvalidScoreModifier
+    );
+
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let mut global: GlobalAccount = decode_padded(&global_info, global_space)?;
+    require!(
+        global.is_role_holder(roles::CONFIG_EDITOR, &ctx.accounts.admin.key()),
+        ChainDepthError::Unauthorized
+    );
+    require!(
+        !global.has_admin_set(),
+        ChainDepthError::AdminActionRequiresProposal
+    );
+
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+
+    global.deep_exit_score_bps = score_bps;
+    let mut data = global_info.try_borrow_mut_data()?;
+    global.try_serialize(&mut &mut data[..])?;
+
+    emit!(DeepExitScoreUpdated {
+        admin: ctx.accounts.admin.key(),
+        score_bps,
+    });
+
+    Ok(())
+}
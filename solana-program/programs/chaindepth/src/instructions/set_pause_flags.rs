@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::PauseFlagsUpdated;
+use crate::state::{pause_flags, roles, GlobalAccount};
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump,
+        constraint = global.is_role_holder(roles::PAUSER, &pauser.key()) @ ChainDepthError::Unauthorized
+    )]
+    pub global: Account<'info, GlobalAccount>,
+}
+
+pub fn handler(ctx: Context<SetPauseFlags>, paused_flags: u16) -> Result<()> {
+    require!(
+        paused_flags & !pause_flags::ALL == 0,
+        ChainDepthError::InvalidPauseFlags
+    );
+
+    // No timelock: pausing has to beat whoever is exploiting the bug.
+    let global = &mut ctx.accounts.global;
+    global.paused_flags = paused_flags;
+
+    emit!(PauseFlagsUpdated {
+        pauser: ctx.accounts.pauser.key(),
+        paused_flags,
+    });
+
+    Ok(())
+}
@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use crate::errors::ChainDepthError;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    session_instruction_bits, GlobalAccount, PlayerAccount, PlayerProfile, RoomPresence,
-    SessionAuthority,
+    session_instruction_bits, GlobalAccount, PlayerAccount, PlayerProfile, RoomAccount,
+    RoomPresence, SessionAuthority,
 };
 
 #[derive(Accounts)]
@@ -43,6 +43,7 @@ pub struct SetPlayerSkin<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::RoleHandoverProposed;
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::{roles, GlobalAccount};
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    /// Current holder of the role, or `admin` while no admin set is configured
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: decoded manually so a global created before the role fields
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetRole>, role: u8, new_holder: Pubkey) -> Result<()> {
+    require!(roles::is_valid(role), ChainDepthError::InvalidRole);
+
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let mut global: GlobalAccount = decode_padded(&global_info, global_space)?;
+    let authority = ctx.accounts.authority.key();
+    let current_holder = global.role_holder(role);
+    if authority != current_holder {
+        require!(global.admin == authority, ChainDepthError::Unauthorized);
+        require!(
+            !global.has_admin_set(),
+            ChainDepthError::AdminActionRequiresProposal
+        );
+    }
+
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+
+    // Only an offer: the role moves once `new_holder` signs `accept_role`.
+    // Offering it to the default key withdraws a pending offer.
+    global.pending_role_holders[usize::from(role)] = new_holder;
+    let mut data = global_info.try_borrow_mut_data()?;
+    global.try_serialize(&mut &mut data[..])?;
+
+    emit!(RoleHandoverProposed {
+        role,
+        current_holder,
+        proposed_holder: new_holder,
+        proposed_by: authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::SeasonGridScheduled;
+use crate::instructions::account_resize::{decode_padded, resize_with_rent};
+use crate::state::{roles, DungeonGrid, GlobalAccount};
+
+#[derive(Accounts)]
+pub struct SetSeasonGrid<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: decoded manually so a global created before the grid fields
+    /// existed can be reallocated here
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump,
+        owner = crate::ID
+    )]
+    pub global: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetSeasonGrid>,
+    width: u8,
+    height: u8,
+    start_x: i8,
+    start_y: i8,
+) -> Result<()> {
+    let next_grid = DungeonGrid {
+        width,
+        height,
+        start_x,
+        start_y,
+    };
+    require!(next_grid.is_valid(), ChainDepthError::InvalidDungeonGrid);
+
+    let global_info = ctx.accounts.global.to_account_info();
+    let global_space = 8 + GlobalAccount::INIT_SPACE;
+    let mut global: GlobalAccount = decode_padded(&global_info, global_space)?;
+    require!(
+        global.is_role_holder(roles::CONFIG_EDITOR, &ctx.accounts.admin.key()),
+        ChainDepthError::Unauthorized
+    );
+    require!(
+        !global.has_admin_set(),
+        ChainDepthError::AdminActionRequiresProposal
+    );
+
+    resize_with_rent(
+        &global_info,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        global_space,
+    )?;
+
+    // The running season keeps its grid: rooms already created were laid out on it.
+    global.next_grid = next_grid;
+    let mut data = global_info.try_borrow_mut_data()?;
+    global.try_serialize(&mut &mut data[..])?;
+
+    emit!(SeasonGridScheduled {
+        admin: ctx.accounts.admin.key(),
+        width,
+        height,
+        start_x,
+        start_y,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::ShopConfigUpdated;
+use crate::state::{roles, GlobalAccount, ShopConfig};
+
+#[derive(Accounts)]
+pub struct SetShopListing<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump,
+        constraint = global.is_role_holder(roles::CONFIG_EDITOR, &admin.key()) @ ChainDepthError::Unauthorized,
+        constraint = !global.has_admin_set() @ ChainDepthError::AdminActionRequiresProposal
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [ShopConfig::SEED_PREFIX],
+        bump = shop_config.bump
+    )]
+    pub shop_config: Account<'info, ShopConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetShopListing>,
+    item_id: u16,
+    durability: u16,
+    price: u64,
+    stock_per_season: u32,
+) -> Result<()> {
+    ctx.accounts
+        .shop_config
+        .upsert_listing(item_id, durability, price, stock_per_season)?;
+
+    emit!(ShopConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        item_id,
+        is_listing: true,
+        price,
+        stock_per_season,
+    });
+
+    Ok(())
+}
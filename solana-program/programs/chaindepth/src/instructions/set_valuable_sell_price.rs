@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::ShopConfigUpdated;
+use crate::state::{is_scored_loot_item, roles, GlobalAccount, ShopConfig};
+
+#[derive(Accounts)]
+pub struct SetValuableSellPrice<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump,
+        constraint = global.is_role_holder(roles::CONFIG_EDITOR, &admin.key()) @ ChainDepthError::Unauthorized,
+        constraint = !global.has_admin_set() @ ChainDepthError::AdminActionRequiresProposal
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [ShopConfig::SEED_PREFIX],
+        bump = shop_config.bump
+    )]
+    pub shop_config: Account<'info, ShopConfig>,
+}
+
+pub fn handler(ctx: Context<SetValuableSellPrice>, item_id: u16, price: u64) -> Result<()> {
+    require!(
+        is_scored_loot_item(item_id),
+        ChainDepthError::ShopItemNotSellable
+    );
+
+    ctx.accounts.shop_config.upsert_sell_price(item_id, price)?;
+
+    emit!(ShopConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        item_id,
+        is_listing: false,
+        price,
+        stock_per_season: 0,
+    });
+
+    Ok(())
+}
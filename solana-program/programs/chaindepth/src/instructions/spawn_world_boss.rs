@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::WorldBossSpawned;
+use crate::state::{pause_flags, GlobalAccount, SeasonHistoryAccount, WorldBossAccount};
+
+#[derive(Accounts)]
+pub struct SpawnWorldBoss<'info> {
+    /// Anyone can spawn the world boss once the season is deep enough; they pay the rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = WorldBossAccount::DISCRIMINATOR.len() + WorldBossAccount::INIT_SPACE,
+        seeds = [WorldBossAccount::SEED_PREFIX, &global.season_seed.to_le_bytes()],
+        bump
+    )]
+    pub world_boss: Account<'info, WorldBossAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SeasonHistoryAccount::DISCRIMINATOR.len() + SeasonHistoryAccount::INIT_SPACE,
+        seeds = [SeasonHistoryAccount::SEED_PREFIX, &global.season_seed.to_le_bytes()],
+        bump
+    )]
+    pub season_history: Account<'info, SeasonHistoryAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SpawnWorldBoss>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::BOSSES)?;
+
+    let global = &ctx.accounts.global;
+    let clock = Clock::get()?;
+    require!(
+        global.depth >= WorldBossAccount::spawn_depth(global.grid()),
+        ChainDepthError::WorldBossNotReady
+    );
+
+    let world_boss = &mut ctx.accounts.world_boss;
+    world_boss.season_seed = global.season_seed;
+    world_boss.boss_id = WorldBossAccount::BOSS_ID;
+    world_boss.max_hp = WorldBossAccount::MAX_HP;
+    world_boss.current_hp = WorldBossAccount::MAX_HP;
+    world_boss.last_update_slot = clock.slot;
+    world_boss.spawned_by = ctx.accounts.authority.key();
+    world_boss.spawned_slot = clock.slot;
+    world_boss.bump = ctx.bumps.world_boss;
+
+    let season_history = &mut ctx.accounts.season_history;
+    season_history.season_seed = global.season_seed;
+    season_history.world_boss_spawned_slot = clock.slot;
+    season_history.bump = ctx.bumps.season_history;
+
+    emit!(WorldBossSpawned {
+        season_seed: global.season_seed,
+        boss_id: world_boss.boss_id,
+        max_hp: world_boss.max_hp,
+        spawned_by: world_boss.spawned_by,
+    });
+
+    Ok(())
+}
@@ -5,8 +5,8 @@ use crate::events::BossTicked;
 use crate::instructions::join_boss_fight::{apply_boss_damage, resolve_player_boss_damage};
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    session_instruction_bits, BossFightAccount, GlobalAccount, InventoryAccount, PlayerAccount,
-    RoomAccount, RoomPresence, SessionAuthority, CENTER_BOSS,
+    pause_flags, session_instruction_bits, BossFightAccount, GlobalAccount, InventoryAccount,
+    PlayerAccount, RoomAccount, RoomPresence, SessionAuthority, CENTER_BOSS,
 };
 
 #[derive(Accounts)]
@@ -37,7 +37,8 @@ pub struct TickBossFight<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -50,6 +51,7 @@ pub struct TickBossFight<'info> {
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
             &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
             player.key().as_ref()
         ],
         bump = room_presence.bump
@@ -87,6 +89,8 @@ pub struct TickBossFight<'info> {
 }
 
 pub fn handler(ctx: Context<TickBossFight>) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::BOSSES)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -95,6 +99,7 @@ pub fn handler(ctx: Context<TickBossFight>) -> Result<()> {
         0,
     )?;
 
+    let grid = ctx.accounts.global.grid();
     let room = &mut ctx.accounts.room;
     let clock = Clock::get()?;
     ctx.accounts.player_account.require_in_dungeon()?;
@@ -102,16 +107,16 @@ pub fn handler(ctx: Context<TickBossFight>) -> Result<()> {
     require!(!room.boss_defeated, ChainDepthError::BossAlreadyDefeated);
     require!(room.boss_fighter_count > 0, ChainDepthError::NoActiveJob);
 
-    apply_boss_damage(room, clock.slot)?;
     resolve_player_boss_damage(
         room,
+        grid,
         &mut ctx.accounts.player_account,
         &mut ctx.accounts.room_presence,
         &mut ctx.accounts.boss_fight,
         &mut ctx.accounts.inventory,
-        ctx.accounts.player.key(),
         clock.slot,
     )?;
+    apply_boss_damage(room, clock.slot)?;
     ctx.accounts.player_account.mark_active(clock.slot);
 
     emit!(BossTicked {
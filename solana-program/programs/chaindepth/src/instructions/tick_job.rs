@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
-use crate::state::{GlobalAccount, RoomAccount};
+use crate::state::{pause_flags, GlobalAccount, RoomAccount};
 
 #[derive(Accounts)]
 #[instruction(direction: u8)]
@@ -22,7 +22,8 @@ pub struct TickJob<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[room.x as u8],
-            &[room.y as u8]
+            &[room.y as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
@@ -30,6 +31,8 @@ pub struct TickJob<'info> {
 }
 
 pub fn handler(ctx: Context<TickJob>, direction: u8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::JOBS)?;
+
     // Validate direction
     require!(
         RoomAccount::is_valid_direction(direction),
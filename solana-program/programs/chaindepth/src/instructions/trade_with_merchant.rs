@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::MerchantTraded;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    merchant_payment_amount, merchant_stock, pause_flags, session_instruction_bits, GlobalAccount,
+    InventoryAccount, MerchantReceipt, PlayerAccount, RoomAccount, SessionAuthority,
+    CENTER_MERCHANT, MERCHANT_STOCK_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct TradeWithMerchant<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Room with the merchant
+    #[account(
+        seeds = [
+            RoomAccount::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
+        ],
+        bump
+    )]
+    pub room: Account<'info, RoomAccount>,
+
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = inventory.bump,
+        constraint = inventory.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub inventory: Account<'info, InventoryAccount>,
+
+    /// Per-player purchase counts for this merchant
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MerchantReceipt::DISCRIMINATOR.len() + MerchantReceipt::INIT_SPACE,
+        seeds = [
+            MerchantReceipt::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump
+    )]
+    pub merchant_receipt: Account<'info, MerchantReceipt>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TradeWithMerchant>, offer_index: u8, payment_item_id: u16) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::TRADE_WITH_MERCHANT,
+        0,
+    )?;
+
+    let room = &ctx.accounts.room;
+    let player_account = &mut ctx.accounts.player_account;
+    let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
+    let receipt = &mut ctx.accounts.merchant_receipt;
+    let player_key = ctx.accounts.player.key();
+    let season_seed = ctx.accounts.global.season_seed;
+    player_account.require_in_dungeon()?;
+
+    require!(
+        room.center_type == CENTER_MERCHANT,
+        ChainDepthError::NoMerchant
+    );
+    require!(
+        player_account.is_at_room(room.x, room.y),
+        ChainDepthError::NotInRoom
+    );
+
+    let slot = usize::from(offer_index);
+    require!(
+        slot < MERCHANT_STOCK_SIZE,
+        ChainDepthError::InvalidMerchantOffer
+    );
+    let offer = merchant_stock(season_seed, room.x, room.y)[slot];
+
+    if receipt.player == Pubkey::default() {
+        receipt.player = player_key;
+        receipt.season_seed = season_seed;
+        receipt.room_x = room.x;
+        receipt.room_y = room.y;
+        receipt.purchased = [0; MERCHANT_STOCK_SIZE];
+        receipt.bump = ctx.bumps.merchant_receipt;
+    }
+    require!(
+        receipt.purchased[slot] < offer.limit_per_player,
+        ChainDepthError::MerchantLimitReached
+    );
+
+    let payment_amount = merchant_payment_amount(offer.price_score, payment_item_id)
+        .ok_or(ChainDepthError::InvalidMerchantPayment)?;
+    inventory.remove_item(payment_item_id, payment_amount)?;
+    inventory.add_item(offer.item_id, 1, offer.durability)?;
+
+    receipt.purchased[slot] += 1;
+    player_account.mark_active(Clock::get()?.slot);
+
+    emit!(MerchantTraded {
+        player: player_key,
+        room_x: room.x,
+        room_y: room.y,
+        item_id: offer.item_id,
+        durability: offer.durability,
+        payment_item_id,
+        payment_amount,
+    });
+
+    Ok(())
+}
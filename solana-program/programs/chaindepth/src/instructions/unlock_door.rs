@@ -4,9 +4,10 @@ use crate::errors::ChainDepthError;
 use crate::events::DoorUnlocked;
 use crate::instructions::session_auth::authorize_player_action;
 use crate::state::{
-    calculate_depth, enforce_special_room_topology, initialize_discovered_room, is_bone_room,
-    item_ids, session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount,
-    RoomAccount, SessionAuthority, LOCK_KIND_NONE, LOCK_KIND_SKELETON, WALL_LOCKED, WALL_OPEN,
+    enforce_special_room_topology, floor_depth, floor_season_seed, initialize_discovered_room,
+    is_bone_room, lock_requirement, pause_flags, session_instruction_bits, FloorConnectivity,
+    GlobalAccount, InventoryAccount, LockRequirement, PlayerAccount, RoomAccount, RoomLocation,
+    RoomPresence, SessionAuthority, LOCK_KIND_NONE, WALL_LOCKED, WALL_OPEN,
 };
 
 #[derive(Accounts)]
@@ -38,7 +39,8 @@ pub struct UnlockDoor<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[player_account.current_room_x as u8],
-            &[player_account.current_room_y as u8]
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor)
         ],
         bump
     )]
@@ -52,12 +54,27 @@ pub struct UnlockDoor<'info> {
             RoomAccount::SEED_PREFIX,
             &global.season_seed.to_le_bytes(),
             &[adjacent_x(room.x, direction) as u8],
-            &[adjacent_y(room.y, direction) as u8]
+            &[adjacent_y(room.y, direction) as u8],
+            RoomAccount::floor_seed(&room.floor)
         ],
         bump
     )]
     pub adjacent_room: Account<'info, RoomAccount>,
 
+    /// Connectivity repairs of this floor; the first discovery on the floor fills them in
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = FloorConnectivity::DISCRIMINATOR.len() + FloorConnectivity::INIT_SPACE,
+        seeds = [
+            FloorConnectivity::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[room.floor]
+        ],
+        bump
+    )]
+    pub floor_connectivity: Box<Account<'info, FloorConnectivity>>,
+
     #[account(
         mut,
         seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
@@ -66,6 +83,9 @@ pub struct UnlockDoor<'info> {
     )]
     pub inventory: Account<'info, InventoryAccount>,
 
+    /// Another player's presence in this room; required for cooperative locks
+    pub partner_presence: Option<Account<'info, RoomPresence>>,
+
     #[account(
         mut,
         seeds = [
@@ -81,6 +101,8 @@ pub struct UnlockDoor<'info> {
 }
 
 pub fn handler(ctx: Context<UnlockDoor>, direction: u8) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+
     authorize_player_action(
         &ctx.accounts.authority,
         &ctx.accounts.player,
@@ -110,31 +132,68 @@ pub fn handler(ctx: Context<UnlockDoor>, direction: u8) -> Result<()> {
     );
 
     let lock_kind = room.door_lock_kinds[direction_index];
-    let key_item_id = key_item_id_for_lock_kind(lock_kind)?;
-    ctx.accounts.inventory.remove_item(key_item_id, 1)?;
+    let requirement = lock_requirement_for_kind(lock_kind)?;
+    let partner = if requirement.needs_partner {
+        let partner_presence = ctx
+            .accounts
+            .partner_presence
+            .as_ref()
+            .ok_or(ChainDepthError::PartnerRequired)?;
+        require!(
+            partner_presence.player != player_key
+                && partner_presence.is_current
+                && partner_presence.season_seed == ctx.accounts.global.season_seed
+                && partner_presence.floor == room.floor
+                && partner_presence.room_x == room.x
+                && partner_presence.room_y == room.y,
+            ChainDepthError::PartnerRequired
+        );
+        partner_presence.player
+    } else {
+        Pubkey::default()
+    };
+    let key_item_id = requirement.key_item_id;
+    ctx.accounts
+        .inventory
+        .remove_item(key_item_id, requirement.keys_required)?;
 
     room.walls[direction_index] = WALL_OPEN;
     room.door_lock_kinds[direction_index] = LOCK_KIND_NONE;
 
     let opposite_direction = RoomAccount::opposite_direction(direction);
     let clock = Clock::get()?;
+    let grid = ctx.accounts.global.grid();
     let adjacent_room = &mut ctx.accounts.adjacent_room;
     if adjacent_room.season_seed == 0 {
+        let (adjacent_room_x, adjacent_room_y) =
+            (adjacent_x(room.x, direction), adjacent_y(room.y, direction));
+        let floor_connectivity = &mut ctx.accounts.floor_connectivity;
+        floor_connectivity.ensure_analyzed(
+            ctx.accounts.global.season_seed,
+            room.floor,
+            grid,
+            ctx.bumps.floor_connectivity,
+        );
         initialize_discovered_room(
             adjacent_room,
-            ctx.accounts.global.season_seed,
-            adjacent_x(room.x, direction),
-            adjacent_y(room.y, direction),
+            RoomLocation::new(
+                ctx.accounts.global.season_seed,
+                grid,
+                room.floor,
+                adjacent_room_x,
+                adjacent_room_y,
+            ),
             opposite_direction,
+            floor_connectivity.repaired_directions(grid, adjacent_room_x, adjacent_room_y),
             player_key,
             clock.slot,
             ctx.bumps.adjacent_room,
         );
     }
 
-    let adjacent_depth = calculate_depth(adjacent_room.x, adjacent_room.y);
+    let adjacent_depth = floor_depth(grid, room.floor, adjacent_room.x, adjacent_room.y);
     if !is_bone_room(
-        ctx.accounts.global.season_seed,
+        floor_season_seed(ctx.accounts.global.season_seed, room.floor),
         adjacent_room.x,
         adjacent_room.y,
         adjacent_depth,
@@ -142,7 +201,7 @@ pub fn handler(ctx: Context<UnlockDoor>, direction: u8) -> Result<()> {
         adjacent_room.walls[opposite_direction as usize] = WALL_OPEN;
         adjacent_room.door_lock_kinds[opposite_direction as usize] = LOCK_KIND_NONE;
     }
-    enforce_special_room_topology(adjacent_room);
+    enforce_special_room_topology(adjacent_room, grid);
 
     let new_depth = adjacent_depth;
     if new_depth > ctx.accounts.global.depth {
@@ -155,6 +214,8 @@ pub fn handler(ctx: Context<UnlockDoor>, direction: u8) -> Result<()> {
         direction,
         player: player_key,
         key_item_id: key_item_id,
+        keys_used: requirement.keys_required,
+        partner,
     });
 
     Ok(())
@@ -176,10 +237,9 @@ fn adjacent_y(y: i8, direction: u8) -> i8 {
     }
 }
 
-fn key_item_id_for_lock_kind(lock_kind: u8) -> Result<u16> {
+fn lock_requirement_for_kind(lock_kind: u8) -> Result<LockRequirement> {
     match lock_kind {
-        LOCK_KIND_SKELETON => Ok(item_ids::SKELETON_KEY),
         LOCK_KIND_NONE => err!(ChainDepthError::WallNotLocked),
-        _ => err!(ChainDepthError::InvalidLockKind),
+        _ => lock_requirement(lock_kind).ok_or(error!(ChainDepthError::InvalidLockKind)),
     }
 }
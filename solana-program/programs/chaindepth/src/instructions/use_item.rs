@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::{ForcedKeyChestRevealed, ItemUsed, PlayerMoved};
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    calculate_depth, floor_season_seed, item_ids, pause_flags, select_forced_key_chest_coords,
+    session_instruction_bits, GlobalAccount, InventoryAccount, PlayerAccount, RoomAccount,
+    RoomPresence, SessionAuthority, FLOOR_DEPTH_STRIDE, FORCED_KEY_CHEST_MIN_DEPTH,
+};
+
+#[derive(Accounts)]
+pub struct UseItem<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, GlobalAccount>>,
+
+    #[account(
+        mut,
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Box<Account<'info, PlayerAccount>>,
+
+    #[account(
+        mut,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = inventory.bump,
+        constraint = inventory.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub inventory: Box<Account<'info, InventoryAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[player_account.current_room_x as u8],
+            &[player_account.current_room_y as u8],
+            RoomAccount::floor_seed(&player_account.current_floor),
+            player.key().as_ref()
+        ],
+        bump = current_presence.bump
+    )]
+    pub current_presence: Box<Account<'info, RoomPresence>>,
+
+    /// Presence at the start room; only needed for items that teleport there
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoomPresence::DISCRIMINATOR.len() + RoomPresence::INIT_SPACE,
+        seeds = [
+            RoomPresence::SEED_PREFIX,
+            &global.season_seed.to_le_bytes(),
+            &[global.grid().start_x as u8],
+            &[global.grid().start_y as u8],
+            player.key().as_ref()
+        ],
+        bump
+    )]
+    pub start_presence: Option<Box<Account<'info, RoomPresence>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UseItem>, item_id: u16) -> Result<()> {
+    // Items can teleport the player or consume loot, so either pause stops them.
+    ctx.accounts.global.require_not_paused(pause_flags::MOVEMENT)?;
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::USE_ITEM,
+        0,
+    )?;
+
+    let player_key = ctx.accounts.player.key();
+    let grid = ctx.accounts.global.grid();
+    let clock = Clock::get()?;
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.require_in_dungeon()?;
+    require!(player_account.current_hp > 0, ChainDepthError::PlayerDead);
+
+    match item_id {
+        item_ids::RUSTED_COMPASS => {
+            let floor = player_account.current_floor;
+            let generation_seed = floor_season_seed(ctx.accounts.global.season_seed, floor);
+            let ring = calculate_depth(
+                grid,
+                player_account.current_room_x,
+                player_account.current_room_y,
+            )
+            .max(FORCED_KEY_CHEST_MIN_DEPTH);
+            let depth = u32::from(floor) * FLOOR_DEPTH_STRIDE + ring;
+            let (room_x, room_y) = select_forced_key_chest_coords(generation_seed, grid, depth)
+                .ok_or(ChainDepthError::ItemNotUsable)?;
+
+            emit!(ForcedKeyChestRevealed {
+                player: player_key,
+                floor,
+                depth,
+                room_x,
+                room_y,
+            });
+        }
+        item_ids::MYSTIC_ORB => {
+            require!(
+                player_account.active_jobs.is_empty(),
+                ChainDepthError::ItemBlockedByActiveJobs
+            );
+            require!(
+                ctx.accounts.current_presence.activity != RoomPresence::ACTIVITY_BOSS_FIGHT,
+                ChainDepthError::AlreadyFightingBoss
+            );
+
+            let from_x = player_account.current_room_x;
+            let from_y = player_account.current_room_y;
+            require!(
+                !player_account.is_at_floor_room(0, grid.start_x, grid.start_y),
+                ChainDepthError::ItemNotUsable
+            );
+
+            let start_presence = ctx
+                .accounts
+                .start_presence
+                .as_mut()
+                .ok_or(ChainDepthError::MissingItemAccounts)?;
+            if start_presence.player == Pubkey::default() {
+                start_presence.player = player_key;
+                start_presence.season_seed = ctx.accounts.global.season_seed;
+                start_presence.room_x = grid.start_x;
+                start_presence.room_y = grid.start_y;
+                start_presence.bump = ctx.bumps.start_presence.unwrap_or_default();
+            }
+            start_presence.skin_id = ctx.accounts.current_presence.skin_id;
+            start_presence.equipped_item_id = player_account.equipped_item_id;
+            start_presence.is_current = true;
+            start_presence.set_idle();
+
+            player_account.current_room_x = grid.start_x;
+            player_account.current_room_y = grid.start_y;
+            player_account.current_floor = 0;
+
+            ctx.accounts
+                .current_presence
+                .close(ctx.accounts.authority.to_account_info())?;
+
+            emit!(PlayerMoved {
+                player: player_key,
+                from_x,
+                from_y,
+                to_x: grid.start_x,
+                to_y: grid.start_y,
+            });
+        }
+        _ => return err!(ChainDepthError::ItemNotUsable),
+    }
+
+    ctx.accounts.inventory.remove_item(item_id, 1)?;
+    player_account.mark_active(clock.slot);
+
+    emit!(ItemUsed {
+        player: player_key,
+        item_id,
+    });
+
+    Ok(())
+}
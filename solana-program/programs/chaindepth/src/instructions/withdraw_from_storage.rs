@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ChainDepthError;
+use crate::events::StorageItemWithdrawn;
+use crate::instructions::session_auth::authorize_player_action;
+use crate::state::{
+    pause_flags, score_value_for_item, session_instruction_bits, GlobalAccount, InventoryAccount,
+    PlayerAccount, SessionAuthority, StorageAccount,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFromStorage<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: wallet owner whose gameplay state is being modified
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED_PREFIX],
+        bump = global.bump
+    )]
+    pub global: Account<'info, GlobalAccount>,
+
+    #[account(
+        seeds = [PlayerAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = InventoryAccount::DISCRIMINATOR.len() + InventoryAccount::INIT_SPACE,
+        seeds = [InventoryAccount::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub inventory: Account<'info, InventoryAccount>,
+
+    #[account(
+        mut,
+        seeds = [StorageAccount::SEED_PREFIX, player.key().as_ref()],
+        bump = storage.bump,
+        constraint = storage.owner == player.key() @ ChainDepthError::Unauthorized
+    )]
+    pub storage: Account<'info, StorageAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            SessionAuthority::SEED_PREFIX,
+            player.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawFromStorage>,
+    item_id: u16,
+    amount: u32,
+    durability: u16,
+) -> Result<()> {
+    ctx.accounts.global.require_not_paused(pause_flags::LOOT)?;
+
+    authorize_player_action(
+        &ctx.accounts.authority,
+        &ctx.accounts.player,
+        ctx.accounts.session_authority.as_mut(),
+        session_instruction_bits::WITHDRAW_FROM_STORAGE,
+        0,
+    )?;
+
+    ctx.accounts.player_account.require_out_of_dungeon()?;
+
+    // Banked valuables were already scored on extraction; carrying them back
+    // out would let the same stack be scored again on the next exit.
+    require!(
+        score_value_for_item(item_id) == 0,
+        ChainDepthError::ItemNotWithdrawable
+    );
+
+    let player_key = ctx.accounts.player.key();
+    let inventory = &mut ctx.accounts.inventory;
+    let inventory_len = inventory.to_account_info().data_len();
+    inventory.reset_stale_bonus_slots(inventory_len);
+    if inventory.owner == Pubkey::default() {
+        inventory.owner = player_key;
+        inventory.items = Vec::new();
+        inventory.bump = ctx.bumps.inventory;
+    }
+    require!(inventory.owner == player_key, ChainDepthError::Unauthorized);
+
+    ctx.accounts
+        .storage
+        .remove_stack(item_id, durability, amount)?;
+    inventory.add_item(item_id, amount, durability)?;
+
+    emit!(StorageItemWithdrawn {
+        player: player_key,
+        item_id,
+        amount,
+        durability,
+    });
+
+    Ok(())
+}
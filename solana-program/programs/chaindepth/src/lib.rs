@@ -6,6 +6,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::AdminAction;
 
 declare_id!("3Ctc2FgnNHQtGAcZftMS4ykLhJYjLzBD3hELKy55DnKo");
 
@@ -53,6 +54,11 @@ pub mod chaindepth {
         instructions::move_player::init_player_handler(ctx)
     }
 
+    /// Upgrade caller's player account to the current data layout (caller pays extra rent).
+    pub fn migrate_player_account(ctx: Context<MigratePlayerAccount>) -> Result<()> {
+        instructions::migrate_player_account::handler(ctx)
+    }
+
     /// Begin a delegated session key for gameplay actions.
     pub fn begin_session(
         ctx: Context<BeginSession>,
@@ -90,7 +96,8 @@ pub mod chaindepth {
         instructions::unlock_door::handler(ctx, direction)
     }
 
-    /// Extract at entrance stairs, bank carried valuables into storage, and award run score.
+    /// Extract at entrance stairs or a deep extraction room, bank carried valuables into
+    /// storage, and award run score.
     pub fn exit_dungeon(ctx: Context<ExitDungeon>) -> Result<()> {
         instructions::exit_dungeon::handler(ctx)
     }
@@ -239,6 +246,223 @@ pub mod chaindepth {
         instructions::add_inventory_item::handler(ctx, item_id, amount, durability)
     }
 
+    /// Move a stack from long-term storage into the run inventory (out of dungeon only).
+    pub fn withdraw_from_storage(
+        ctx: Context<WithdrawFromStorage>,
+        item_id: u16,
+        amount: u32,
+        durability: u16,
+    ) -> Result<()> {
+        instructions::withdraw_from_storage::handler(ctx, item_id, amount, durability)
+    }
+
+    /// Move a stack from the run inventory into long-term storage (out of dungeon only).
+    pub fn deposit_to_storage(
+        ctx: Context<DepositToStorage>,
+        item_id: u16,
+        amount: u32,
+        durability: u16,
+    ) -> Result<()> {
+        instructions::deposit_to_storage::handler(ctx, item_id, amount, durability)
+    }
+
+    /// Create the shop config or change whether shop payments are burned (admin only).
+    pub fn configure_shop(ctx: Context<ConfigureShop>, burn_payments: bool) -> Result<()> {
+        instructions::configure_shop::handler(ctx, burn_payments)
+    }
+
+    /// Add, update, or remove (price 0) a shop listing (admin only).
+    pub fn set_shop_listing(
+        ctx: Context<SetShopListing>,
+        item_id: u16,
+        durability: u16,
+        price: u64,
+        stock_per_season: u32,
+    ) -> Result<()> {
+        instructions::set_shop_listing::handler(ctx, item_id, durability, price, stock_per_season)
+    }
+
+    /// Set the SKR buy-back price for a valuable, 0 to stop buying it (admin only).
+    pub fn set_valuable_sell_price(
+        ctx: Context<SetValuableSellPrice>,
+        item_id: u16,
+        price: u64,
+    ) -> Result<()> {
+        instructions::set_valuable_sell_price::handler(ctx, item_id, price)
+    }
+
+    /// Buy gear from the surface shop with SKR (out of dungeon only).
+    pub fn buy_item(ctx: Context<BuyItem>, item_id: u16, amount: u32) -> Result<()> {
+        instructions::buy_item::handler(ctx, item_id, amount)
+    }
+
+    /// Sell banked valuables from storage for SKR (out of dungeon only).
+    pub fn sell_valuable(ctx: Context<SellValuable>, item_id: u16, amount: u32) -> Result<()> {
+        instructions::sell_valuable::handler(ctx, item_id, amount)
+    }
+
+    /// Buy extra inventory slots (level-gated, paid in SKR; player pays the extra rent).
+    pub fn expand_inventory(ctx: Context<ExpandInventory>) -> Result<()> {
+        instructions::expand_inventory::handler(ctx)
+    }
+
+    /// Grow a legacy inventory to the current layout (caller pays extra rent).
+    pub fn migrate_inventory(ctx: Context<MigrateInventory>) -> Result<()> {
+        instructions::migrate_inventory::handler(ctx)
+    }
+
+    /// Grow a global account created before the latest fields to the current layout
+    /// (anyone, caller pays extra rent). Run once right after upgrading the program.
+    pub fn migrate_global(ctx: Context<MigrateGlobal>) -> Result<()> {
+        instructions::migrate_global::handler(ctx)
+    }
+
+    /// Buy extra storage slots (level-gated, paid in SKR; player pays the extra rent).
+    pub fn expand_storage(ctx: Context<ExpandStorage>) -> Result<()> {
+        instructions::expand_storage::handler(ctx)
+    }
+
+    /// Consume a usable item (Rusted Compass reveals the key chest, Mystic Orb returns to start).
+    pub fn use_item(ctx: Context<UseItem>, item_id: u16) -> Result<()> {
+        instructions::use_item::handler(ctx, item_id)
+    }
+
+    /// Export one unit of a rare item as a Token-2022 NFT (out of dungeon only).
+    pub fn export_item(ctx: Context<ExportItem>, item_id: u16, durability: u16) -> Result<()> {
+        instructions::export_item::handler(ctx, item_id, durability)
+    }
+
+    /// Burn an exported item NFT and restore the item in-game (out of dungeon only).
+    pub fn import_item(ctx: Context<ImportItem>) -> Result<()> {
+        instructions::import_item::handler(ctx)
+    }
+
+    /// Disarm a trap in an adjacent room by spending a Trap Kit.
+    pub fn disarm_trap(ctx: Context<DisarmTrap>, direction: u8) -> Result<()> {
+        instructions::disarm_trap::handler(ctx, direction)
+    }
+
+    /// Heal to full at a shrine, once per player per shrine (tribute shrines take a valuable).
+    pub fn pray_at_shrine(ctx: Context<PrayAtShrine>, tribute_item_id: u16) -> Result<()> {
+        instructions::pray_at_shrine::handler(ctx, tribute_item_id)
+    }
+
+    /// Buy one unit of a merchant offer, paying with a single kind of carried valuable.
+    pub fn trade_with_merchant(
+        ctx: Context<TradeWithMerchant>,
+        offer_index: u8,
+        payment_item_id: u16,
+    ) -> Result<()> {
+        instructions::trade_with_merchant::handler(ctx, offer_index, payment_item_id)
+    }
+
+    /// Take the stairs-down room to the start room of the floor below.
+    pub fn descend(ctx: Context<Descend>) -> Result<()> {
+        instructions::descend::handler(ctx)
+    }
+
+    /// Take the stairs in a lower floor's start room back up to the stairs-down room above.
+    pub fn ascend(ctx: Context<Ascend>) -> Result<()> {
+        instructions::ascend::handler(ctx)
+    }
+
+    /// Settle boss hits on any fighter (permissionless), so idle fighters take damage
+    /// and stop adding DPS once their HP runs out.
+    pub fn crank_boss_fight(ctx: Context<CrankBossFight>) -> Result<()> {
+        instructions::crank_boss_fight::handler(ctx)
+    }
+
+    /// Spawn this season's world boss once exploration reaches the outermost ring (anyone).
+    pub fn spawn_world_boss(ctx: Context<SpawnWorldBoss>) -> Result<()> {
+        instructions::spawn_world_boss::handler(ctx)
+    }
+
+    /// Join the world boss fight from a first-floor room on the outermost ring.
+    pub fn join_world_boss(ctx: Context<JoinWorldBoss>) -> Result<()> {
+        instructions::join_world_boss::handler(ctx)
+    }
+
+    /// Leave the world boss fight, banking the damage dealt so far.
+    pub fn leave_world_boss(ctx: Context<LeaveWorldBoss>) -> Result<()> {
+        instructions::leave_world_boss::handler(ctx)
+    }
+
+    /// Claim a damage-weighted share of the world boss prize after it dies.
+    pub fn claim_world_boss_reward(ctx: Context<ClaimWorldBossReward>) -> Result<()> {
+        instructions::claim_world_boss_reward::handler(ctx)
+    }
+
+    /// Claim SKR royalties earned from other players' activity in rooms you discovered.
+    /// The first call opens the royalty account; royalties only accrue once it exists.
+    pub fn claim_discovery_royalties(ctx: Context<ClaimDiscoveryRoyalties>) -> Result<()> {
+        instructions::claim_discovery_royalties::handler(ctx)
+    }
+
+    /// Propose a season reset or config change. Once an admin set is configured these
+    /// only run through `execute_admin_action`; until then `admin` is a 1-of-1 set.
+    pub fn propose_admin_action(
+        ctx: Context<ProposeAdminAction>,
+        action_id: u64,
+        action: AdminAction,
+    ) -> Result<()> {
+        instructions::propose_admin_action::handler(ctx, action_id, action)
+    }
+
+    /// Approve a pending admin action (admin set members only).
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        instructions::approve_admin_action::handler(ctx)
+    }
+
+    /// Cancel a pending admin action (any admin, or its proposer).
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        instructions::cancel_admin_action::handler(ctx)
+    }
+
+    /// Run a pending admin action once its timelock passed and it has enough approvals.
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        instructions::execute_admin_action::handler(ctx)
+    }
+
+    /// Switch subsystems off or back on in an emergency (pauser only).
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused_flags: u16) -> Result<()> {
+        instructions::set_pause_flags::handler(ctx, paused_flags)
+    }
+
+    /// Offer a role to a new key (current holder, or admin without an admin set).
+    /// The role only moves once the new key calls `accept_role`.
+    pub fn set_role(ctx: Context<SetRole>, role: u8, new_holder: Pubkey) -> Result<()> {
+        instructions::set_role::handler(ctx, role, new_holder)
+    }
+
+    /// Take over a role offered by `set_role`.
+    pub fn accept_role(ctx: Context<AcceptRole>, role: u8) -> Result<()> {
+        instructions::accept_role::handler(ctx, role)
+    }
+
+    /// Add SOL to the treasury that reimburses new rooms' rent (anyone).
+    /// Also reallocates a global account created before the treasury fields existed.
+    pub fn fund_treasury(ctx: Context<FundTreasury>, amount: u64) -> Result<()> {
+        instructions::fund_treasury::handler(ctx, amount)
+    }
+
+    /// Set the run score multiplier for deep extraction-stair exits (admin only).
+    /// Also reallocates a global account created before the field existed.
+    pub fn set_deep_exit_score(ctx: Context<SetDeepExitScore>, score_bps: u16) -> Result<()> {
+        instructions::set_deep_exit_score::handler(ctx, score_bps)
+    }
+
+    /// Choose the room grid size and start room for the next season (admin only).
+    /// Also reallocates a global account created before the grid fields existed.
+    pub fn set_season_grid(
+        ctx: Context<SetSeasonGrid>,
+        width: u8,
+        height: u8,
+        start_x: i8,
+        start_y: i8,
+    ) -> Result<()> {
+        instructions::set_season_grid::handler(ctx, width, height, start_x, start_y)
+    }
+
     /// Remove items from player's inventory (e.g. spending items)
     pub fn remove_inventory_item(
         ctx: Context<RemoveInventoryItem>,
@@ -4,12 +4,18 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct BossFightAccount {
     pub player: Pubkey,
+    /// Room account, or the `WorldBossAccount` for world boss fights
     pub room: Pubkey,
     pub dps: u64,
     pub joined_slot: u64,
+    /// Room bosses: last slot the boss hit this player.
+    /// World boss: last slot this player's damage was credited.
     pub last_damage_slot: u64,
     pub is_active: bool,
     pub bump: u8,
+    /// World boss damage credited to this player over all their fights
+    pub damage_dealt: u64,
+    pub reward_claimed: bool,
 }
 
 impl BossFightAccount {
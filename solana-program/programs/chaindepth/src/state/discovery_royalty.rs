@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+/// What earned a discoverer royalty, reported in `DiscoveryRoyaltyAccrued`
+pub mod royalty_sources {
+    pub const JOB_COMPLETED: u8 = 0;
+    pub const CHEST_LOOTED: u8 = 1;
+    pub const BOSS_LOOTED: u8 = 2;
+}
+
+/// SKR a player earns when others complete jobs, loot chests or kill bosses
+/// in rooms they discovered. Accrued as a ledger and paid from the prize pool on claim.
+/// PDA seeds: ["discovery_royalty", discoverer]
+#[account]
+#[derive(InitSpace)]
+pub struct DiscoveryRoyaltyAccount {
+    pub owner: Pubkey,
+
+    /// Season `season_accrued` counts toward
+    pub season_seed: u64,
+
+    /// Royalties accrued in `season_seed`, capped at `SEASON_CAP`
+    pub season_accrued: u64,
+
+    /// Accrued and not yet claimed; survives season resets
+    pub pending: u64,
+
+    pub total_claimed: u64,
+
+    pub bump: u8,
+}
+
+impl DiscoveryRoyaltyAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"discovery_royalty";
+
+    pub const JOB_COMPLETED_ROYALTY: u64 = 1_000_000; // 0.001 * 10^9
+    pub const CHEST_LOOTED_ROYALTY: u64 = 500_000; // 0.0005 * 10^9
+    pub const BOSS_LOOTED_ROYALTY: u64 = 2_000_000; // 0.002 * 10^9
+
+    /// Most a single discoverer can accrue in one season.
+    pub const SEASON_CAP: u64 = 100_000_000; // 0.1 * 10^9
+
+    /// Fill in a freshly created account; a no-op once it has an owner.
+    pub fn open(&mut self, owner: Pubkey, season_seed: u64, bump: u8) {
+        if self.owner != Pubkey::default() {
+            return;
+        }
+        self.owner = owner;
+        self.season_seed = season_seed;
+        self.bump = bump;
+    }
+
+    /// Add a royalty for `season_seed`, clipped to what is left of the season cap.
+    /// Returns the amount actually accrued.
+    pub fn accrue(&mut self, season_seed: u64, amount: u64) -> u64 {
+        if self.season_seed != season_seed {
+            self.season_seed = season_seed;
+            self.season_accrued = 0;
+        }
+
+        let accrued = amount.min(Self::SEASON_CAP.saturating_sub(self.season_accrued));
+        self.season_accrued = self.season_accrued.saturating_add(accrued);
+        self.pending = self.pending.saturating_add(accrued);
+        accrued
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accrual_caps_per_season_and_resets_next_season() {
+        let mut royalty = DiscoveryRoyaltyAccount {
+            owner: Pubkey::default(),
+            season_seed: 1,
+            season_accrued: 0,
+            pending: 0,
+            total_claimed: 0,
+            bump: 0,
+        };
+
+        let cap = DiscoveryRoyaltyAccount::SEASON_CAP;
+        assert_eq!(royalty.accrue(1, cap - 1), cap - 1);
+        assert_eq!(royalty.accrue(1, 5), 1);
+        assert_eq!(royalty.accrue(1, 5), 0);
+        assert_eq!(royalty.pending, cap);
+
+        // A new season reopens the cap; unclaimed royalties carry over.
+        assert_eq!(royalty.accrue(2, 5), 5);
+        assert_eq!(royalty.season_accrued, 5);
+        assert_eq!(royalty.pending, cap + 5);
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use super::item_ids;
+
+/// Symbol carried by every exported item NFT
+pub const EXPORTED_ITEM_SYMBOL: &str = "CDITEM";
+
+/// Metadata keys written into the Token-2022 metadata extension
+pub const EXPORTED_ITEM_ID_KEY: &str = "item_id";
+pub const EXPORTED_ITEM_DURABILITY_KEY: &str = "durability";
+
+/// Record of an item that left the game as an NFT.
+/// Only this program can create it, so import trusts it over the mint metadata.
+/// PDA seeds: ["exported_item", mint]
+#[account]
+#[derive(InitSpace)]
+pub struct ExportedItem {
+    /// Token-2022 mint of the NFT (supply fixed at 1)
+    pub mint: Pubkey,
+    pub item_id: u16,
+    pub durability: u16,
+    /// Wallet that exported the item
+    pub exporter: Pubkey,
+    pub exported_slot: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ExportedItem {
+    pub const SEED_PREFIX: &'static [u8] = b"exported_item";
+}
+
+/// Units a wallet received from `add_inventory_item` rather than play.
+/// Granted items can be traded or spent into other items, so any grant bars the
+/// wallet from `export_item` instead of tracking which units they became.
+/// PDA seeds: ["granted_items", player]
+#[account]
+#[derive(InitSpace)]
+pub struct GrantedItems {
+    pub owner: Pubkey,
+    pub granted_units: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GrantedItems {
+    pub const SEED_PREFIX: &'static [u8] = b"granted_items";
+}
+
+/// Display name for exportable items, `None` if the item cannot leave the game.
+pub fn exported_item_name(item_id: u16) -> Option<&'static str> {
+    match item_id {
+        item_ids::DIAMOND_SWORD => Some("Diamond Sword"),
+        item_ids::NOKIA_3310 => Some("Nokia 3310"),
+        item_ids::ANCIENT_CROWN => Some("Ancient Crown"),
+        item_ids::DRAGON_SCALE => Some("Dragon Scale"),
+        item_ids::PHOENIX_FEATHER => Some("Phoenix Feather"),
+        item_ids::VOID_SHARD => Some("Void Shard"),
+        _ => None,
+    }
+}
+
+pub fn is_exportable_item(item_id: u16) -> bool {
+    exported_item_name(item_id).is_some()
+}
+
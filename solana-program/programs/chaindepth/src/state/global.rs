@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use super::FLOOR_DEPTH_STRIDE;
+use crate::errors::ChainDepthError;
+
 /// Global game state - one per season
 /// PDA seeds: ["global"]
 #[account]
@@ -18,7 +21,7 @@ pub struct GlobalAccount {
     /// Prize pool token account (ATA owned by this PDA)
     pub prize_pool: Pubkey,
 
-    /// Admin pubkey authorized to reset seasons
+    /// Admin pubkey; also holds every role in `role_holders` that is left unset
     pub admin: Pubkey,
 
     /// Slot when this season ends (for weekly resets)
@@ -29,6 +32,100 @@ pub struct GlobalAccount {
 
     /// PDA bump seed
     pub bump: u8,
+
+    // New fields are appended here; accounts created before them are reallocated by
+    // the admin setter that first writes them and read as zero until then.
+
+    /// Score multiplier in basis points for extracting at a deep extraction-stair room
+    /// instead of the entrance (0 = use `DEFAULT_DEEP_EXIT_SCORE_BPS`).
+    pub deep_exit_score_bps: u16,
+
+    /// Room grid of the current season (all zero = `DungeonGrid::LEGACY`).
+    pub grid: DungeonGrid,
+
+    /// Room grid the next season reset switches to (all zero = `DungeonGrid::LEGACY`).
+    pub next_grid: DungeonGrid,
+
+    /// Admins who propose, approve and cancel `PendingAdminAction`s; only the first
+    /// `admin_count` entries are used. Empty until configured, which leaves `admin` acting alone.
+    pub admin_set: [Pubkey; MAX_ADMINS],
+    pub admin_count: u8,
+
+    /// Approvals from `admin_set` a pending action needs before it can execute.
+    pub admin_threshold: u8,
+
+    /// Slots between proposing an admin action and executing it.
+    pub admin_timelock_slots: u64,
+
+    /// Subsystems switched off for emergencies, see `pause_flags`.
+    pub paused_flags: u16,
+
+    /// Keys holding each of `roles`; default = `admin` holds the role.
+    pub role_holders: [Pubkey; roles::COUNT],
+
+    /// Keys a role has been offered to, waiting for `accept_role`; default = none.
+    pub pending_role_holders: [Pubkey; roles::COUNT],
+
+    /// Lamports paid out of this account toward new rooms' rent, all seasons.
+    pub total_rent_reimbursed: u64,
+
+    /// Rooms whose rent this account covered, all seasons.
+    pub rooms_funded: u64,
+
+    /// Lamports reimbursed this season; cleared by season resets.
+    pub season_rent_reimbursed: u64,
+
+    /// Most rent reimbursed per season (0 = only the spare balance limits it).
+    pub season_rent_budget: u64,
+
+    /// Lamports added through `fund_treasury`, all seasons.
+    pub total_treasury_funded: u64,
+}
+
+/// Indexes into `GlobalAccount::role_holders`.
+pub mod roles {
+    /// Resets seasons (`reset_season`, and `force_reset_season` without an admin set)
+    pub const SEASON_OPERATOR: u8 = 0;
+    /// Owns the dev treasury token account that receives duel tax
+    pub const TREASURER: u8 = 1;
+    /// Signs `set_pause_flags`
+    pub const PAUSER: u8 = 2;
+    /// Edits the shop, sell prices, and global config without an admin set
+    pub const CONFIG_EDITOR: u8 = 3;
+
+    pub const COUNT: usize = 4;
+
+    pub fn is_valid(role: u8) -> bool {
+        usize::from(role) < COUNT
+    }
+}
+
+/// Bits of `GlobalAccount::paused_flags`. Withdrawal paths (abandoning jobs, claiming
+/// job rewards, leaving boss fights, declining or expiring duels, ending sessions)
+/// ignore them so nobody's stake is locked in by a pause.
+pub mod pause_flags {
+    pub const JOBS: u16 = 1 << 0;
+    pub const MOVEMENT: u16 = 1 << 1;
+    pub const LOOT: u16 = 1 << 2;
+    pub const BOSSES: u16 = 1 << 3;
+    pub const DUELS: u16 = 1 << 4;
+    pub const SESSIONS: u16 = 1 << 5;
+    pub const EXTRACTION: u16 = 1 << 6;
+
+    pub const ALL: u16 = JOBS | MOVEMENT | LOOT | BOSSES | DUELS | SESSIONS | EXTRACTION;
+}
+
+/// Largest multisig admin set `GlobalAccount` can hold.
+pub const MAX_ADMINS: usize = 5;
+
+/// Room grid of one season. Coordinates run from `GlobalAccount::MIN_COORD` to
+/// `width - 1` / `height - 1`, and each coordinate is one PDA seed byte.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct DungeonGrid {
+    pub width: u8,
+    pub height: u8,
+    pub start_x: i8,
+    pub start_y: i8,
 }
 
 impl GlobalAccount {
@@ -39,11 +136,304 @@ impl GlobalAccount {
     /// At 400ms per slot: 604800 / 0.4 = 1,512,000 slots
     pub const SEASON_DURATION_SLOTS: u64 = 1_512_000;
 
-    /// Starting room coordinates (center of 20x20 grid)
-    pub const START_X: i8 = 10;
-    pub const START_Y: i8 = 10;
-
-    /// Grid boundaries
+    /// Lowest room coordinate on both axes; the upper bounds come from the season grid.
     pub const MIN_COORD: i8 = 0;
-    pub const MAX_COORD: i8 = 19;
+
+    /// Deepest floor index; every floor is its own grid with the same bounds.
+    pub const MAX_FLOOR: u8 = 1;
+
+    /// Deep exits keep 90% of the run score by default: the shortcut home costs a little.
+    pub const DEFAULT_DEEP_EXIT_SCORE_BPS: u16 = 9_000;
+    pub const MAX_DEEP_EXIT_SCORE_BPS: u16 = 20_000;
+    pub const SCORE_BPS_DENOMINATOR: u64 = 10_000;
+
+    /// Rooms of rent left in the treasury below which reimbursements raise `TreasuryLow`.
+    pub const LOW_TREASURY_ROOMS: u64 = 25;
+
+    /// Grid of the current season.
+    pub fn grid(&self) -> DungeonGrid {
+        self.grid.or_legacy()
+    }
+
+    /// Grid the next season reset will use.
+    pub fn next_grid(&self) -> DungeonGrid {
+        self.next_grid.or_legacy()
+    }
+
+    pub fn deep_exit_score_bps(&self) -> u16 {
+        if self.deep_exit_score_bps == 0 {
+            Self::DEFAULT_DEEP_EXIT_SCORE_BPS
+        } else {
+            self.deep_exit_score_bps
+        }
+    }
+
+    /// Whether admin operations go through `PendingAdminAction` instead of `admin` alone.
+    pub fn has_admin_set(&self) -> bool {
+        self.admin_count > 0
+    }
+
+    /// Whether `key` may propose, approve or cancel admin actions.
+    pub fn is_admin_signer(&self, key: &Pubkey) -> bool {
+        if self.has_admin_set() {
+            self.admin_set[..usize::from(self.admin_count)].contains(key)
+        } else {
+            self.admin == *key
+        }
+    }
+
+    /// Approvals an action needs; `admin` alone counts as a 1-of-1 set until one is configured.
+    pub fn admin_threshold(&self) -> u8 {
+        if self.has_admin_set() {
+            self.admin_threshold
+        } else {
+            1
+        }
+    }
+
+    pub fn admin_timelock_slots(&self) -> u64 {
+        if self.has_admin_set() {
+            self.admin_timelock_slots
+        } else {
+            0
+        }
+    }
+
+    /// Approvals that still come from the current admin set.
+    pub fn count_admin_approvals(&self, approvals: &[Pubkey]) -> u8 {
+        approvals
+            .iter()
+            .filter(|approver| self.is_admin_signer(approver))
+            .count() as u8
+    }
+
+    pub fn is_paused(&self, flag: u16) -> bool {
+        self.paused_flags & flag != 0
+    }
+
+    /// Fail if the subsystem behind `flag` is paused.
+    pub fn require_not_paused(&self, flag: u16) -> Result<()> {
+        require!(!self.is_paused(flag), ChainDepthError::SubsystemPaused);
+        Ok(())
+    }
+
+    /// Key currently holding `role`, falling back to `admin` while it is unset.
+    pub fn role_holder(&self, role: u8) -> Pubkey {
+        let holder = self.role_holders[usize::from(role)];
+        if holder == Pubkey::default() {
+            self.admin
+        } else {
+            holder
+        }
+    }
+
+    pub fn is_role_holder(&self, role: u8, key: &Pubkey) -> bool {
+        roles::is_valid(role) && self.role_holder(role) == *key
+    }
+
+    pub fn season_rent_budget_remaining(&self) -> u64 {
+        if self.season_rent_budget == 0 {
+            u64::MAX
+        } else {
+            self.season_rent_budget
+                .saturating_sub(self.season_rent_reimbursed)
+        }
+    }
+
+    /// Whether the treasury covers `rent_cost` out of `spare_lamports` (its balance
+    /// above its own rent exemption) without going over this season's budget.
+    pub fn can_reimburse_rent(&self, rent_cost: u64, spare_lamports: u64) -> bool {
+        rent_cost <= spare_lamports && rent_cost <= self.season_rent_budget_remaining()
+    }
+
+    pub fn record_rent_reimbursement(&mut self, rent_cost: u64) {
+        self.total_rent_reimbursed = self.total_rent_reimbursed.saturating_add(rent_cost);
+        self.season_rent_reimbursed = self.season_rent_reimbursed.saturating_add(rent_cost);
+        self.rooms_funded = self.rooms_funded.saturating_add(1);
+    }
+
+    /// Scale a run score by the deep-exit multiplier.
+    pub fn apply_deep_exit_score(&self, run_score: u64) -> u64 {
+        let scaled = u128::from(run_score) * u128::from(self.deep_exit_score_bps())
+            / u128::from(Self::SCORE_BPS_DENOMINATOR);
+        u64::try_from(scaled).unwrap_or(u64::MAX)
+    }
+}
+
+impl DungeonGrid {
+    pub const MIN_SIZE: u8 = 5;
+    pub const MAX_SIZE: u8 = 20;
+    /// Rings the start room needs on every side: the start approach room and the
+    /// forced depth-one chest sit on ring 1, and the stairs down on the outermost full ring.
+    pub const MIN_OUTER_RING: u32 = 2;
+
+    /// 20x20 grid starting in the middle; also what seasons created before the grid
+    /// was configurable decode as.
+    pub const LEGACY: Self = Self {
+        width: 20,
+        height: 20,
+        start_x: 10,
+        start_y: 10,
+    };
+
+    pub fn or_legacy(self) -> Self {
+        if self.width == 0 {
+            Self::LEGACY
+        } else {
+            self
+        }
+    }
+
+    pub fn max_x(&self) -> i8 {
+        GlobalAccount::MIN_COORD + self.width as i8 - 1
+    }
+
+    pub fn max_y(&self) -> i8 {
+        GlobalAccount::MIN_COORD + self.height as i8 - 1
+    }
+
+    pub fn contains(&self, x: i8, y: i8) -> bool {
+        (GlobalAccount::MIN_COORD..=self.max_x()).contains(&x)
+            && (GlobalAccount::MIN_COORD..=self.max_y()).contains(&y)
+    }
+
+    pub fn is_start(&self, x: i8, y: i8) -> bool {
+        x == self.start_x && y == self.start_y
+    }
+
+    pub fn room_count(&self) -> usize {
+        usize::from(self.width) * usize::from(self.height)
+    }
+
+    /// Ring distance from the start room.
+    pub fn ring(&self, x: i8, y: i8) -> u32 {
+        let dx = (i16::from(x) - i16::from(self.start_x)).unsigned_abs();
+        let dy = (i16::from(y) - i16::from(self.start_y)).unsigned_abs();
+        u32::from(dx.max(dy))
+    }
+
+    /// Distances from the start room to the west, east, south and north edges.
+    fn edge_distances(&self) -> [u32; 4] {
+        [
+            self.ring(GlobalAccount::MIN_COORD, self.start_y),
+            self.ring(self.max_x(), self.start_y),
+            self.ring(self.start_x, GlobalAccount::MIN_COORD),
+            self.ring(self.start_x, self.max_y()),
+        ]
+    }
+
+    /// Widest ring that still has rooms.
+    pub fn max_ring(&self) -> u32 {
+        self.edge_distances().into_iter().max().unwrap_or(0)
+    }
+
+    /// Widest ring that goes all the way around the start room.
+    pub fn outer_complete_ring(&self) -> u32 {
+        self.edge_distances().into_iter().min().unwrap_or(0)
+    }
+
+    /// Sizes in range, start room inside with `MIN_OUTER_RING` rings around it, and
+    /// rings shallow enough that depth never spills into the next floor's stride.
+    pub fn is_valid(&self) -> bool {
+        (Self::MIN_SIZE..=Self::MAX_SIZE).contains(&self.width)
+            && (Self::MIN_SIZE..=Self::MAX_SIZE).contains(&self.height)
+            && self.contains(self.start_x, self.start_y)
+            && self.outer_complete_ring() >= Self::MIN_OUTER_RING
+            && self.max_ring() < FLOOR_DEPTH_STRIDE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_grid_matches_the_original_bounds() {
+        let grid = DungeonGrid::LEGACY;
+        assert!(grid.is_valid());
+        assert_eq!(grid.max_x(), 19);
+        assert_eq!(grid.max_y(), 19);
+        assert_eq!(grid.room_count(), 400);
+        assert_eq!(grid.max_ring(), 10);
+        assert_eq!(grid.outer_complete_ring(), 9);
+        assert_eq!(
+            DungeonGrid {
+                width: 0,
+                height: 0,
+                start_x: 0,
+                start_y: 0,
+            }
+            .or_legacy(),
+            grid
+        );
+    }
+
+    #[test]
+    fn grid_validation_rejects_unplayable_shapes() {
+        let small = DungeonGrid {
+            width: 7,
+            height: 5,
+            start_x: 3,
+            start_y: 2,
+        };
+        assert!(small.is_valid());
+        assert_eq!(small.outer_complete_ring(), 2);
+        assert_eq!(small.max_ring(), 3);
+        assert!(!small.contains(7, 2));
+        assert!(!small.contains(3, 5));
+
+        let too_small = DungeonGrid { width: 4, ..small };
+        let start_outside = DungeonGrid { start_x: 9, ..small };
+        let start_on_edge = DungeonGrid { start_y: 1, ..small };
+        let too_deep = DungeonGrid {
+            width: 20,
+            height: 20,
+            start_x: 2,
+            start_y: 2,
+        };
+        for grid in [too_small, start_outside, start_on_edge, too_deep] {
+            assert!(!grid.is_valid(), "{grid:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rent_reimbursement_stops_at_budget_and_spare_balance() {
+        let mut global = GlobalAccount {
+            season_seed: 1,
+            depth: 0,
+            skr_mint: Pubkey::default(),
+            prize_pool: Pubkey::default(),
+            admin: Pubkey::default(),
+            end_slot: 0,
+            jobs_completed: 0,
+            bump: 0,
+            deep_exit_score_bps: 0,
+            grid: DungeonGrid::LEGACY,
+            next_grid: DungeonGrid::LEGACY,
+            admin_set: [Pubkey::default(); MAX_ADMINS],
+            admin_count: 0,
+            admin_threshold: 0,
+            admin_timelock_slots: 0,
+            paused_flags: 0,
+            role_holders: [Pubkey::default(); roles::COUNT],
+            pending_role_holders: [Pubkey::default(); roles::COUNT],
+            total_rent_reimbursed: 0,
+            rooms_funded: 0,
+            season_rent_reimbursed: 0,
+            season_rent_budget: 0,
+            total_treasury_funded: 0,
+        };
+
+        // No budget: only the spare balance limits reimbursement.
+        assert!(global.can_reimburse_rent(100, 100));
+        assert!(!global.can_reimburse_rent(100, 99));
+
+        global.season_rent_budget = 250;
+        global.record_rent_reimbursement(100);
+        global.record_rent_reimbursement(100);
+        assert_eq!(global.season_rent_budget_remaining(), 50);
+        assert!(!global.can_reimburse_rent(100, 1_000));
+        assert_eq!(global.rooms_funded, 2);
+        assert_eq!(global.total_rent_reimbursed, 200);
+    }
 }
@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
+use crate::state::scoring::item_weight;
 
 pub const MAX_INVENTORY_SLOTS: usize = 64;
 
+// Capacity upgrades (shared by inventory and storage)
+pub const SLOTS_PER_CAPACITY_UPGRADE: u16 = 16;
+pub const MAX_CAPACITY_UPGRADES: u16 = 4;
+/// Upgrade n requires player level >= n * this.
+pub const LEVELS_PER_CAPACITY_UPGRADE: u16 = 5;
+/// Upgrade n costs n * this in SKR base units.
+pub const CAPACITY_UPGRADE_BASE_PRICE: u64 = 50_000_000; // 0.05 * 10^9
+
 pub mod item_ids {
     // Legacy IDs (kept for backward compat with existing inventories)
     pub const LEGACY_ORE: u16 = 1;
@@ -42,10 +51,13 @@ pub mod item_ids {
     pub const DWARF_BEARD_RING: u16 = 217;
     pub const PHOENIX_FEATHER: u16 = 218;
     pub const VOID_SHARD: u16 = 219;
+    pub const GOLD_KEY: u16 = 220;
+    pub const RUNE_KEY: u16 = 221;
 
     // ── Consumable Buffs (300-399) ──
     pub const MINOR_BUFF: u16 = 300;
     pub const MAJOR_BUFF: u16 = 301;
+    pub const TRAP_KIT: u16 = 302;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -62,12 +74,27 @@ pub struct InventoryAccount {
     #[max_len(MAX_INVENTORY_SLOTS)]
     pub items: Vec<InventoryItem>,
     pub bump: u8,
+    /// Slots bought on top of `MAX_INVENTORY_SLOTS`; the account is reallocated to fit.
+    /// Accounts created before this field hold stale item bytes here until
+    /// `migrate_inventory` or `expand_inventory` grows them; every handler that reads it
+    /// calls `reset_stale_bonus_slots` first.
+    pub bonus_slots: u16,
 }
 
 impl InventoryAccount {
     pub const SEED_PREFIX: &'static [u8] = b"inventory";
 
     pub fn add_item(&mut self, item_id: u16, amount: u32, durability: u16) -> Result<()> {
+        require!(
+            self.try_add_item(item_id, amount, durability)?,
+            ChainDepthError::InventoryFull
+        );
+        Ok(())
+    }
+
+    /// Like `add_item`, but returns `Ok(false)` instead of failing when a new
+    /// stack is needed and every slot is taken.
+    pub fn try_add_item(&mut self, item_id: u16, amount: u32, durability: u16) -> Result<bool> {
         require!(item_id > 0, ChainDepthError::InvalidItemId);
         require!(amount > 0, ChainDepthError::InvalidItemAmount);
 
@@ -80,13 +107,12 @@ impl InventoryAccount {
                 .amount
                 .checked_add(amount)
                 .ok_or(ChainDepthError::Overflow)?;
-            return Ok(());
+            return Ok(true);
         }
 
-        require!(
-            self.items.len() < MAX_INVENTORY_SLOTS,
-            ChainDepthError::InventoryFull
-        );
+        if self.items.len() >= self.slot_capacity() {
+            return Ok(false);
+        }
 
         self.items.push(InventoryItem {
             item_id,
@@ -94,7 +120,32 @@ impl InventoryAccount {
             durability,
         });
 
-        Ok(())
+        Ok(true)
+    }
+
+    /// Total carried weight of every stack.
+    pub fn carry_weight(&self) -> u64 {
+        self.items
+            .iter()
+            .map(|item| item_weight(item.item_id).saturating_mul(u64::from(item.amount)))
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn slot_capacity(&self) -> usize {
+        MAX_INVENTORY_SLOTS + usize::from(self.bonus_slots)
+    }
+
+    /// Allocated account size for the given number of bonus slots.
+    pub fn space_for_bonus_slots(bonus_slots: u16) -> usize {
+        space_with_bonus_slots(Self::DISCRIMINATOR.len() + Self::INIT_SPACE, bonus_slots)
+    }
+
+    /// An account shorter than `space_for_bonus_slots(0)` predates `bonus_slots`;
+    /// the bytes it was read from are left over from a longer item list.
+    pub fn reset_stale_bonus_slots(&mut self, data_len: usize) {
+        if data_len < Self::space_for_bonus_slots(0) {
+            self.bonus_slots = 0;
+        }
     }
 
     pub fn remove_item(&mut self, item_id: u16, amount: u32) -> Result<()> {
@@ -121,5 +172,61 @@ impl InventoryAccount {
         self.items.retain(|item| item.amount > 0);
         Ok(())
     }
+
+    /// Remove units from the single stack matching `(item_id, durability)`.
+    pub fn remove_stack(&mut self, item_id: u16, durability: u16, amount: u32) -> Result<()> {
+        remove_stack(&mut self.items, item_id, durability, amount)
+    }
+
+    pub fn item_count(&self, item_id: u16) -> u64 {
+        self.items
+            .iter()
+            .filter(|item| item.item_id == item_id)
+            .map(|item| u64::from(item.amount))
+            .sum()
+    }
+}
+
+/// Account size of an item list account whose base layout takes `base_len` bytes,
+/// grown by `bonus_slots` extra stacks. Shared by inventory and storage.
+pub(crate) fn space_with_bonus_slots(base_len: usize, bonus_slots: u16) -> usize {
+    base_len + usize::from(bonus_slots) * InventoryItem::INIT_SPACE
 }
 
+/// Remove units from the single stack matching `(item_id, durability)`, dropping
+/// the stack once empty. Shared by inventory and storage.
+pub(crate) fn remove_stack(
+    items: &mut Vec<InventoryItem>,
+    item_id: u16,
+    durability: u16,
+    amount: u32,
+) -> Result<()> {
+    require!(item_id > 0, ChainDepthError::InvalidItemId);
+    require!(amount > 0, ChainDepthError::InvalidItemAmount);
+
+    let stack = items
+        .iter_mut()
+        .find(|item| item.item_id == item_id && item.durability == durability)
+        .ok_or(ChainDepthError::InsufficientItemAmount)?;
+    stack.amount = stack
+        .amount
+        .checked_sub(amount)
+        .ok_or(ChainDepthError::InsufficientItemAmount)?;
+
+    items.retain(|item| item.amount > 0);
+    Ok(())
+}
+
+/// Level and SKR price for the next capacity upgrade, given slots already bought.
+/// Returns `None` once `MAX_CAPACITY_UPGRADES` is reached.
+pub fn next_capacity_upgrade(bonus_slots: u16) -> Option<(u16, u64)> {
+    let upgrades_done = bonus_slots / SLOTS_PER_CAPACITY_UPGRADE;
+    if upgrades_done >= MAX_CAPACITY_UPGRADES {
+        return None;
+    }
+    let next = upgrades_done + 1;
+    Some((
+        next * LEVELS_PER_CAPACITY_UPGRADE,
+        CAPACITY_UPGRADE_BASE_PRICE * u64::from(next),
+    ))
+}
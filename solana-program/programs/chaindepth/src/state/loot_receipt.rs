@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 /// Per-player loot receipt for a specific room.
 /// Existence of this PDA proves the player has already looted the chest.
-/// PDA seeds: ["loot_receipt", season_seed (8 bytes), room_x (1 byte), room_y (1 byte), player_pubkey]
+/// PDA seeds: ["loot_receipt", season_seed (8 bytes), room_x (1 byte), room_y (1 byte), floor (1 byte, floors > 0 only), player_pubkey]
 #[account]
 #[derive(InitSpace)]
 pub struct LootReceipt {
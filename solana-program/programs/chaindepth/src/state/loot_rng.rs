@@ -0,0 +1,28 @@
+/// Deterministic splitmix64 stream behind chest, boss and merchant rolls.
+pub struct LootRng {
+    state: u64,
+}
+
+impl LootRng {
+    pub fn new(seed: u64) -> Self {
+        // Avoid zero-lock and keep deterministic progression.
+        Self {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub fn range_u32(&mut self, upper_exclusive: u32) -> u32 {
+        if upper_exclusive <= 1 {
+            return 0;
+        }
+        (self.next_u64() % u64::from(upper_exclusive)) as u32
+    }
+}
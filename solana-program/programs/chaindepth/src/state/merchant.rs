@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::state::inventory::item_ids;
+use crate::state::loot_rng::LootRng;
+use crate::state::scoring::{is_scored_loot_item, score_value_for_item};
+
+/// Offers shown by each merchant room.
+pub const MERCHANT_STOCK_SIZE: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MerchantOffer {
+    pub item_id: u16,
+    pub durability: u16,
+    /// Price in extraction score points, paid with a single kind of carried valuable.
+    pub price_score: u64,
+    /// How many times one player may buy this offer from one merchant.
+    pub limit_per_player: u8,
+}
+
+#[derive(Clone, Copy)]
+struct MerchantCatalogEntry {
+    offer: MerchantOffer,
+    weight: u16,
+}
+
+const fn entry(
+    item_id: u16,
+    durability: u16,
+    price_score: u64,
+    limit_per_player: u8,
+    weight: u16,
+) -> MerchantCatalogEntry {
+    MerchantCatalogEntry {
+        offer: MerchantOffer {
+            item_id,
+            durability,
+            price_score,
+            limit_per_player,
+        },
+        weight,
+    }
+}
+
+// Consumables are common and cheap, weapons rare and pricey.
+const MERCHANT_CATALOG: [MerchantCatalogEntry; 8] = [
+    entry(item_ids::MINOR_BUFF, 0, 6, 3, 24),
+    entry(item_ids::MAJOR_BUFF, 0, 15, 1, 12),
+    entry(item_ids::TRAP_KIT, 0, 5, 2, 18),
+    entry(item_ids::SKELETON_KEY, 0, 12, 1, 14),
+    entry(item_ids::BRONZE_SWORD, 80, 8, 1, 12),
+    entry(item_ids::IRON_PICKAXE, 120, 14, 1, 8),
+    entry(item_ids::IRON_SWORD, 120, 16, 1, 8),
+    entry(item_ids::DIAMOND_SWORD, 200, 40, 1, 4),
+];
+
+/// Per-player purchase counts at a specific merchant room.
+/// PDA seeds: ["merchant_receipt", season_seed (8 bytes), room_x (1 byte), room_y (1 byte), floor (1 byte, floors > 0 only), player_pubkey]
+#[account]
+#[derive(InitSpace)]
+pub struct MerchantReceipt {
+    pub player: Pubkey,
+    pub season_seed: u64,
+    pub room_x: i8,
+    pub room_y: i8,
+    /// Units bought per stock slot, matching `merchant_stock` order.
+    pub purchased: [u8; MERCHANT_STOCK_SIZE],
+    pub bump: u8,
+}
+
+impl MerchantReceipt {
+    pub const SEED_PREFIX: &'static [u8] = b"merchant_receipt";
+}
+
+/// Deterministic stock for the merchant at `(room_x, room_y)`: distinct catalog offers.
+pub fn merchant_stock(season_seed: u64, room_x: i8, room_y: i8) -> [MerchantOffer; MERCHANT_STOCK_SIZE] {
+    let mut seed = season_seed ^ 0x4D45_5243_4841_4E54;
+    seed = seed.wrapping_mul(31).wrapping_add(room_x as u64);
+    seed = seed.wrapping_mul(31).wrapping_add(room_y as u64);
+    let mut rng = LootRng::new(seed);
+
+    let mut picked = [false; MERCHANT_CATALOG.len()];
+    let mut stock = [MERCHANT_CATALOG[0].offer; MERCHANT_STOCK_SIZE];
+    for slot in stock.iter_mut() {
+        let total_weight: u32 = MERCHANT_CATALOG
+            .iter()
+            .zip(picked.iter())
+            .filter(|(_, taken)| !**taken)
+            .map(|(entry, _)| u32::from(entry.weight))
+            .sum();
+        let mut roll = rng.range_u32(total_weight);
+        for (index, entry) in MERCHANT_CATALOG.iter().enumerate() {
+            if picked[index] {
+                continue;
+            }
+            let weight = u32::from(entry.weight);
+            if roll < weight {
+                picked[index] = true;
+                *slot = entry.offer;
+                break;
+            }
+            roll -= weight;
+        }
+    }
+    stock
+}
+
+/// Units of `payment_item_id` needed to cover `price_score`, rounded up.
+/// None if the item is not a valuable the merchant accepts.
+pub fn merchant_payment_amount(price_score: u64, payment_item_id: u16) -> Option<u32> {
+    if !is_scored_loot_item(payment_item_id) {
+        return None;
+    }
+    let unit_value = score_value_for_item(payment_item_id);
+    if unit_value == 0 {
+        return None;
+    }
+    u32::try_from(price_score.div_ceil(unit_value)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stock_is_deterministic_and_distinct() {
+        for seed in 1u64..=500 {
+            let stock = merchant_stock(seed, 4, 15);
+            assert_eq!(stock, merchant_stock(seed, 4, 15));
+            for (index, offer) in stock.iter().enumerate() {
+                assert!(offer.limit_per_player > 0);
+                assert!(stock[index + 1..]
+                    .iter()
+                    .all(|other| other.item_id != offer.item_id));
+            }
+        }
+    }
+
+    #[test]
+    fn every_catalog_offer_can_be_stocked() {
+        let mut seen = [false; MERCHANT_CATALOG.len()];
+        for seed in 1u64..=2000 {
+            for offer in merchant_stock(seed, 3, 3) {
+                let index = MERCHANT_CATALOG
+                    .iter()
+                    .position(|entry| entry.offer == offer)
+                    .unwrap();
+                seen[index] = true;
+            }
+        }
+        assert!(seen.iter().all(|stocked| *stocked));
+    }
+
+    #[test]
+    fn payment_rounds_up_and_rejects_non_valuables() {
+        assert_eq!(merchant_payment_amount(6, item_ids::SILVER_COIN), Some(6));
+        assert_eq!(merchant_payment_amount(6, item_ids::GOLD_BAR), Some(1));
+        assert_eq!(merchant_payment_amount(16, item_ids::RUBY), Some(2));
+        assert_eq!(merchant_payment_amount(6, item_ids::SKELETON_KEY), None);
+        assert_eq!(merchant_payment_amount(6, item_ids::IRON_SWORD), None);
+    }
+}
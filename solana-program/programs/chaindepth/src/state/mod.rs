@@ -1,29 +1,51 @@
 pub mod boss_fight;
+pub mod discovery_royalty;
 pub mod duel_challenge;
+pub mod exported_item;
 pub mod global;
 pub mod helper_stake;
 pub mod inventory;
 pub mod loot_receipt;
+pub mod loot_rng;
+pub mod merchant;
+pub mod pending_admin_action;
 pub mod player;
+pub mod player_migration;
 pub mod player_profile;
 pub mod room;
+pub mod room_connectivity;
 pub mod room_generation;
 pub mod room_presence;
 pub mod scoring;
+pub mod season_history;
 pub mod session_authority;
+pub mod shop_config;
+pub mod shrine_receipt;
 pub mod storage;
+pub mod world_boss;
 
 pub use boss_fight::*;
+pub use discovery_royalty::*;
 pub use duel_challenge::*;
+pub use exported_item::*;
 pub use global::*;
 pub use helper_stake::*;
 pub use inventory::*;
 pub use loot_receipt::*;
+pub use loot_rng::*;
+pub use merchant::*;
+pub use pending_admin_action::*;
 pub use player::*;
+pub use player_migration::*;
 pub use player_profile::*;
 pub use room::*;
+pub use room_connectivity::*;
 pub use room_generation::*;
 pub use room_presence::*;
 pub use scoring::*;
+pub use season_history::*;
 pub use session_authority::*;
+pub use shop_config::*;
+pub use shrine_receipt::*;
 pub use storage::*;
+pub use world_boss::*;
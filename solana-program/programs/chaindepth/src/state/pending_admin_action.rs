@@ -0,0 +1,411 @@
+use anchor_lang::prelude::*;
+
+use super::{is_scored_loot_item, roles, DungeonGrid, GlobalAccount, ShopConfig, MAX_ADMINS};
+use crate::errors::ChainDepthError;
+
+/// Season reset or config change waiting on its timelock and admin approvals.
+/// Closed back to the proposer when executed or cancelled.
+/// PDA seeds: ["pending_admin_action", proposer, action_id]
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAdminAction {
+    pub proposer: Pubkey,
+
+    /// Proposer-chosen id, so one admin can have several actions pending
+    pub action_id: u64,
+
+    pub action: AdminAction,
+
+    pub proposed_slot: u64,
+
+    /// First slot `execute_admin_action` accepts
+    pub execute_after_slot: u64,
+
+    /// Admins who approved, in order; only the first `approval_count` are used
+    pub approvals: [Pubkey; MAX_ADMINS],
+    pub approval_count: u8,
+
+    pub bump: u8,
+}
+
+/// Operation a `PendingAdminAction` runs once executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AdminAction {
+    /// Same as `force_reset_season`
+    ForceResetSeason,
+    /// Same as `set_deep_exit_score`
+    SetDeepExitScore { score_bps: u16 },
+    /// Same as `set_season_grid`
+    SetSeasonGrid { grid: DungeonGrid },
+    /// Replace the admin set, its approval threshold and the timelock
+    SetAdminSet {
+        admins: [Pubkey; MAX_ADMINS],
+        admin_count: u8,
+        threshold: u8,
+        timelock_slots: u64,
+    },
+    /// Hand one of `roles` straight to `holder` (the default key falls back to `admin`),
+    /// withdrawing any pending offer
+    SetRole { role: u8, holder: Pubkey },
+    /// Cap the rent the treasury reimburses per season (0 = no cap)
+    SetRentBudget { lamports: u64 },
+    /// Same as `configure_shop`
+    ConfigureShop { burn_payments: bool },
+    /// Same as `set_shop_listing`
+    SetShopListing {
+        item_id: u16,
+        durability: u16,
+        price: u64,
+        stock_per_season: u32,
+    },
+    /// Same as `set_valuable_sell_price`
+    SetValuableSellPrice { item_id: u16, price: u64 },
+}
+
+impl PendingAdminAction {
+    pub const SEED_PREFIX: &'static [u8] = b"pending_admin_action";
+
+    pub fn approvals(&self) -> &[Pubkey] {
+        &self.approvals[..usize::from(self.approval_count)]
+    }
+
+    /// Record `admin`'s approval. Approvals from keys that have since left the admin
+    /// set are dropped first, so their slots never crowd out current admins.
+    pub fn approve(&mut self, admin: Pubkey, global: &GlobalAccount) -> Result<()> {
+        let mut kept = 0;
+        for index in 0..usize::from(self.approval_count) {
+            let approver = self.approvals[index];
+            if global.is_admin_signer(&approver) {
+                self.approvals[kept] = approver;
+                kept += 1;
+            }
+        }
+        self.approvals[kept..].fill(Pubkey::default());
+        self.approval_count = kept as u8;
+
+        require!(
+            !self.approvals().contains(&admin),
+            ChainDepthError::AdminActionAlreadyApproved
+        );
+        // Every kept approver is a distinct current admin, so the array never overflows.
+        let index = usize::from(self.approval_count);
+        require!(index < MAX_ADMINS, ChainDepthError::AdminActionAlreadyApproved);
+        self.approvals[index] = admin;
+        self.approval_count += 1;
+        Ok(())
+    }
+}
+
+impl AdminAction {
+    /// Longest timelock an admin set can give itself: one season.
+    pub const MAX_TIMELOCK_SLOTS: u64 = GlobalAccount::SEASON_DURATION_SLOTS;
+
+    /// Reject payloads the matching direct instruction would reject.
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            AdminAction::ForceResetSeason
+            | AdminAction::SetRentBudget { .. }
+            | AdminAction::ConfigureShop { .. } => {}
+            AdminAction::SetShopListing { item_id, .. } => {
+                require!(item_id > 0, ChainDepthError::InvalidItemId);
+                require!(
+                    !is_scored_loot_item(item_id),
+                    ChainDepthError::ShopItemNotBuyable
+                );
+            }
+            AdminAction::SetValuableSellPrice { item_id, .. } => require!(
+                is_scored_loot_item(item_id),
+                ChainDepthError::ShopItemNotSellable
+            ),
+            AdminAction::SetRole { role, .. } => {
+                require!(roles::is_valid(role), ChainDepthError::InvalidRole)
+            }
+            AdminAction::SetDeepExitScore { score_bps } => require!(
+                score_bps > 0 && score_bps <= GlobalAccount::MAX_DEEP_EXIT_SCORE_BPS,
+                ChainDepthError::InvalidScoreModifier
+            ),
+            AdminAction::SetSeasonGrid { grid } => {
+                require!(grid.is_valid(), ChainDepthError::InvalidDungeonGrid)
+            }
+            AdminAction::SetAdminSet {
+                admins,
+                admin_count,
+                threshold,
+                timelock_slots,
+            } => {
+                let count = usize::from(admin_count);
+                require!(
+                    (1..=MAX_ADMINS).contains(&count),
+                    ChainDepthError::InvalidAdminSet
+                );
+                require!(
+                    threshold >= 1 && threshold <= admin_count,
+                    ChainDepthError::InvalidAdminSet
+                );
+                require!(
+                    timelock_slots <= Self::MAX_TIMELOCK_SLOTS,
+                    ChainDepthError::InvalidAdminSet
+                );
+                let members = &admins[..count];
+                for (index, admin) in members.iter().enumerate() {
+                    require!(
+                        *admin != Pubkey::default() && !members[..index].contains(admin),
+                        ChainDepthError::InvalidAdminSet
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a config change to `global`; the season reset is handled by the caller.
+    pub fn apply_config(&self, global: &mut GlobalAccount) {
+        match *self {
+            AdminAction::ForceResetSeason => {}
+            AdminAction::SetDeepExitScore { score_bps } => global.deep_exit_score_bps = score_bps,
+            // The running season keeps its grid: rooms already created were laid out on it.
+            AdminAction::SetSeasonGrid { grid } => global.next_grid = grid,
+            AdminAction::SetAdminSet {
+                admins,
+                admin_count,
+                threshold,
+                timelock_slots,
+            } => {
+                global.admin_set = [Pubkey::default(); MAX_ADMINS];
+                global.admin_set[..usize::from(admin_count)]
+                    .copy_from_slice(&admins[..usize::from(admin_count)]);
+                global.admin_count = admin_count;
+                global.admin_threshold = threshold;
+                global.admin_timelock_slots = timelock_slots;
+            }
+            AdminAction::SetRole { role, holder } => {
+                // The admin set already approved the holder, so there is no offer to accept.
+                global.role_holders[usize::from(role)] = holder;
+                global.pending_role_holders[usize::from(role)] = Pubkey::default();
+            }
+            AdminAction::SetRentBudget { lamports } => global.season_rent_budget = lamports,
+            AdminAction::ConfigureShop { .. }
+            | AdminAction::SetShopListing { .. }
+            | AdminAction::SetValuableSellPrice { .. } => {}
+        }
+    }
+
+    /// Whether the action edits `ShopConfig` rather than `global`.
+    pub fn is_shop_action(&self) -> bool {
+        matches!(
+            self,
+            AdminAction::ConfigureShop { .. }
+                | AdminAction::SetShopListing { .. }
+                | AdminAction::SetValuableSellPrice { .. }
+        )
+    }
+
+    /// Apply a shop change to `shop_config`; other actions leave it untouched.
+    pub fn apply_shop(&self, shop_config: &mut ShopConfig) -> Result<()> {
+        match *self {
+            AdminAction::ConfigureShop { burn_payments } => shop_config.burn_payments = burn_payments,
+            AdminAction::SetShopListing {
+                item_id,
+                durability,
+                price,
+                stock_per_season,
+            } => shop_config.upsert_listing(item_id, durability, price, stock_per_season)?,
+            AdminAction::SetValuableSellPrice { item_id, price } => {
+                shop_config.upsert_sell_price(item_id, price)?
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn global_with_admin(admin: Pubkey) -> GlobalAccount {
+        GlobalAccount {
+            season_seed: 1,
+            depth: 0,
+            skr_mint: Pubkey::default(),
+            prize_pool: Pubkey::default(),
+            admin,
+            end_slot: 0,
+            jobs_completed: 0,
+            bump: 0,
+            deep_exit_score_bps: 0,
+            grid: DungeonGrid::LEGACY,
+            next_grid: DungeonGrid::LEGACY,
+            admin_set: [Pubkey::default(); MAX_ADMINS],
+            admin_count: 0,
+            admin_threshold: 0,
+            admin_timelock_slots: 0,
+            paused_flags: 0,
+            role_holders: [Pubkey::default(); roles::COUNT],
+            pending_role_holders: [Pubkey::default(); roles::COUNT],
+            total_rent_reimbursed: 0,
+            rooms_funded: 0,
+            season_rent_reimbursed: 0,
+            season_rent_budget: 0,
+            total_treasury_funded: 0,
+        }
+    }
+
+    fn admin_set_action(admins: &[Pubkey], threshold: u8) -> AdminAction {
+        let mut members = [Pubkey::default(); MAX_ADMINS];
+        members[..admins.len()].copy_from_slice(admins);
+        AdminAction::SetAdminSet {
+            admins: members,
+            admin_count: admins.len() as u8,
+            threshold,
+            timelock_slots: 1_000,
+        }
+    }
+
+    #[test]
+    fn lone_admin_bootstraps_the_admin_set() {
+        let admin = Pubkey::new_unique();
+        let mut global = global_with_admin(admin);
+        assert!(global.is_admin_signer(&admin));
+        assert_eq!(global.admin_threshold(), 1);
+        assert_eq!(global.admin_timelock_slots(), 0);
+
+        let members = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let action = admin_set_action(&members, 2);
+        action.validate().unwrap();
+        action.apply_config(&mut global);
+
+        assert!(!global.is_admin_signer(&admin));
+        assert!(members.iter().all(|member| global.is_admin_signer(member)));
+        assert_eq!(global.admin_threshold(), 2);
+        assert_eq!(global.admin_timelock_slots(), 1_000);
+    }
+
+    #[test]
+    fn approvals_only_count_current_admins() {
+        let members = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut global = global_with_admin(members[0]);
+        admin_set_action(&members, 2).apply_config(&mut global);
+
+        let mut pending = PendingAdminAction {
+            proposer: members[0],
+            action_id: 7,
+            action: AdminAction::ForceResetSeason,
+            proposed_slot: 0,
+            execute_after_slot: 1_000,
+            approvals: [Pubkey::default(); MAX_ADMINS],
+            approval_count: 0,
+            bump: 0,
+        };
+        pending.approve(members[0], &global).unwrap();
+        assert!(pending.approve(members[0], &global).is_err());
+        pending.approve(members[1], &global).unwrap();
+        assert_eq!(global.count_admin_approvals(pending.approvals()), 2);
+
+        // Dropping an approver from the set withdraws their approval.
+        admin_set_action(&[members[0], members[2]], 1).apply_config(&mut global);
+        assert_eq!(global.count_admin_approvals(pending.approvals()), 1);
+
+        // The next approval frees the removed admin's slot.
+        pending.approve(members[2], &global).unwrap();
+        assert_eq!(pending.approvals(), &[members[0], members[2]]);
+        assert_eq!(pending.approvals[2], Pubkey::default());
+    }
+
+    #[test]
+    fn admin_set_validation_rejects_bad_sets() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        assert!(admin_set_action(&[a, b], 2).validate().is_ok());
+        assert!(admin_set_action(&[a, b], 3).validate().is_err());
+        assert!(admin_set_action(&[a, b], 0).validate().is_err());
+        assert!(admin_set_action(&[a, a], 1).validate().is_err());
+        assert!(admin_set_action(&[a, Pubkey::default()], 1).validate().is_err());
+        assert!(admin_set_action(&[], 0).validate().is_err());
+    }
+
+    #[test]
+    fn roles_fall_back_to_admin_until_a_holder_is_set() {
+        let admin = Pubkey::new_unique();
+        let treasurer = Pubkey::new_unique();
+        let mut global = global_with_admin(admin);
+        assert!(global.is_role_holder(roles::TREASURER, &admin));
+        assert!(!global.is_role_holder(roles::COUNT as u8, &admin));
+
+        // A holder's own offer is pending; the admin set's action replaces it outright.
+        global.pending_role_holders[usize::from(roles::TREASURER)] = Pubkey::new_unique();
+        let assignment = AdminAction::SetRole {
+            role: roles::TREASURER,
+            holder: treasurer,
+        };
+        assignment.validate().unwrap();
+        assignment.apply_config(&mut global);
+        assert_eq!(global.role_holder(roles::TREASURER), treasurer);
+        assert_eq!(
+            global.pending_role_holders[usize::from(roles::TREASURER)],
+            Pubkey::default()
+        );
+        assert!(global.is_role_holder(roles::TREASURER, &treasurer));
+        assert!(!global.is_role_holder(roles::TREASURER, &admin));
+        assert!(global.is_role_holder(roles::PAUSER, &admin));
+        assert!(AdminAction::SetRole {
+            role: roles::COUNT as u8,
+            holder: treasurer,
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn shop_actions_edit_the_shop_config() {
+        let mut shop_config = ShopConfig {
+            season_seed: 0,
+            burn_payments: false,
+            listings: Vec::new(),
+            sell_prices: Vec::new(),
+            bump: 0,
+        };
+        shop_config.init_if_new(42, 254);
+        assert_eq!(shop_config.season_seed, 42);
+
+        let listing = AdminAction::SetShopListing {
+            item_id: 1,
+            durability: 10,
+            price: 500,
+            stock_per_season: 3,
+        };
+        let sell_price = AdminAction::SetValuableSellPrice {
+            item_id: 200,
+            price: 75,
+        };
+        for action in [AdminAction::ConfigureShop { burn_payments: true }, listing, sell_price] {
+            assert!(action.is_shop_action());
+            action.validate().unwrap();
+            action.apply_shop(&mut shop_config).unwrap();
+        }
+        assert!(shop_config.burn_payments);
+        assert_eq!(shop_config.listings.len(), 1);
+        assert_eq!(shop_config.listings[0].price, 500);
+        assert_eq!(shop_config.sell_prices.len(), 1);
+        assert_eq!(shop_config.sell_prices[0].price, 75);
+
+        // A second init leaves the live config alone.
+        shop_config.init_if_new(7, 1);
+        assert_eq!(shop_config.season_seed, 42);
+        assert_eq!(shop_config.listings.len(), 1);
+
+        // Same payload rules as the direct instructions.
+        assert!(AdminAction::SetShopListing {
+            item_id: 200,
+            durability: 0,
+            price: 1,
+            stock_per_season: 0,
+        }
+        .validate()
+        .is_err());
+        assert!(AdminAction::SetValuableSellPrice { item_id: 1, price: 1 }
+            .validate()
+            .is_err());
+        assert!(!AdminAction::ForceResetSeason.is_shop_action());
+    }
+}
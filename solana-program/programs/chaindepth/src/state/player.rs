@@ -4,6 +4,22 @@ use anchor_lang::prelude::*;
 pub const MAX_ACTIVE_JOBS: usize = 4;
 pub const DEFAULT_PLAYER_MAX_HP: u16 = 100;
 
+// Progression
+pub const XP_PER_JOB_COMPLETED: u64 = 20;
+pub const XP_PER_CHEST_LOOTED: u64 = 10;
+pub const XP_PER_BOSS_KILL: u64 = 50;
+pub const XP_PER_EXTRACTION: u64 = 30;
+/// XP to go from level 1 to 2; each further level costs this much more than the last.
+pub const LEVEL_XP_STEP: u64 = 100;
+pub const MAX_PLAYER_LEVEL: u16 = 30;
+pub const MAX_HP_PER_LEVEL: u16 = 10;
+/// Levels needed per extra point of bare base DPS.
+pub const LEVELS_PER_BONUS_DPS: u16 = 5;
+
+// Encumbrance
+pub const BASE_MAX_CARRY_WEIGHT: u64 = 150;
+pub const MAX_CARRY_WEIGHT_PER_LEVEL: u64 = 10;
+
 /// Player account - one per wallet
 /// PDA seeds: ["player", user_pubkey]
 #[account]
@@ -61,6 +77,18 @@ pub struct PlayerAccount {
 
     /// PDA bump seed
     pub bump: u8,
+
+    // New fields are appended here so older accounts can be upgraded in place by
+    // reallocating; see `state::player_migration` for the per-version steps.
+
+    /// Lifetime experience points (data_version 3).
+    pub xp: u64,
+
+    /// Level derived from `xp`; drives `max_hp` and bonus DPS (data_version 3).
+    pub level: u16,
+
+    /// Floor of the current room; 0 is the surface floor (data_version 4).
+    pub current_floor: u8,
 }
 
 /// Represents an active job the player is working on
@@ -73,13 +101,18 @@ pub struct ActiveJob {
 
 impl PlayerAccount {
     pub const SEED_PREFIX: &'static [u8] = b"player";
-    pub const CURRENT_DATA_VERSION: u16 = 2;
+    pub const CURRENT_DATA_VERSION: u16 = 4;
 
     /// Check if player is at the given room
     pub fn is_at_room(&self, x: i8, y: i8) -> bool {
         self.current_room_x == x && self.current_room_y == y
     }
 
+    /// Check if player is at the given room on the given floor
+    pub fn is_at_floor_room(&self, floor: u8, x: i8, y: i8) -> bool {
+        self.current_floor == floor && self.is_at_room(x, y)
+    }
+
     /// Check if player is already working on a job at given room/direction
     pub fn has_active_job(&self, room_x: i8, room_y: i8, direction: u8) -> bool {
         self.active_jobs.iter().any(|job| {
@@ -112,8 +145,139 @@ impl PlayerAccount {
         self.last_active_slot = slot;
     }
 
+    /// Add XP and apply any level-ups. Returns true if the level changed.
+    /// Newly gained max HP is also granted as current HP.
+    pub fn grant_xp(&mut self, amount: u64) -> bool {
+        self.xp = self.xp.saturating_add(amount);
+        let new_level = level_for_xp(self.xp);
+        if new_level <= self.level {
+            return false;
+        }
+
+        self.level = new_level;
+        let new_max_hp = max_hp_for_level(new_level);
+        if new_max_hp > self.max_hp {
+            let gained = new_max_hp - self.max_hp;
+            self.max_hp = new_max_hp;
+            self.current_hp = self.current_hp.saturating_add(gained).min(new_max_hp);
+        }
+        true
+    }
+
+    /// Legacy layouts go through `migrate_player_account` before any gameplay.
+    pub fn require_current_version(&self) -> Result<()> {
+        require!(
+            self.data_version == Self::CURRENT_DATA_VERSION,
+            crate::errors::ChainDepthError::PlayerAccountMigrationRequired
+        );
+        Ok(())
+    }
+
     pub fn require_in_dungeon(&self) -> Result<()> {
         require!(self.in_dungeon, crate::errors::ChainDepthError::NotInDungeon);
         Ok(())
     }
+
+    pub fn require_out_of_dungeon(&self) -> Result<()> {
+        require!(!self.in_dungeon, crate::errors::ChainDepthError::PlayerInDungeon);
+        Ok(())
+    }
+}
+
+/// Total XP required to reach `level` (level 1 needs 0).
+pub fn xp_for_level(level: u16) -> u64 {
+    let steps = u64::from(level.saturating_sub(1));
+    LEVEL_XP_STEP.saturating_mul(steps.saturating_mul(steps + 1) / 2)
+}
+
+pub fn level_for_xp(xp: u64) -> u16 {
+    let mut level = 1;
+    while level < MAX_PLAYER_LEVEL && xp >= xp_for_level(level + 1) {
+        level += 1;
+    }
+    level
+}
+
+pub fn max_hp_for_level(level: u16) -> u16 {
+    DEFAULT_PLAYER_MAX_HP.saturating_add(MAX_HP_PER_LEVEL.saturating_mul(level.saturating_sub(1)))
+}
+
+/// Extra DPS added on top of the equipped weapon.
+pub fn level_dps_bonus(level: u16) -> u64 {
+    u64::from(level.saturating_sub(1) / LEVELS_PER_BONUS_DPS)
+}
+
+/// Heaviest load a player can move with.
+pub fn max_carry_weight(level: u16) -> u64 {
+    BASE_MAX_CARRY_WEIGHT
+        .saturating_add(MAX_CARRY_WEIGHT_PER_LEVEL.saturating_mul(u64::from(level.saturating_sub(1))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player() -> PlayerAccount {
+        PlayerAccount {
+            owner: Pubkey::new_unique(),
+            current_room_x: 10,
+            current_room_y: 10,
+            active_jobs: Vec::new(),
+            jobs_completed: 0,
+            chests_looted: 0,
+            equipped_item_id: 0,
+            total_score: 0,
+            current_run_start_slot: 0,
+            runs_extracted: 0,
+            last_extraction_slot: 0,
+            in_dungeon: true,
+            current_hp: 40,
+            max_hp: DEFAULT_PLAYER_MAX_HP,
+            data_version: PlayerAccount::CURRENT_DATA_VERSION,
+            last_active_slot: 0,
+            bump: 255,
+            xp: 0,
+            level: 1,
+            current_floor: 0,
+        }
+    }
+
+    #[test]
+    fn level_curve_thresholds() {
+        assert_eq!(xp_for_level(1), 0);
+        assert_eq!(xp_for_level(2), 100);
+        assert_eq!(xp_for_level(3), 300);
+        assert_eq!(level_for_xp(0), 1);
+        assert_eq!(level_for_xp(99), 1);
+        assert_eq!(level_for_xp(100), 2);
+        assert_eq!(level_for_xp(299), 2);
+        assert_eq!(level_for_xp(u64::MAX), MAX_PLAYER_LEVEL);
+    }
+
+    #[test]
+    fn level_up_raises_max_and_current_hp() {
+        let mut player = player();
+        assert!(!player.grant_xp(99));
+        assert!(player.grant_xp(1));
+        assert_eq!(player.level, 2);
+        assert_eq!(player.max_hp, DEFAULT_PLAYER_MAX_HP + MAX_HP_PER_LEVEL);
+        assert_eq!(player.current_hp, 40 + MAX_HP_PER_LEVEL);
+    }
+
+    #[test]
+    fn carry_limit_grows_with_level() {
+        assert_eq!(max_carry_weight(0), BASE_MAX_CARRY_WEIGHT);
+        assert_eq!(max_carry_weight(1), BASE_MAX_CARRY_WEIGHT);
+        assert_eq!(
+            max_carry_weight(3),
+            BASE_MAX_CARRY_WEIGHT + 2 * MAX_CARRY_WEIGHT_PER_LEVEL
+        );
+    }
+
+    #[test]
+    fn dps_bonus_steps_every_few_levels() {
+        assert_eq!(level_dps_bonus(1), 0);
+        assert_eq!(level_dps_bonus(LEVELS_PER_BONUS_DPS), 0);
+        assert_eq!(level_dps_bonus(LEVELS_PER_BONUS_DPS + 1), 1);
+    }
 }
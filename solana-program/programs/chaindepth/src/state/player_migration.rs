@@ -0,0 +1,366 @@
+use anchor_lang::prelude::*;
+
+use super::{level_for_xp, max_hp_for_level, ActiveJob, PlayerAccount, DEFAULT_PLAYER_MAX_HP};
+use crate::errors::ChainDepthError;
+
+/// Bytes appended to the account in data_version 3 (`xp: u64`, `level: u16`).
+const V3_APPENDED_LEN: usize = 8 + 2;
+/// Bytes appended to the account in data_version 4 (`current_floor: u8`).
+const V4_APPENDED_LEN: usize = 1;
+
+/// Allocated account size (discriminator included) for a given layout version.
+/// Versions 0-2 share one byte layout; only their semantics differ.
+pub fn player_account_len_for_version(version: u16) -> usize {
+    let current = PlayerAccount::DISCRIMINATOR.len() + PlayerAccount::INIT_SPACE;
+    match version {
+        0..=2 => current - V3_APPENDED_LEN - V4_APPENDED_LEN,
+        3 => current - V4_APPENDED_LEN,
+        _ => current,
+    }
+}
+
+/// Fields every layout version stores, in on-chain order; versions 0-2 end here.
+#[derive(AnchorDeserialize)]
+struct PlayerAccountV2 {
+    owner: Pubkey,
+    current_room_x: i8,
+    current_room_y: i8,
+    active_jobs: Vec<ActiveJob>,
+    jobs_completed: u64,
+    chests_looted: u64,
+    equipped_item_id: u16,
+    total_score: u64,
+    current_run_start_slot: u64,
+    runs_extracted: u64,
+    last_extraction_slot: u64,
+    in_dungeon: bool,
+    current_hp: u16,
+    max_hp: u16,
+    data_version: u16,
+    last_active_slot: u64,
+    bump: u8,
+}
+
+/// Decode a player account of any supported version, reading only the fields
+/// its `data_version` wrote. Bytes past them are left over from a longer job
+/// list (Anchor never clears the tail), so later fields start from their defaults.
+pub fn decode_player_account(data: &[u8]) -> Result<PlayerAccount> {
+    require!(
+        data.starts_with(PlayerAccount::DISCRIMINATOR),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    let mut rest = &data[PlayerAccount::DISCRIMINATOR.len()..];
+    let base = PlayerAccountV2::deserialize(&mut rest)
+        .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+
+    let (xp, level) = if base.data_version >= 3 {
+        <(u64, u16)>::deserialize(&mut rest)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?
+    } else {
+        (0, 1)
+    };
+    let current_floor = if base.data_version >= 4 {
+        u8::deserialize(&mut rest)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?
+    } else {
+        0
+    };
+
+    Ok(PlayerAccount {
+        owner: base.owner,
+        current_room_x: base.current_room_x,
+        current_room_y: base.current_room_y,
+        active_jobs: base.active_jobs,
+        jobs_completed: base.jobs_completed,
+        chests_looted: base.chests_looted,
+        equipped_item_id: base.equipped_item_id,
+        total_score: base.total_score,
+        current_run_start_slot: base.current_run_start_slot,
+        runs_extracted: base.runs_extracted,
+        last_extraction_slot: base.last_extraction_slot,
+        in_dungeon: base.in_dungeon,
+        current_hp: base.current_hp,
+        max_hp: base.max_hp,
+        data_version: base.data_version,
+        last_active_slot: base.last_active_slot,
+        bump: base.bump,
+        xp,
+        level,
+        current_floor,
+    })
+}
+
+/// Upgrade one version at a time until current. Returns the starting version.
+pub fn upgrade_player_account(player: &mut PlayerAccount) -> Result<u16> {
+    let from_version = player.data_version;
+    require!(
+        from_version <= PlayerAccount::CURRENT_DATA_VERSION,
+        ChainDepthError::UnsupportedDataVersion
+    );
+
+    while player.data_version < PlayerAccount::CURRENT_DATA_VERSION {
+        match player.data_version {
+            0 => upgrade_v0_to_v1(player),
+            1 => upgrade_v1_to_v2(player),
+            2 => upgrade_v2_to_v3(player),
+            3 => upgrade_v3_to_v4(player),
+            _ => return err!(ChainDepthError::UnsupportedDataVersion),
+        }
+        player.data_version += 1;
+    }
+
+    Ok(from_version)
+}
+
+/// v1 introduced hit points; v0 accounts have zeroed HP fields.
+fn upgrade_v0_to_v1(player: &mut PlayerAccount) {
+    if player.max_hp == 0 {
+        player.max_hp = DEFAULT_PLAYER_MAX_HP;
+    }
+    if player.current_hp == 0 && !player.in_dungeon {
+        player.current_hp = player.max_hp;
+    }
+}
+
+/// v2 started tracking `last_active_slot`; backfill from the latest known activity.
+fn upgrade_v1_to_v2(player: &mut PlayerAccount) {
+    if player.last_active_slot == 0 {
+        player.last_active_slot = player
+            .current_run_start_slot
+            .max(player.last_extraction_slot);
+    }
+}
+
+/// v3 appended xp/level; level 1 at zero xp keeps max_hp unchanged.
+fn upgrade_v2_to_v3(player: &mut PlayerAccount) {
+    player.level = level_for_xp(player.xp);
+    player.max_hp = player.max_hp.max(max_hp_for_level(player.level));
+}
+
+/// v4 appended `current_floor`; every older account is on the surface floor.
+fn upgrade_v3_to_v4(player: &mut PlayerAccount) {
+    player.current_floor = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{ActiveJob, MAX_ACTIVE_JOBS, MAX_HP_PER_LEVEL};
+
+    fn player_at_version(version: u16) -> PlayerAccount {
+        PlayerAccount {
+            owner: Pubkey::new_unique(),
+            current_room_x: 10,
+            current_room_y: 11,
+            active_jobs: Vec::new(),
+            jobs_completed: 3,
+            chests_looted: 5,
+            equipped_item_id: 103,
+            total_score: 900,
+            current_run_start_slot: 1_000,
+            runs_extracted: 2,
+            last_extraction_slot: 800,
+            in_dungeon: false,
+            current_hp: 70,
+            max_hp: DEFAULT_PLAYER_MAX_HP,
+            data_version: version,
+            last_active_slot: 1_200,
+            bump: 254,
+            xp: 0,
+            level: if version >= 3 { 1 } else { 0 },
+            current_floor: 0,
+        }
+    }
+
+    /// Serialize as the given version would have been stored on chain.
+    fn legacy_bytes(player: &PlayerAccount, version: u16) -> Vec<u8> {
+        let mut data = vec![0u8; player_account_len_for_version(PlayerAccount::CURRENT_DATA_VERSION)];
+        player.try_serialize(&mut &mut data[..]).unwrap();
+        data.truncate(player_account_len_for_version(version));
+        data
+    }
+
+    #[test]
+    fn v0_to_v1_fills_missing_hp() {
+        let mut player = player_at_version(0);
+        player.max_hp = 0;
+        player.current_hp = 0;
+
+        upgrade_v0_to_v1(&mut player);
+
+        assert_eq!(player.max_hp, DEFAULT_PLAYER_MAX_HP);
+        assert_eq!(player.current_hp, DEFAULT_PLAYER_MAX_HP);
+    }
+
+    #[test]
+    fn v0_to_v1_keeps_dead_in_dungeon_player_dead() {
+        let mut player = player_at_version(0);
+        player.max_hp = 0;
+        player.current_hp = 0;
+        player.in_dungeon = true;
+
+        upgrade_v0_to_v1(&mut player);
+
+        assert_eq!(player.max_hp, DEFAULT_PLAYER_MAX_HP);
+        assert_eq!(player.current_hp, 0);
+    }
+
+    #[test]
+    fn v1_to_v2_backfills_last_active_slot() {
+        let mut player = player_at_version(1);
+        player.last_active_slot = 0;
+
+        upgrade_v1_to_v2(&mut player);
+        assert_eq!(player.last_active_slot, 1_000);
+
+        let mut player = player_at_version(1);
+        upgrade_v1_to_v2(&mut player);
+        assert_eq!(player.last_active_slot, 1_200);
+    }
+
+    #[test]
+    fn v2_to_v3_derives_level_from_xp() {
+        let mut player = player_at_version(2);
+        upgrade_v2_to_v3(&mut player);
+        assert_eq!(player.level, 1);
+        assert_eq!(player.max_hp, DEFAULT_PLAYER_MAX_HP);
+
+        let mut player = player_at_version(2);
+        player.xp = 100;
+        upgrade_v2_to_v3(&mut player);
+        assert_eq!(player.level, 2);
+        assert_eq!(player.max_hp, DEFAULT_PLAYER_MAX_HP + MAX_HP_PER_LEVEL);
+    }
+
+    #[test]
+    fn v2_with_a_dirty_tail_migrates_at_level_one() {
+        let mut longer = player_at_version(2);
+        longer.active_jobs = (0..MAX_ACTIVE_JOBS as u8)
+            .map(|direction| ActiveJob {
+                room_x: 127,
+                room_y: 127,
+                direction,
+            })
+            .collect();
+        let mut data = legacy_bytes(&longer, 2);
+        write_prefix(
+            &player_at_version(2),
+            &mut data,
+            V3_APPENDED_LEN + V4_APPENDED_LEN,
+        );
+
+        let mut player = decode_player_account(&data).unwrap();
+        upgrade_player_account(&mut player).unwrap();
+
+        assert_eq!(player.xp, 0);
+        assert_eq!(player.level, 1);
+        assert_eq!(player.max_hp, DEFAULT_PLAYER_MAX_HP);
+        assert_eq!(player.current_hp, 70);
+    }
+
+    #[test]
+    fn v3_decodes_onto_the_surface_floor() {
+        let mut original = player_at_version(3);
+        original.current_floor = 1;
+        let data = legacy_bytes(&original, 3);
+        assert_eq!(data.len(), player_account_len_for_version(4) - 1);
+
+        let mut player = decode_player_account(&data).unwrap();
+        upgrade_player_account(&mut player).unwrap();
+
+        assert_eq!(player.current_floor, 0);
+        assert_eq!(player.level, original.level);
+        assert_eq!(player.data_version, 4);
+    }
+
+    #[test]
+    fn every_version_upgrades_to_current() {
+        for version in 0..=PlayerAccount::CURRENT_DATA_VERSION {
+            let original = player_at_version(version);
+            let data = legacy_bytes(&original, version);
+
+            let mut player = decode_player_account(&data).unwrap();
+            let from_version = upgrade_player_account(&mut player).unwrap();
+
+            assert_eq!(from_version, version);
+            assert_eq!(player.data_version, PlayerAccount::CURRENT_DATA_VERSION);
+            assert_eq!(player.owner, original.owner);
+            assert_eq!(player.total_score, original.total_score);
+            assert_eq!(player.bump, original.bump);
+            assert!(player.level >= 1);
+        }
+    }
+
+    #[test]
+    fn full_job_list_decodes_from_legacy_length() {
+        let mut original = player_at_version(2);
+        original.active_jobs = (0..MAX_ACTIVE_JOBS as u8)
+            .map(|direction| ActiveJob {
+                room_x: 10,
+                room_y: 10,
+                direction,
+            })
+            .collect();
+        let data = legacy_bytes(&original, 2);
+
+        let player = decode_player_account(&data).unwrap();
+
+        assert_eq!(player.active_jobs.len(), MAX_ACTIVE_JOBS);
+        assert_eq!(player.bump, original.bump);
+        assert_eq!(player.xp, 0);
+    }
+
+    #[test]
+    fn stale_tail_bytes_are_not_read_as_new_fields() {
+        // A job list that shrank leaves its old bytes behind the shorter encoding.
+        let mut longer = player_at_version(2);
+        longer.active_jobs = (0..MAX_ACTIVE_JOBS as u8)
+            .map(|direction| ActiveJob {
+                room_x: -2,
+                room_y: -2,
+                direction,
+            })
+            .collect();
+        let dirty = legacy_bytes(&longer, 2);
+
+        let mut original = player_at_version(2);
+        original.active_jobs = longer.active_jobs[..1].to_vec();
+        let mut data = dirty.clone();
+        write_prefix(&original, &mut data, V3_APPENDED_LEN + V4_APPENDED_LEN);
+
+        let player = decode_player_account(&data).unwrap();
+
+        assert_eq!(player.active_jobs.len(), 1);
+        assert_eq!(player.bump, original.bump);
+        assert_eq!(player.xp, 0);
+        assert_eq!(player.level, 1);
+        assert_eq!(player.current_floor, 0);
+
+        let mut original = player_at_version(3);
+        original.active_jobs = longer.active_jobs[..1].to_vec();
+        original.xp = 100;
+        original.level = 2;
+        let mut data = dirty;
+        data.resize(player_account_len_for_version(3), 0xFE);
+        write_prefix(&original, &mut data, V4_APPENDED_LEN);
+
+        let player = decode_player_account(&data).unwrap();
+
+        assert_eq!((player.xp, player.level), (100, 2));
+        assert_eq!(player.current_floor, 0);
+    }
+
+    /// Writes `player` the way an older program would, without the last `skipped` bytes.
+    fn write_prefix(player: &PlayerAccount, data: &mut [u8], skipped: usize) {
+        let mut encoded = Vec::new();
+        player.try_serialize(&mut encoded).unwrap();
+        let len = encoded.len() - skipped;
+        data[..len].copy_from_slice(&encoded[..len]);
+    }
+
+    #[test]
+    fn rejects_future_versions() {
+        let mut player = player_at_version(PlayerAccount::CURRENT_DATA_VERSION + 1);
+        assert!(upgrade_player_account(&mut player).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use super::global::GlobalAccount;
+use super::global::{DungeonGrid, GlobalAccount};
+use super::inventory::item_ids;
 
 pub const MAX_BOSS_HP: u64 = 100_000;
 
@@ -20,6 +21,34 @@ pub const WALL_ENTRANCE_STAIRS: u8 = 4;
 /// Door lock kind constants
 pub const LOCK_KIND_NONE: u8 = 0;
 pub const LOCK_KIND_SKELETON: u8 = 1;
+pub const LOCK_KIND_GOLD: u8 = 2;
+/// Needs two rune keys at once.
+pub const LOCK_KIND_RUNE: u8 = 3;
+/// Gold lock that also needs a second player standing in the room.
+pub const LOCK_KIND_GOLD_COOP: u8 = 4;
+
+/// What it takes to open a door of a given lock kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LockRequirement {
+    pub key_item_id: u16,
+    pub keys_required: u32,
+    pub needs_partner: bool,
+}
+
+pub fn lock_requirement(lock_kind: u8) -> Option<LockRequirement> {
+    let (key_item_id, keys_required, needs_partner) = match lock_kind {
+        LOCK_KIND_SKELETON => (item_ids::SKELETON_KEY, 1, false),
+        LOCK_KIND_GOLD => (item_ids::GOLD_KEY, 1, false),
+        LOCK_KIND_RUNE => (item_ids::RUNE_KEY, 2, false),
+        LOCK_KIND_GOLD_COOP => (item_ids::GOLD_KEY, 1, true),
+        _ => return None,
+    };
+    Some(LockRequirement {
+        key_item_id,
+        keys_required,
+        needs_partner,
+    })
+}
 
 /// Center state constants
 pub const CENTER_EMPTY: u8 = 0;
@@ -28,9 +57,39 @@ pub const CENTER_BOSS: u8 = 2;
 pub const CENTER_BONE_CHEST: u8 = 3;
 pub const CENTER_GILDED_CHEST: u8 = 4;
 pub const CENTER_SARCOPHAGUS_CHEST: u8 = 5;
+/// Deep room with stairs to the surface; `exit_dungeon` is allowed here.
+pub const CENTER_EXTRACTION_STAIRS: u8 = 6;
+/// Damages players who move in until someone disarms it.
+pub const CENTER_TRAP: u8 = 7;
+/// Heals to full once per player; `center_id` picks the shrine variant.
+pub const CENTER_SHRINE: u8 = 8;
+/// Trades consumables and gear for carried valuables; stock comes from `merchant_stock`.
+pub const CENTER_MERCHANT: u8 = 9;
+/// Outer-ring room with stairs down to the next floor; see `descend`.
+pub const CENTER_STAIRS_DOWN: u8 = 10;
+
+/// Shrine variants (stored in `center_id`)
+pub const SHRINE_FREE: u16 = 1;
+/// Demands one scored valuable as tribute before healing.
+pub const SHRINE_TRIBUTE: u16 = 2;
+
+/// Biome ids (stored in `biome_id`); picked by depth band in `biome_for_depth`
+pub const BIOME_CRYPT: u8 = 0;
+pub const BIOME_CATACOMBS: u8 = 1;
+pub const BIOME_ABYSS: u8 = 2;
+/// Every floor below the surface.
+pub const BIOME_UNDERDARK: u8 = 3;
+
+/// Elite boss modifier bits (stored in `boss_modifiers`); rolled with the room in `select_elite_modifiers`
+/// Heals back towards full HP while nobody is fighting it.
+pub const ELITE_REGENERATING: u8 = 1 << 0;
+/// Hits fighters harder once the fight has run for `ELITE_ENRAGE_AFTER_SLOTS`.
+pub const ELITE_ENRAGE: u8 = 1 << 1;
+/// Takes no damage until `ELITE_SHIELD_MIN_FIGHTERS` players fight it at once.
+pub const ELITE_SHIELDED: u8 = 1 << 2;
 
 /// Room account - one per coordinate pair per season
-/// PDA seeds: ["room", season_seed (8 bytes), x (1 byte), y (1 byte)]
+/// PDA seeds: ["room", season_seed (8 bytes), x (1 byte), y (1 byte), floor (1 byte, floors > 0 only)]
 #[account]
 #[derive(InitSpace)]
 pub struct RoomAccount {
@@ -46,7 +105,8 @@ pub struct RoomAccount {
     /// 3 = locked, 4 = entrance stairs (extract at spawn room south)
     pub walls: [u8; 4],
 
-    /// Per-door lock kind: 0 = none, 1 = skeleton lock
+    /// Per-door lock kind: 0 = none, 1 = skeleton, 2 = gold, 3 = rune (two keys),
+    /// 4 = cooperative gold (second player present)
     pub door_lock_kinds: [u8; 4],
 
     /// Count of active helpers per direction
@@ -111,11 +171,36 @@ pub struct RoomAccount {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Dungeon floor this room belongs to (0 = surface floor)
+    pub floor: u8,
+
+    /// Biome theme clients dress the room in (see `BIOME_*`)
+    pub biome_id: u8,
+
+    /// Elite modifier bits of the boss (see `ELITE_*`); 0 for regular bosses
+    pub boss_modifiers: u8,
+
+    /// Slot the current boss fight started (first fighter joined an empty fight)
+    pub boss_engaged_slot: u64,
+
+    /// Whether a trap center has been disarmed
+    pub trap_disarmed: bool,
 }
 
 impl RoomAccount {
     pub const SEED_PREFIX: &'static [u8] = b"room";
 
+    /// Floor component of room-keyed PDA seeds. Floor 0 adds nothing so surface
+    /// floor addresses stay the same; deeper floors append the floor byte.
+    pub fn floor_seed(floor: &u8) -> &[u8] {
+        if *floor == 0 {
+            &[]
+        } else {
+            std::slice::from_ref(floor)
+        }
+    }
+
     /// Stake amount per player joining a job (0.01 SKR with 9 decimals)
     pub const STAKE_AMOUNT: u64 = 10_000_000; // 0.01 * 10^9
 
@@ -131,8 +216,22 @@ impl RoomAccount {
     /// Refund percentage when abandoning (80%)
     pub const ABANDON_REFUND_PERCENT: u64 = 80;
     pub const BOSS_BASE_HP: u64 = 300;
+    /// HP and damage multiplier of standard boss ids 1-8 (index `id - 1`). The deeper id
+    /// `k + 5` outranks the Crypt id `k + 1` it replaces, so bosses never weaken with depth.
+    pub const BOSS_STAT_MULTIPLIERS: [u64; 8] = [2, 3, 4, 5, 4, 5, 6, 7];
+    /// Multiplier of ids outside the table, such as the bone boss.
+    pub const DEFAULT_BOSS_STAT_MULTIPLIER: u64 = 2;
     pub const BONE_BOSS_ID: u16 = 11;
     pub const BONE_BOSS_HP_MULTIPLIER: u64 = 5;
+    pub const TRAP_BASE_DAMAGE: u64 = 10;
+    pub const TRAP_DAMAGE_PER_DEPTH: u64 = 5;
+    pub const TRAP_VARIANTS: u16 = 3;
+    /// Regenerating elites heal from 0 to full HP over this many idle slots (~10 minutes).
+    pub const ELITE_REGEN_SLOTS_TO_FULL: u64 = 1_500;
+    /// Slots into a fight before an enrage elite starts hitting harder (~5 minutes).
+    pub const ELITE_ENRAGE_AFTER_SLOTS: u64 = 750;
+    pub const ELITE_ENRAGE_DAMAGE_MULTIPLIER: u64 = 2;
+    pub const ELITE_SHIELD_MIN_FIGHTERS: u32 = 2;
 
     /// Get opposite direction
     pub fn opposite_direction(direction: u8) -> u8 {
@@ -188,11 +287,37 @@ impl RoomAccount {
             || center_type == CENTER_BONE_CHEST
             || center_type == CENTER_GILDED_CHEST
             || center_type == CENTER_SARCOPHAGUS_CHEST
+            || center_type == CENTER_EXTRACTION_STAIRS
+            || center_type == CENTER_TRAP
+            || center_type == CENTER_SHRINE
+            || center_type == CENTER_MERCHANT
+    }
+
+    pub fn is_trap_armed(&self) -> bool {
+        self.center_type == CENTER_TRAP && !self.trap_disarmed
+    }
+
+    pub fn disarm_trap(&mut self) {
+        self.trap_disarmed = true;
+    }
+
+    pub fn trap_damage_for_depth(depth: u32) -> u16 {
+        let damage = Self::TRAP_BASE_DAMAGE
+            .saturating_add(Self::TRAP_DAMAGE_PER_DEPTH.saturating_mul(u64::from(depth)));
+        u16::try_from(damage).unwrap_or(u16::MAX)
+    }
+
+    pub fn boss_stat_multiplier(boss_id: u16) -> u64 {
+        usize::from(boss_id)
+            .checked_sub(1)
+            .and_then(|index| Self::BOSS_STAT_MULTIPLIERS.get(index))
+            .copied()
+            .unwrap_or(Self::DEFAULT_BOSS_STAT_MULTIPLIER)
     }
 
     pub fn boss_hp_for_depth(depth: u32, boss_id: u16) -> u64 {
         let depth_multiplier = 1 + (depth / 4) as u64;
-        let id_multiplier = 1 + (boss_id % 5) as u64;
+        let id_multiplier = Self::boss_stat_multiplier(boss_id);
         let mut hp = Self::BOSS_BASE_HP
             .saturating_mul(depth_multiplier)
             .saturating_mul(id_multiplier);
@@ -202,23 +327,61 @@ impl RoomAccount {
         hp.min(MAX_BOSS_HP)
     }
 
-    pub fn generate_start_walls(_season_seed: u64, x: i8, y: i8) -> [u8; 4] {
+    pub fn is_elite(&self) -> bool {
+        self.center_type == CENTER_BOSS && self.boss_modifiers != 0
+    }
+
+    pub fn has_boss_modifier(&self, modifier: u8) -> bool {
+        self.center_type == CENTER_BOSS && self.boss_modifiers & modifier != 0
+    }
+
+    /// Shielded elites ignore fighter damage while too few players fight together.
+    pub fn is_boss_shield_up(&self) -> bool {
+        self.has_boss_modifier(ELITE_SHIELDED)
+            && self.boss_fighter_count < Self::ELITE_SHIELD_MIN_FIGHTERS
+    }
+
+    /// HP a regenerating elite recovers over `idle_slots` without fighters.
+    pub fn boss_regen_for_slots(&self, idle_slots: u64) -> u64 {
+        if !self.has_boss_modifier(ELITE_REGENERATING) {
+            return 0;
+        }
+        let regen = u128::from(self.boss_max_hp) * u128::from(idle_slots)
+            / u128::from(Self::ELITE_REGEN_SLOTS_TO_FULL);
+        regen.min(u128::from(self.boss_max_hp)) as u64
+    }
+
+    /// How many of `ticks` damage ticks (each `tick_slots` long, counted from
+    /// `last_tick_slot`) land after an enrage elite's timer ran out.
+    pub fn enraged_boss_ticks(&self, last_tick_slot: u64, ticks: u64, tick_slots: u64) -> u64 {
+        if !self.has_boss_modifier(ELITE_ENRAGE) || tick_slots == 0 {
+            return 0;
+        }
+        let enrage_slot = self
+            .boss_engaged_slot
+            .saturating_add(Self::ELITE_ENRAGE_AFTER_SLOTS);
+        let slots_until_enrage = enrage_slot.saturating_sub(last_tick_slot);
+        let calm_ticks = slots_until_enrage.saturating_sub(1) / tick_slots;
+        ticks.saturating_sub(calm_ticks)
+    }
+
+    pub fn generate_start_walls(_season_seed: u64, grid: DungeonGrid, x: i8, y: i8) -> [u8; 4] {
         let mut walls = [WALL_OPEN; 4];
         walls[DIRECTION_SOUTH as usize] = WALL_ENTRANCE_STAIRS;
-        Self::clamp_boundary_walls(&mut walls, x, y);
+        Self::clamp_boundary_walls(&mut walls, grid, x, y);
         walls
     }
 
     /// Force walls that face outside the grid boundary to Solid so
     /// players never see interactable doors that lead nowhere.
-    pub fn clamp_boundary_walls(walls: &mut [u8; 4], x: i8, y: i8) {
-        if y >= GlobalAccount::MAX_COORD {
+    pub fn clamp_boundary_walls(walls: &mut [u8; 4], grid: DungeonGrid, x: i8, y: i8) {
+        if y >= grid.max_y() {
             walls[DIRECTION_NORTH as usize] = WALL_SOLID;
         }
         if y <= GlobalAccount::MIN_COORD {
             walls[DIRECTION_SOUTH as usize] = WALL_SOLID;
         }
-        if x >= GlobalAccount::MAX_COORD {
+        if x >= grid.max_x() {
             walls[DIRECTION_EAST as usize] = WALL_SOLID;
         }
         if x <= GlobalAccount::MIN_COORD {
@@ -0,0 +1,428 @@
+use anchor_lang::prelude::*;
+
+use super::{
+    calculate_depth, floor_season_seed, generate_room_hash, generate_walls, is_start_approach_wall,
+    DungeonGrid, GlobalAccount, RoomAccount, DIRECTION_WEST, WALL_LOCKED, WALL_OPEN, WALL_RUBBLE,
+    WALL_SOLID,
+};
+
+/// Entrance sentinel that never matches a direction, so every wall is rolled.
+const NO_ENTRANCE: u8 = u8::MAX;
+
+/// Rooms in the largest grid a season can use.
+const MAX_GRID_ROOMS: usize = DungeonGrid::MAX_SIZE as usize * DungeonGrid::MAX_SIZE as usize;
+
+/// Bytes of `FloorConnectivity::repaired_walls`: one nibble of directions per room.
+const REPAIRED_WALL_BYTES: usize = MAX_GRID_ROOMS / 2;
+
+/// Connectivity repairs of one floor. The full-grid analysis runs once, on the first
+/// discovery of the floor; every later discovery only reads its own room's nibble.
+/// PDA seeds: ["floor_connectivity", season_seed, floor]
+#[account]
+#[derive(InitSpace)]
+pub struct FloorConnectivity {
+    pub season_seed: u64,
+    pub floor: u8,
+
+    /// Set once the repairs below are filled in
+    pub is_analyzed: bool,
+
+    pub reachable_without_repairs: u16,
+    pub repair_count: u16,
+
+    /// Repaired directions per room index, low nibble first (bit `d` = direction `d`)
+    pub repaired_walls: [u8; REPAIRED_WALL_BYTES],
+
+    pub bump: u8,
+}
+
+impl FloorConnectivity {
+    pub const SEED_PREFIX: &'static [u8] = b"floor_connectivity";
+
+    /// Run the season analysis for this floor unless an earlier discovery already did.
+    pub fn ensure_analyzed(&mut self, season_seed: u64, floor: u8, grid: DungeonGrid, bump: u8) {
+        if self.is_analyzed && self.season_seed == season_seed && self.floor == floor {
+            return;
+        }
+        let connectivity = analyze_season_connectivity(floor_season_seed(season_seed, floor), grid);
+        self.record(season_seed, floor, grid, &connectivity);
+        self.bump = bump;
+    }
+
+    pub fn record(
+        &mut self,
+        season_seed: u64,
+        floor: u8,
+        grid: DungeonGrid,
+        connectivity: &SeasonConnectivity,
+    ) {
+        self.season_seed = season_seed;
+        self.floor = floor;
+        self.reachable_without_repairs = connectivity.reachable_without_repairs as u16;
+        self.repair_count = connectivity.repairs.len() as u16;
+        self.repaired_walls = [0; REPAIRED_WALL_BYTES];
+        for repair in connectivity.repairs.iter() {
+            let index = room_index(grid, repair.x, repair.y);
+            self.repaired_walls[index / 2] |= (1 << repair.direction) << (4 * (index % 2));
+        }
+        self.is_analyzed = true;
+    }
+
+    /// Directions of this room whose Solid roll must become Rubble.
+    pub fn repaired_directions(&self, grid: DungeonGrid, room_x: i8, room_y: i8) -> [bool; 4] {
+        let index = room_index(grid, room_x, room_y);
+        let nibble = (self.repaired_walls[index / 2] >> (4 * (index % 2))) & 0x0F;
+        [0u8, 1, 2, 3].map(|direction| nibble & (1 << direction) != 0)
+    }
+}
+
+/// A rolled Solid wall that generation turns into Rubble to reconnect the map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WallRepair {
+    pub x: i8,
+    pub y: i8,
+    pub direction: u8,
+}
+
+/// Season-wide reachability of the room grid from the start room.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeasonConnectivity {
+    pub total_rooms: u32,
+    /// Rooms a player could reach with the raw wall rolls (keys assumed available)
+    pub reachable_without_repairs: u32,
+    /// Walls forced to Rubble, in the order they were chosen
+    pub repairs: Vec<WallRepair>,
+}
+
+impl SeasonConnectivity {
+    pub fn is_fully_connected_without_repairs(&self) -> bool {
+        self.reachable_without_repairs == self.total_rooms
+    }
+
+    /// Directions of this room whose Solid roll must become Rubble.
+    pub fn repaired_directions(&self, room_x: i8, room_y: i8) -> [bool; 4] {
+        let mut directions = [false; 4];
+        for repair in self.repairs.iter() {
+            if repair.x == room_x && repair.y == room_y {
+                directions[repair.direction as usize] = true;
+            }
+        }
+        directions
+    }
+}
+
+/// Walls that can eventually be crossed: Rubble is cleared by a job and locks
+/// are opened with keys from the forced key chests.
+pub fn is_passable_wall(wall: u8) -> bool {
+    wall == WALL_OPEN || wall == WALL_RUBBLE || wall == WALL_LOCKED
+}
+
+/// Walls of a room before its entrance side is opened and before locks are placed.
+/// Discovery only ever opens extra walls, so reachability over these walls is a lower
+/// bound that does not depend on the order rooms are discovered in.
+pub fn season_base_walls(season_seed: u64, grid: DungeonGrid, room_x: i8, room_y: i8) -> [u8; 4] {
+    if calculate_depth(grid, room_x, room_y) == 0 {
+        return RoomAccount::generate_start_walls(season_seed, grid, room_x, room_y);
+    }
+
+    let mut walls = generate_walls(generate_room_hash(season_seed, room_x, room_y), NO_ENTRANCE);
+    RoomAccount::clamp_boundary_walls(&mut walls, grid, room_x, room_y);
+    for direction in 0..=DIRECTION_WEST {
+        if is_start_approach_wall(grid, room_x, room_y, direction) {
+            walls[direction as usize] = WALL_SOLID;
+        }
+    }
+    walls
+}
+
+/// Flood fill from the start room over `season_base_walls`. Whenever the fill stalls
+/// before covering the grid, the first reached room (in x, y scan order) with a Solid
+/// wall into an unreached neighbor gets that wall repaired, and the fill continues.
+pub fn analyze_season_connectivity(season_seed: u64, grid: DungeonGrid) -> SeasonConnectivity {
+    let grid_rooms = grid.room_count();
+    let mut walls = Vec::<[u8; 4]>::with_capacity(grid_rooms);
+    for index in 0..grid_rooms {
+        let (room_x, room_y) = room_coords(grid, index);
+        walls.push(season_base_walls(season_seed, grid, room_x, room_y));
+    }
+
+    let mut reached = vec![false; grid_rooms];
+    let mut queue = Vec::<usize>::with_capacity(grid_rooms);
+    let start_index = room_index(grid, grid.start_x, grid.start_y);
+    reached[start_index] = true;
+    queue.push(start_index);
+
+    let mut head = 0usize;
+    let mut reachable_without_repairs = None;
+    let mut repairs = Vec::<WallRepair>::new();
+    loop {
+        while head < queue.len() {
+            let index = queue[head];
+            head += 1;
+            for direction in 0..=DIRECTION_WEST {
+                if !is_passable_wall(walls[index][direction as usize]) {
+                    continue;
+                }
+                if let Some(neighbor) = neighbor_index(grid, index, direction) {
+                    if !reached[neighbor] {
+                        reached[neighbor] = true;
+                        queue.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        if queue.len() == grid_rooms {
+            break;
+        }
+        reachable_without_repairs.get_or_insert(queue.len());
+
+        let Some((index, direction, neighbor)) = find_repair(grid, &walls, &reached) else {
+            break;
+        };
+        walls[index][direction as usize] = WALL_RUBBLE;
+        let (room_x, room_y) = room_coords(grid, index);
+        repairs.push(WallRepair {
+            x: room_x,
+            y: room_y,
+            direction,
+        });
+        reached[neighbor] = true;
+        queue.push(neighbor);
+    }
+
+    SeasonConnectivity {
+        total_rooms: grid_rooms as u32,
+        reachable_without_repairs: reachable_without_repairs.unwrap_or(queue.len()) as u32,
+        repairs,
+    }
+}
+
+/// Turn this room's repaired Solid walls into Rubble.
+pub fn apply_connectivity_repairs(walls: &mut [u8; 4], repaired_directions: [bool; 4]) {
+    for (wall, repaired) in walls.iter_mut().zip(repaired_directions.iter()) {
+        if *repaired && *wall == WALL_SOLID {
+            *wall = WALL_RUBBLE;
+        }
+    }
+}
+
+fn find_repair(
+    grid: DungeonGrid,
+    walls: &[[u8; 4]],
+    reached: &[bool],
+) -> Option<(usize, u8, usize)> {
+    for index in 0..grid.room_count() {
+        if !reached[index] {
+            continue;
+        }
+        for direction in 0..=DIRECTION_WEST {
+            if walls[index][direction as usize] != WALL_SOLID {
+                continue;
+            }
+            let (room_x, room_y) = room_coords(grid, index);
+            if is_start_approach_wall(grid, room_x, room_y, direction) {
+                continue;
+            }
+            if let Some(neighbor) = neighbor_index(grid, index, direction) {
+                if !reached[neighbor] {
+                    return Some((index, direction, neighbor));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn room_index(grid: DungeonGrid, room_x: i8, room_y: i8) -> usize {
+    let column = (room_x - GlobalAccount::MIN_COORD) as usize;
+    let row = (room_y - GlobalAccount::MIN_COORD) as usize;
+    column * usize::from(grid.height) + row
+}
+
+fn room_coords(grid: DungeonGrid, index: usize) -> (i8, i8) {
+    let height = usize::from(grid.height);
+    (
+        GlobalAccount::MIN_COORD + (index / height) as i8,
+        GlobalAccount::MIN_COORD + (index % height) as i8,
+    )
+}
+
+fn neighbor_index(grid: DungeonGrid, index: usize, direction: u8) -> Option<usize> {
+    let (room_x, room_y) = room_coords(grid, index);
+    let (neighbor_x, neighbor_y) = RoomAccount::adjacent_coords(room_x, room_y, direction);
+    if !grid.contains(neighbor_x, neighbor_y) {
+        return None;
+    }
+    Some(room_index(grid, neighbor_x, neighbor_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{generate_discovered_walls, select_forced_key_chests, RoomLocation};
+
+    const SEED_COUNT: u64 = 300;
+    const GRID: DungeonGrid = DungeonGrid::LEGACY;
+    const SMALL_GRID: DungeonGrid = DungeonGrid {
+        width: 9,
+        height: 7,
+        start_x: 3,
+        start_y: 3,
+    };
+
+    /// Explore the season the way players do: walk through every passable wall and
+    /// discover the neighbor with its real generated walls (entrance side opened).
+    fn explore_season(
+        season_seed: u64,
+        grid: DungeonGrid,
+        connectivity: &SeasonConnectivity,
+    ) -> Vec<bool> {
+        let mut discovered = vec![false; grid.room_count()];
+        let mut room_walls = vec![[WALL_SOLID; 4]; grid.room_count()];
+        let start_index = room_index(grid, grid.start_x, grid.start_y);
+        discovered[start_index] = true;
+        room_walls[start_index] =
+            RoomAccount::generate_start_walls(season_seed, grid, grid.start_x, grid.start_y);
+
+        let mut queue = vec![start_index];
+        while let Some(index) = queue.pop() {
+            for direction in 0..=DIRECTION_WEST {
+                if !is_passable_wall(room_walls[index][direction as usize]) {
+                    continue;
+                }
+                let Some(neighbor) = neighbor_index(grid, index, direction) else {
+                    continue;
+                };
+                if discovered[neighbor] {
+                    continue;
+                }
+                let (room_x, room_y) = room_coords(grid, neighbor);
+                let (walls, _) = generate_discovered_walls(
+                    RoomLocation::new(season_seed, grid, 0, room_x, room_y),
+                    RoomAccount::opposite_direction(direction),
+                    connectivity.repaired_directions(room_x, room_y),
+                );
+                discovered[neighbor] = true;
+                room_walls[neighbor] = walls;
+                queue.push(neighbor);
+            }
+        }
+        discovered
+    }
+
+    fn season_seeds() -> impl Iterator<Item = u64> {
+        (1..=SEED_COUNT).chain([u64::MAX, 0xDEAD_BEEF_CAFE_F00D, 1 << 63])
+    }
+
+    #[test]
+    fn repaired_seasons_reach_every_room_and_forced_key_chest() {
+        for grid in [GRID, SMALL_GRID] {
+            for seed in season_seeds() {
+                let connectivity = analyze_season_connectivity(seed, grid);
+                let discovered = explore_season(seed, grid, &connectivity);
+                assert!(
+                    discovered.iter().all(|reached| *reached),
+                    "Seed {seed} leaves rooms of {grid:?} unreachable after repair"
+                );
+
+                for depth in 2..=grid.max_ring() {
+                    for (x, y) in select_forced_key_chests(seed, grid, depth).into_iter().flatten() {
+                        assert!(discovered[room_index(grid, x, y)]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn repairs_only_fix_dead_end_seeds() {
+        let mut repaired_seeds = 0u32;
+        for seed in season_seeds() {
+            let connectivity = analyze_season_connectivity(seed, GRID);
+            let unrepaired = explore_season(seed, GRID, &SeasonConnectivity::default());
+            let unrepaired_count = unrepaired.iter().filter(|reached| **reached).count() as u32;
+
+            // Real exploration can only do better than the order-independent lower bound.
+            assert!(unrepaired_count >= connectivity.reachable_without_repairs);
+            if connectivity.is_fully_connected_without_repairs() {
+                assert!(connectivity.repairs.is_empty());
+                continue;
+            }
+
+            repaired_seeds += 1;
+            assert!(!connectivity.repairs.is_empty());
+            assert!(
+                connectivity.repairs.len() as u32
+                    <= connectivity.total_rooms - connectivity.reachable_without_repairs
+            );
+            for repair in connectivity.repairs.iter() {
+                let walls = season_base_walls(seed, GRID, repair.x, repair.y);
+                assert_eq!(walls[repair.direction as usize], WALL_SOLID);
+            }
+        }
+
+        assert!(repaired_seeds > 0, "No seed exercised the repair path");
+    }
+
+    #[test]
+    fn connectivity_analysis_is_deterministic() {
+        for seed in [3u64, 77, 4096, u64::MAX] {
+            assert_eq!(
+                analyze_season_connectivity(seed, GRID),
+                analyze_season_connectivity(seed, GRID)
+            );
+        }
+    }
+
+    #[test]
+    fn floor_connectivity_stores_every_repair() {
+        for grid in [GRID, SMALL_GRID] {
+            for seed in season_seeds().take(60) {
+                let connectivity = analyze_season_connectivity(seed, grid);
+                let mut stored = FloorConnectivity {
+                    season_seed: 0,
+                    floor: 0,
+                    is_analyzed: false,
+                    reachable_without_repairs: 0,
+                    repair_count: 0,
+                    repaired_walls: [0; REPAIRED_WALL_BYTES],
+                    bump: 0,
+                };
+                stored.ensure_analyzed(seed, 0, grid, 7);
+                assert!(stored.is_analyzed);
+                assert_eq!(usize::from(stored.repair_count), connectivity.repairs.len());
+                for x in GlobalAccount::MIN_COORD..=grid.max_x() {
+                    for y in GlobalAccount::MIN_COORD..=grid.max_y() {
+                        assert_eq!(
+                            stored.repaired_directions(grid, x, y),
+                            connectivity.repaired_directions(x, y)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_grid_fits_the_repair_nibbles() {
+        let grid = DungeonGrid {
+            width: DungeonGrid::MAX_SIZE,
+            height: DungeonGrid::MAX_SIZE,
+            start_x: 10,
+            start_y: 10,
+        };
+        assert_eq!(grid.room_count(), MAX_GRID_ROOMS);
+        let last = room_index(grid, grid.max_x(), grid.max_y());
+        assert!(last / 2 < REPAIRED_WALL_BYTES);
+    }
+
+    #[test]
+    fn repairs_never_open_the_start_approach_wall() {
+        for seed in season_seeds() {
+            for repair in analyze_season_connectivity(seed, GRID).repairs {
+                assert!(!is_start_approach_wall(GRID, repair.x, repair.y, repair.direction));
+            }
+        }
+    }
+}
@@ -1,33 +1,172 @@
 use anchor_lang::prelude::*;
 
 use super::{
-    GlobalAccount, RoomAccount, CENTER_BONE_CHEST, CENTER_BOSS, CENTER_CHEST, CENTER_EMPTY,
-    CENTER_GILDED_CHEST, CENTER_SARCOPHAGUS_CHEST, DIRECTION_NORTH, DIRECTION_WEST,
-    LOCK_KIND_NONE, LOCK_KIND_SKELETON, WALL_LOCKED, WALL_OPEN, WALL_RUBBLE, WALL_SOLID,
+    apply_connectivity_repairs, lock_requirement, DungeonGrid, GlobalAccount, RoomAccount,
+    BIOME_ABYSS, BIOME_CATACOMBS, BIOME_CRYPT, BIOME_UNDERDARK, CENTER_BONE_CHEST, CENTER_BOSS,
+    CENTER_CHEST, CENTER_EMPTY, CENTER_EXTRACTION_STAIRS, CENTER_GILDED_CHEST, CENTER_MERCHANT,
+    CENTER_SARCOPHAGUS_CHEST, CENTER_SHRINE, CENTER_STAIRS_DOWN, CENTER_TRAP, DIRECTION_NORTH,
+    DIRECTION_WEST, ELITE_ENRAGE, ELITE_REGENERATING, ELITE_SHIELDED, LOCK_KIND_GOLD,
+    LOCK_KIND_GOLD_COOP, LOCK_KIND_NONE, LOCK_KIND_RUNE, LOCK_KIND_SKELETON, SHRINE_FREE,
+    SHRINE_TRIBUTE, WALL_LOCKED, WALL_OPEN, WALL_RUBBLE, WALL_SOLID,
 };
 
 const LOCK_MIN_DEPTH: u32 = 2;
+const TRAP_MIN_DEPTH: u32 = 2;
+const SHRINE_MIN_DEPTH: u32 = 2;
+const SHRINE_TRIBUTE_CHANCE_PERCENT: u64 = 50;
+const MERCHANT_MIN_DEPTH: u32 = 3;
 const MAX_LOCKED_DOORS_PER_ROOM: usize = 1;
-const FORCED_KEY_CHEST_MIN_DEPTH: u32 = 2;
+pub const FORCED_KEY_CHEST_MIN_DEPTH: u32 = 2;
+pub const GOLD_LOCK_MIN_DEPTH: u32 = 4;
+pub const COOP_LOCK_MIN_DEPTH: u32 = 5;
+pub const RUNE_LOCK_MIN_DEPTH: u32 = 6;
+// Lock kind roll (out of 100), checked in order; anything above falls back to skeleton.
+const RUNE_LOCK_ROLL_CUTOFF: u64 = 20;
+const COOP_LOCK_ROLL_CUTOFF: u64 = 30;
+const GOLD_LOCK_ROLL_CUTOFF: u64 = 55;
+/// Lock kinds that get a guaranteed key chest in every eligible depth ring, in selection order.
+pub const FORCED_KEY_LOCK_KINDS: [u8; 3] = [LOCK_KIND_SKELETON, LOCK_KIND_GOLD, LOCK_KIND_RUNE];
 const BONE_ROOM_MIN_DEPTH: u32 = 2;
 const BONE_ROOM_CHANCE_PERCENT: u64 = 18;
+pub const EXTRACTION_ROOM_MIN_DEPTH: u32 = 4;
+const EXTRACTION_ROOM_CHANCE_PERCENT: u64 = 5;
 const WALL_ROLL_SIDES: u64 = 100;
 const WALL_RUBBLE_THRESHOLD: u64 = 65;
 const WALL_SOLID_THRESHOLD: u64 = 80;
 const ROOM_CENTER_ROLL_SIDES: u64 = 100;
 const ROOM_CENTER_DEPTH_ONE_CHEST_THRESHOLD: u64 = 50;
-const ROOM_CENTER_BOSS_WEIGHT: u64 = 50;
-const ROOM_CENTER_CHEST_WEIGHT: u64 = 25;
+/// Gilded and sarcophagus chests only roll from this depth on.
+const TIERED_CHEST_MIN_DEPTH: u32 = 3;
+/// Empty share every biome keeps; trap, shrine and merchant rooms come out of bosses and chests.
 const ROOM_CENTER_EMPTY_WEIGHT: u64 = 25;
-const DEPTH_THREE_PLUS_BASIC_CHEST_WEIGHT: u64 = 70;
-const DEPTH_THREE_PLUS_GILDED_CHEST_WEIGHT: u64 = 20;
-const DEPTH_THREE_PLUS_SARCOPHAGUS_CHEST_WEIGHT: u64 = 10;
+const CHEST_TIER_ROLL_SIDES: u64 = 100;
 const STANDARD_BOSS_VARIANTS: i16 = 4;
+const ELITE_MODIFIERS: [u8; 3] = [ELITE_REGENERATING, ELITE_ENRAGE, ELITE_SHIELDED];
+
+/// Generation tables for one depth band. Center weights are out of
+/// `ROOM_CENTER_ROLL_SIDES`, chest tier weights out of `CHEST_TIER_ROLL_SIDES`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BiomeProfile {
+    pub id: u8,
+    /// First depth of the band; the band runs until the next biome's `min_depth`.
+    pub min_depth: u32,
+    pub boss_weight: u64,
+    pub chest_weight: u64,
+    pub trap_weight: u64,
+    pub shrine_weight: u64,
+    pub merchant_weight: u64,
+    pub empty_weight: u64,
+    /// Basic, gilded and sarcophagus shares of rolled chests (from `TIERED_CHEST_MIN_DEPTH`).
+    pub chest_tier_weights: [u64; 3],
+    /// Standard boss ids. Slot `k` only ever holds `k + 1` or `k + 5` in every biome, so
+    /// the linear slot mapping keeps orthogonal neighbors distinct across biome borders too.
+    pub boss_pool: [u16; STANDARD_BOSS_VARIANTS as usize],
+    /// Percent of standard bosses that roll an elite modifier.
+    pub elite_chance: u64,
+}
+
+/// Biomes ordered by `min_depth`.
+pub const BIOMES: [BiomeProfile; 4] = [
+    BiomeProfile {
+        id: BIOME_CRYPT,
+        min_depth: 0,
+        boss_weight: 40,
+        chest_weight: 17,
+        trap_weight: 10,
+        shrine_weight: 5,
+        merchant_weight: 3,
+        empty_weight: ROOM_CENTER_EMPTY_WEIGHT,
+        chest_tier_weights: [70, 20, 10],
+        boss_pool: [1, 2, 3, 4],
+        elite_chance: 0,
+    },
+    BiomeProfile {
+        id: BIOME_CATACOMBS,
+        min_depth: 4,
+        boss_weight: 36,
+        chest_weight: 17,
+        trap_weight: 11,
+        shrine_weight: 6,
+        merchant_weight: 5,
+        empty_weight: ROOM_CENTER_EMPTY_WEIGHT,
+        chest_tier_weights: [60, 25, 15],
+        boss_pool: [1, 6, 3, 8],
+        elite_chance: 10,
+    },
+    BiomeProfile {
+        id: BIOME_ABYSS,
+        min_depth: 8,
+        boss_weight: 40,
+        chest_weight: 15,
+        trap_weight: 12,
+        shrine_weight: 4,
+        merchant_weight: 4,
+        empty_weight: ROOM_CENTER_EMPTY_WEIGHT,
+        chest_tier_weights: [45, 35, 20],
+        boss_pool: [5, 6, 7, 8],
+        elite_chance: 20,
+    },
+    BiomeProfile {
+        id: BIOME_UNDERDARK,
+        min_depth: FLOOR_DEPTH_STRIDE,
+        boss_weight: 43,
+        chest_weight: 14,
+        trap_weight: 12,
+        shrine_weight: 3,
+        merchant_weight: 3,
+        empty_weight: ROOM_CENTER_EMPTY_WEIGHT,
+        chest_tier_weights: [30, 40, 30],
+        boss_pool: [5, 6, 7, 8],
+        elite_chance: 30,
+    },
+];
+
+pub fn biome_for_depth(depth: u32) -> &'static BiomeProfile {
+    BIOMES
+        .iter()
+        .rev()
+        .find(|biome| depth >= biome.min_depth)
+        .unwrap_or(&BIOMES[0])
+}
+/// Depth added per floor: one more than the widest ring any valid grid allows,
+/// so rings never overlap.
+pub const FLOOR_DEPTH_STRIDE: u32 = 11;
+
+pub fn calculate_depth(grid: DungeonGrid, x: i8, y: i8) -> u32 {
+    grid.ring(x, y)
+}
+
+/// Stairs down sit on the outermost complete ring of the grid.
+pub fn stairs_down_ring(grid: DungeonGrid) -> u32 {
+    grid.outer_complete_ring()
+}
+
+/// Depth used for generation and scaling: rings continue floor after floor,
+/// so floor 1's arrival room sits one step deeper than floor 0's widest ring.
+pub fn floor_depth(grid: DungeonGrid, floor: u8, x: i8, y: i8) -> u32 {
+    u32::from(floor) * FLOOR_DEPTH_STRIDE + calculate_depth(grid, x, y)
+}
 
-pub fn calculate_depth(x: i8, y: i8) -> u32 {
-    let dx = (x - GlobalAccount::START_X).abs() as u32;
-    let dy = (y - GlobalAccount::START_Y).abs() as u32;
-    dx.max(dy)
+pub fn floor_for_depth(depth: u32) -> u8 {
+    (depth / FLOOR_DEPTH_STRIDE) as u8
+}
+
+fn ring_for_depth(depth: u32) -> u32 {
+    depth % FLOOR_DEPTH_STRIDE
+}
+
+/// Depth of another room on the same floor as a room at `depth`.
+fn depth_on_same_floor(grid: DungeonGrid, depth: u32, x: i8, y: i8) -> u32 {
+    depth - ring_for_depth(depth) + calculate_depth(grid, x, y)
+}
+
+/// Seed every generation roll uses for a floor. Floor 0 keeps the season seed,
+/// so its layout is unchanged; deeper floors get their own layout.
+pub fn floor_season_seed(season_seed: u64, floor: u8) -> u64 {
+    if floor == 0 {
+        return season_seed;
+    }
+    season_seed ^ u64::from(floor).wrapping_mul(0x9E37_79B9_7F4A_7C15)
 }
 
 pub fn generate_room_hash(seed: u64, x: i8, y: i8) -> u64 {
@@ -59,12 +198,18 @@ pub fn generate_walls(hash: u64, entrance_direction: u8) -> [u8; 4] {
     walls
 }
 
-pub fn generate_room_center(season_seed: u64, room_x: i8, room_y: i8, depth: u32) -> (u8, u16, bool) {
+pub fn generate_room_center(
+    season_seed: u64,
+    grid: DungeonGrid,
+    room_x: i8,
+    room_y: i8,
+    depth: u32,
+) -> (u8, u16, bool) {
     let room_hash = generate_room_hash(season_seed, room_x, room_y);
-    let forced_key_drop = is_forced_key_chest(season_seed, room_x, room_y, depth);
+    let forced_key_drop = is_forced_key_chest(season_seed, grid, room_x, room_y, depth);
 
     if depth == 1 {
-        if is_forced_depth_one_chest(season_seed, room_x, room_y)
+        if is_forced_depth_one_chest(season_seed, grid, room_x, room_y)
             || (room_hash % ROOM_CENTER_ROLL_SIDES) < ROOM_CENTER_DEPTH_ONE_CHEST_THRESHOLD
         {
             return (CENTER_CHEST, 1, false);
@@ -72,6 +217,10 @@ pub fn generate_room_center(season_seed: u64, room_x: i8, room_y: i8, depth: u32
         return (CENTER_EMPTY, 0, false);
     }
 
+    if is_stairs_down_room(season_seed, grid, room_x, room_y, depth) {
+        return (CENTER_STAIRS_DOWN, 0, false);
+    }
+
     if is_bone_room(season_seed, room_x, room_y, depth) {
         // Bone rooms are always special centers: either skeleton boss #11 or a bone chest.
         let bone_hash = generate_room_hash(season_seed ^ 0xB0DE_CAFE_BEEF_D00D, room_x, room_y);
@@ -88,25 +237,33 @@ pub fn generate_room_center(season_seed: u64, room_x: i8, room_y: i8, depth: u32
         return (CENTER_CHEST, 1, true);
     }
 
+    if is_extraction_room(season_seed, grid, room_x, room_y, depth) {
+        return (CENTER_EXTRACTION_STAIRS, 0, false);
+    }
+
+    let biome = biome_for_depth(depth);
     let center_roll = room_hash % ROOM_CENTER_ROLL_SIDES;
-    let chest_cutoff = ROOM_CENTER_BOSS_WEIGHT + ROOM_CENTER_CHEST_WEIGHT;
-    let empty_cutoff = chest_cutoff + ROOM_CENTER_EMPTY_WEIGHT;
-    if center_roll < ROOM_CENTER_BOSS_WEIGHT {
-        let boss_id = select_standard_boss_id(season_seed, room_x, room_y);
+    let chest_cutoff = biome.boss_weight + biome.chest_weight;
+    let trap_cutoff = chest_cutoff + biome.trap_weight;
+    let shrine_cutoff = trap_cutoff + biome.shrine_weight;
+    let merchant_cutoff = shrine_cutoff + biome.merchant_weight;
+    let empty_cutoff = merchant_cutoff + biome.empty_weight;
+    if center_roll < biome.boss_weight {
+        let boss_id = select_standard_boss_id(season_seed, biome, room_x, room_y);
         return (CENTER_BOSS, boss_id, false);
     }
 
     if center_roll < chest_cutoff {
-        if depth >= 3 {
+        if depth >= TIERED_CHEST_MIN_DEPTH {
             let chest_roll = generate_room_hash(
                 season_seed ^ 0xA5A5_5A5A_F0F0_0F0F,
                 room_x,
                 room_y,
-            ) % ROOM_CENTER_ROLL_SIDES;
-            let gilded_cutoff =
-                DEPTH_THREE_PLUS_BASIC_CHEST_WEIGHT + DEPTH_THREE_PLUS_GILDED_CHEST_WEIGHT;
-            let sarcophagus_cutoff = gilded_cutoff + DEPTH_THREE_PLUS_SARCOPHAGUS_CHEST_WEIGHT;
-            if chest_roll < DEPTH_THREE_PLUS_BASIC_CHEST_WEIGHT {
+            ) % CHEST_TIER_ROLL_SIDES;
+            let [basic_weight, gilded_weight, sarcophagus_weight] = biome.chest_tier_weights;
+            let gilded_cutoff = basic_weight + gilded_weight;
+            let sarcophagus_cutoff = gilded_cutoff + sarcophagus_weight;
+            if chest_roll < basic_weight {
                 return (CENTER_CHEST, 1, false);
             }
 
@@ -124,6 +281,19 @@ pub fn generate_room_center(season_seed: u64, room_x: i8, room_y: i8, depth: u32
         return (CENTER_CHEST, 1, false);
     }
 
+    if center_roll < trap_cutoff && depth >= TRAP_MIN_DEPTH {
+        return (CENTER_TRAP, select_trap_variant(season_seed, room_x, room_y), false);
+    }
+
+    if center_roll >= trap_cutoff && center_roll < shrine_cutoff && depth >= SHRINE_MIN_DEPTH {
+        return (CENTER_SHRINE, select_shrine_variant(season_seed, room_x, room_y), false);
+    }
+
+    if center_roll >= shrine_cutoff && center_roll < merchant_cutoff && depth >= MERCHANT_MIN_DEPTH
+    {
+        return (CENTER_MERCHANT, 0, false);
+    }
+
     if center_roll < empty_cutoff {
         return (CENTER_EMPTY, 0, false);
     }
@@ -131,52 +301,101 @@ pub fn generate_room_center(season_seed: u64, room_x: i8, room_y: i8, depth: u32
     (CENTER_EMPTY, 0, false)
 }
 
-fn select_standard_boss_id(season_seed: u64, room_x: i8, room_y: i8) -> u16 {
-    // Linear coordinate mapping guarantees orthogonal neighbors always differ.
+fn select_standard_boss_id(
+    season_seed: u64,
+    biome: &BiomeProfile,
+    room_x: i8,
+    room_y: i8,
+) -> u16 {
+    // Linear coordinate mapping guarantees orthogonal neighbors always land in different slots.
     let season_offset = (season_seed % STANDARD_BOSS_VARIANTS as u64) as i16;
     let linear_value = i16::from(room_x) + (i16::from(room_y) * 3) + season_offset;
     let variant_index = linear_value.rem_euclid(STANDARD_BOSS_VARIANTS);
-    (variant_index as u16) + 1
+    biome.boss_pool[variant_index as usize]
+}
+
+/// Elite modifier bits for a standard boss room; 0 for regular bosses.
+pub fn select_elite_modifiers(season_seed: u64, room_x: i8, room_y: i8, depth: u32) -> u8 {
+    let elite_hash = generate_room_hash(season_seed ^ 0xE117_E000_B055_0001, room_x, room_y);
+    if elite_hash % ROOM_CENTER_ROLL_SIDES >= biome_for_depth(depth).elite_chance {
+        return 0;
+    }
+    let modifier_index = (elite_hash / ROOM_CENTER_ROLL_SIDES) % ELITE_MODIFIERS.len() as u64;
+    ELITE_MODIFIERS[modifier_index as usize]
+}
+
+fn select_trap_variant(season_seed: u64, room_x: i8, room_y: i8) -> u16 {
+    let trap_hash = generate_room_hash(season_seed ^ 0x7A4B_7A4B_7A4B_7A4B, room_x, room_y);
+    (trap_hash % u64::from(RoomAccount::TRAP_VARIANTS)) as u16 + 1
+}
+
+fn select_shrine_variant(season_seed: u64, room_x: i8, room_y: i8) -> u16 {
+    let shrine_hash = generate_room_hash(season_seed ^ 0x5A12_5A12_5A12_5A12, room_x, room_y);
+    if (shrine_hash % 100) < SHRINE_TRIBUTE_CHANCE_PERCENT {
+        SHRINE_TRIBUTE
+    } else {
+        SHRINE_FREE
+    }
+}
+
+/// Where a room sits in a season: the season seed, the grid it is generated on, its floor
+/// and its coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoomLocation {
+    pub season_seed: u64,
+    pub grid: DungeonGrid,
+    pub floor: u8,
+    pub x: i8,
+    pub y: i8,
+}
+
+impl RoomLocation {
+    pub fn new(season_seed: u64, grid: DungeonGrid, floor: u8, x: i8, y: i8) -> Self {
+        Self {
+            season_seed,
+            grid,
+            floor,
+            x,
+            y,
+        }
+    }
+
+    /// Seed the room's rolls use, mixed with its floor.
+    pub fn generation_seed(&self) -> u64 {
+        floor_season_seed(self.season_seed, self.floor)
+    }
+
+    pub fn depth(&self) -> u32 {
+        floor_depth(self.grid, self.floor, self.x, self.y)
+    }
 }
 
 pub fn initialize_discovered_room(
     room: &mut RoomAccount,
-    season_seed: u64,
-    room_x: i8,
-    room_y: i8,
+    location: RoomLocation,
     entrance_direction: u8,
+    repaired_directions: [bool; 4],
     created_by: Pubkey,
     created_slot: u64,
     bump: u8,
 ) {
-    let room_depth = calculate_depth(room_x, room_y);
-    let room_hash = generate_room_hash(season_seed, room_x, room_y);
+    let RoomLocation {
+        season_seed,
+        grid,
+        floor,
+        x: room_x,
+        y: room_y,
+    } = location;
+    let generation_seed = location.generation_seed();
+    let room_depth = location.depth();
 
     room.x = room_x;
     room.y = room_y;
     room.season_seed = season_seed;
-    room.walls = generate_walls(room_hash, entrance_direction);
-    RoomAccount::clamp_boundary_walls(&mut room.walls, room_x, room_y);
-    room.door_lock_kinds = [LOCK_KIND_NONE; 4];
-    apply_locked_doors(
-        &mut room.walls,
-        &mut room.door_lock_kinds,
-        season_seed,
-        room_x,
-        room_y,
-        room_depth,
-        entrance_direction,
-    );
-    apply_bone_room_locks(
-        &mut room.walls,
-        &mut room.door_lock_kinds,
-        season_seed,
-        room_x,
-        room_y,
-        room_depth,
-        entrance_direction,
-    );
-    enforce_special_room_topology(room);
+    room.floor = floor;
+    room.biome_id = biome_for_depth(room_depth).id;
+    (room.walls, room.door_lock_kinds) =
+        generate_discovered_walls(location, entrance_direction, repaired_directions);
     room.helper_counts = [0; 4];
     room.progress = [0; 4];
     room.start_slot = [0; 4];
@@ -186,12 +405,17 @@ pub fn initialize_discovered_room(
     room.bonus_per_helper = [0; 4];
 
     let (center_type, center_id, forced_key_drop) =
-        generate_room_center(season_seed, room_x, room_y, room_depth);
+        generate_room_center(generation_seed, grid, room_x, room_y, room_depth);
     let boss_max_hp = if center_type == CENTER_BOSS {
         RoomAccount::boss_hp_for_depth(room_depth, center_id)
     } else {
         0
     };
+    let boss_modifiers = if center_type == CENTER_BOSS && center_id != RoomAccount::BONE_BOSS_ID {
+        select_elite_modifiers(generation_seed, room_x, room_y, room_depth)
+    } else {
+        0
+    };
 
     room.has_chest = center_type == CENTER_CHEST
         || center_type == CENTER_BONE_CHEST
@@ -206,12 +430,56 @@ pub fn initialize_discovered_room(
     room.boss_total_dps = 0;
     room.boss_fighter_count = 0;
     room.boss_defeated = false;
+    room.boss_modifiers = boss_modifiers;
+    room.boss_engaged_slot = 0;
+    room.trap_disarmed = false;
     room.looted_count = 0;
     room.created_by = created_by;
     room.created_slot = created_slot;
     room.bump = bump;
 }
 
+/// Walls and lock kinds of a newly discovered room, including connectivity repairs.
+/// `repaired_directions` come from this floor's `FloorConnectivity`.
+pub fn generate_discovered_walls(
+    location: RoomLocation,
+    entrance_direction: u8,
+    repaired_directions: [bool; 4],
+) -> ([u8; 4], [u8; 4]) {
+    let RoomLocation {
+        grid,
+        x: room_x,
+        y: room_y,
+        ..
+    } = location;
+    let season_seed = location.generation_seed();
+    let room_depth = location.depth();
+    let room_hash = generate_room_hash(season_seed, room_x, room_y);
+
+    let mut walls = generate_walls(room_hash, entrance_direction);
+    RoomAccount::clamp_boundary_walls(&mut walls, grid, room_x, room_y);
+    // Repairs land before locks so a reopened wall can still roll a lock like any rubble.
+    apply_connectivity_repairs(&mut walls, repaired_directions);
+    let mut door_lock_kinds = [LOCK_KIND_NONE; 4];
+    apply_locked_doors(
+        &mut walls,
+        &mut door_lock_kinds,
+        season_seed,
+        room_x,
+        room_y,
+        room_depth,
+        entrance_direction,
+    );
+    apply_bone_room_locks(
+        &mut walls,
+        &mut door_lock_kinds,
+        location,
+        entrance_direction,
+    );
+    enforce_special_wall_topology(&mut walls, &mut door_lock_kinds, grid, room_x, room_y);
+    (walls, door_lock_kinds)
+}
+
 pub fn is_bone_room(season_seed: u64, room_x: i8, room_y: i8, depth: u32) -> bool {
     if depth < BONE_ROOM_MIN_DEPTH {
         return false;
@@ -228,15 +496,65 @@ pub fn is_bone_room(season_seed: u64, room_x: i8, room_y: i8, depth: u32) -> boo
     (room_hash % 100) < BONE_ROOM_CHANCE_PERCENT
 }
 
-pub fn enforce_special_room_topology(room: &mut RoomAccount) {
-    // Reserve start-room south edge for entrance stairs/extraction only:
-    // room (START_X, START_Y - 1) north wall must never be passable or lockable.
-    if room.x == GlobalAccount::START_X && room.y == GlobalAccount::START_Y - 1 {
-        room.walls[DIRECTION_NORTH as usize] = WALL_SOLID;
-        room.door_lock_kinds[DIRECTION_NORTH as usize] = LOCK_KIND_NONE;
+pub fn is_extraction_room(
+    season_seed: u64,
+    grid: DungeonGrid,
+    room_x: i8,
+    room_y: i8,
+    depth: u32,
+) -> bool {
+    if depth < EXTRACTION_ROOM_MIN_DEPTH {
+        return false;
+    }
+
+    // Lattice gate: candidates sit on every other column and row, so two extraction
+    // rooms never touch (not even diagonally). The lattice parity is chosen opposite
+    // to the bone-room parity so the two special rooms never share a coordinate.
+    let lattice_x = (season_seed >> 1) & 1;
+    let lattice_y = (lattice_x + (season_seed & 1) + 1) % 2;
+    if i16::from(room_x).rem_euclid(2) as u64 != lattice_x
+        || i16::from(room_y).rem_euclid(2) as u64 != lattice_y
+    {
+        return false;
+    }
+
+    if is_forced_key_chest(season_seed, grid, room_x, room_y, depth) {
+        return false;
+    }
+
+    let room_hash = generate_room_hash(season_seed ^ 0xE417_E417_E417_E417, room_x, room_y);
+    (room_hash % 100) < EXTRACTION_ROOM_CHANCE_PERCENT
+}
+
+pub fn enforce_special_room_topology(room: &mut RoomAccount, grid: DungeonGrid) {
+    enforce_special_wall_topology(
+        &mut room.walls,
+        &mut room.door_lock_kinds,
+        grid,
+        room.x,
+        room.y,
+    );
+}
+
+fn enforce_special_wall_topology(
+    walls: &mut [u8; 4],
+    door_lock_kinds: &mut [u8; 4],
+    grid: DungeonGrid,
+    room_x: i8,
+    room_y: i8,
+) {
+    if is_start_approach_wall(grid, room_x, room_y, DIRECTION_NORTH) {
+        walls[DIRECTION_NORTH as usize] = WALL_SOLID;
+        door_lock_kinds[DIRECTION_NORTH as usize] = LOCK_KIND_NONE;
     }
 }
 
+/// Reserve start-room south edge for entrance stairs/extraction only:
+/// room (start_x, start_y - 1) north wall must never be passable or lockable.
+pub fn is_start_approach_wall(grid: DungeonGrid, room_x: i8, room_y: i8, direction: u8) -> bool {
+    room_x == grid.start_x && room_y == grid.start_y - 1 && direction == DIRECTION_NORTH
+}
+
 fn apply_locked_doors(
     walls: &mut [u8; 4],
     door_lock_kinds: &mut [u8; 4],
@@ -286,7 +604,8 @@ fn apply_locked_doors(
         }
 
         walls[lock_direction as usize] = WALL_LOCKED;
-        door_lock_kinds[lock_direction as usize] = LOCK_KIND_SKELETON;
+        door_lock_kinds[lock_direction as usize] =
+            select_door_lock_kind(season_seed, room_x, room_y, room_depth);
         locked_count += 1;
         if locked_count >= MAX_LOCKED_DOORS_PER_ROOM {
             break;
@@ -294,15 +613,36 @@ fn apply_locked_doors(
     }
 }
 
+/// Deeper doors roll tougher locks; bone-room seals always stay skeleton locks.
+pub fn select_door_lock_kind(season_seed: u64, room_x: i8, room_y: i8, depth: u32) -> u8 {
+    let lock_roll =
+        generate_room_hash(season_seed ^ 0x10C4_10C4_10C4_10C4, room_x, room_y) % 100;
+    if depth >= RUNE_LOCK_MIN_DEPTH && lock_roll < RUNE_LOCK_ROLL_CUTOFF {
+        return LOCK_KIND_RUNE;
+    }
+    if depth >= COOP_LOCK_MIN_DEPTH && lock_roll < COOP_LOCK_ROLL_CUTOFF {
+        return LOCK_KIND_GOLD_COOP;
+    }
+    if depth >= GOLD_LOCK_MIN_DEPTH && lock_roll < GOLD_LOCK_ROLL_CUTOFF {
+        return LOCK_KIND_GOLD;
+    }
+    LOCK_KIND_SKELETON
+}
+
 fn apply_bone_room_locks(
     walls: &mut [u8; 4],
     door_lock_kinds: &mut [u8; 4],
-    season_seed: u64,
-    room_x: i8,
-    room_y: i8,
-    room_depth: u32,
+    location: RoomLocation,
     entrance_direction: u8,
 ) {
+    let RoomLocation {
+        grid,
+        x: room_x,
+        y: room_y,
+        ..
+    } = location;
+    let season_seed = location.generation_seed();
+    let room_depth = location.depth();
     if room_depth < BONE_ROOM_MIN_DEPTH {
         return;
     }
@@ -326,11 +666,11 @@ fn apply_bone_room_locks(
 
         let adjacent_x = adjacent_x(room_x, direction);
         let adjacent_y = adjacent_y(room_y, direction);
-        if !is_within_dungeon_bounds(adjacent_x, adjacent_y) {
+        if !is_within_dungeon_bounds(grid, adjacent_x, adjacent_y) {
             continue;
         }
 
-        let adjacent_depth = calculate_depth(adjacent_x, adjacent_y);
+        let adjacent_depth = depth_on_same_floor(grid, room_depth, adjacent_x, adjacent_y);
         if is_bone_room(season_seed, adjacent_x, adjacent_y, adjacent_depth) {
             walls[direction as usize] = WALL_LOCKED;
             door_lock_kinds[direction as usize] = LOCK_KIND_SKELETON;
@@ -342,11 +682,8 @@ fn is_lockable_door_wall(wall: u8) -> bool {
     wall == WALL_RUBBLE || wall == WALL_OPEN
 }
 
-fn is_within_dungeon_bounds(x: i8, y: i8) -> bool {
-    x >= GlobalAccount::MIN_COORD
-        && x <= GlobalAccount::MAX_COORD
-        && y >= GlobalAccount::MIN_COORD
-        && y <= GlobalAccount::MAX_COORD
+fn is_within_dungeon_bounds(grid: DungeonGrid, x: i8, y: i8) -> bool {
+    grid.contains(x, y)
 }
 
 fn adjacent_x(x: i8, direction: u8) -> i8 {
@@ -365,37 +702,75 @@ fn adjacent_y(y: i8, direction: u8) -> i8 {
     }
 }
 
-fn is_forced_depth_one_chest(season_seed: u64, room_x: i8, room_y: i8) -> bool {
+fn is_forced_depth_one_chest(season_seed: u64, grid: DungeonGrid, room_x: i8, room_y: i8) -> bool {
     let forced_direction = (season_seed % 4) as u8;
     let expected = match forced_direction {
-        0 => (GlobalAccount::START_X, GlobalAccount::START_Y + 1),
-        1 => (GlobalAccount::START_X, GlobalAccount::START_Y - 1),
-        2 => (GlobalAccount::START_X + 1, GlobalAccount::START_Y),
-        _ => (GlobalAccount::START_X - 1, GlobalAccount::START_Y),
+        0 => (grid.start_x, grid.start_y + 1),
+        1 => (grid.start_x, grid.start_y - 1),
+        2 => (grid.start_x + 1, grid.start_y),
+        _ => (grid.start_x - 1, grid.start_y),
     };
     room_x == expected.0 && room_y == expected.1
 }
 
-fn is_forced_key_chest(season_seed: u64, room_x: i8, room_y: i8, depth: u32) -> bool {
+fn is_forced_key_chest(season_seed: u64, grid: DungeonGrid, room_x: i8, room_y: i8, depth: u32) -> bool {
+    forced_key_drop_for_room(season_seed, grid, room_x, room_y, depth).is_some()
+}
+
+/// Key stack `(item_id, amount)` a forced key chest at this room grants, if it is one.
+pub fn forced_key_drop_for_room(
+    season_seed: u64,
+    grid: DungeonGrid,
+    room_x: i8,
+    room_y: i8,
+    depth: u32,
+) -> Option<(u16, u32)> {
     if depth < FORCED_KEY_CHEST_MIN_DEPTH {
-        return false;
+        return None;
     }
 
-    if let Some((forced_x, forced_y)) = select_forced_key_chest_coords(season_seed, depth) {
-        return room_x == forced_x && room_y == forced_y;
-    }
+    let forced_coords = select_forced_key_chests(season_seed, grid, depth);
+    FORCED_KEY_LOCK_KINDS
+        .iter()
+        .zip(forced_coords.iter())
+        .find(|(_, coords)| **coords == Some((room_x, room_y)))
+        .and_then(|(lock_kind, _)| lock_requirement(*lock_kind))
+        .map(|requirement| (requirement.key_item_id, requirement.keys_required))
+}
 
-    false
+pub fn select_forced_key_chest_coords(
+    season_seed: u64,
+    grid: DungeonGrid,
+    depth: u32,
+) -> Option<(i8, i8)> {
+    select_forced_key_chests(season_seed, grid, depth)[0]
+}
+
+fn forced_key_min_depth(lock_kind: u8) -> u32 {
+    match lock_kind {
+        LOCK_KIND_GOLD => GOLD_LOCK_MIN_DEPTH,
+        LOCK_KIND_RUNE => RUNE_LOCK_MIN_DEPTH,
+        _ => FORCED_KEY_CHEST_MIN_DEPTH,
+    }
 }
 
-fn select_forced_key_chest_coords(season_seed: u64, depth: u32) -> Option<(i8, i8)> {
-    let target_depth = depth as i8;
+/// One forced key chest per `FORCED_KEY_LOCK_KINDS` entry for this depth ring, on
+/// distinct non-bone rooms. Kinds whose locks cannot appear at this depth get None.
+pub fn select_forced_key_chests(
+    season_seed: u64,
+    grid: DungeonGrid,
+    depth: u32,
+) -> [Option<(i8, i8)>; FORCED_KEY_LOCK_KINDS.len()] {
+    let target_ring = ring_for_depth(depth);
     let mut ring_coords = Vec::<(i8, i8)>::new();
+    // A floor's ring 0 is only its arrival room, which never holds a chest.
+    if target_ring == 0 {
+        return [None; FORCED_KEY_LOCK_KINDS.len()];
+    }
 
-    for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-        for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-            let candidate_depth = calculate_depth(x, y) as i8;
-            if candidate_depth == target_depth {
+    for x in GlobalAccount::MIN_COORD..=grid.max_x() {
+        for y in GlobalAccount::MIN_COORD..=grid.max_y() {
+            if calculate_depth(grid, x, y) == target_ring {
                 if is_bone_room(season_seed, x, y, depth) {
                     continue;
                 }
@@ -404,21 +779,160 @@ fn select_forced_key_chest_coords(season_seed: u64, depth: u32) -> Option<(i8, i
         }
     }
 
+    let mut selected = [None; FORCED_KEY_LOCK_KINDS.len()];
+    for (kind_index, lock_kind) in FORCED_KEY_LOCK_KINDS.iter().enumerate() {
+        if ring_coords.is_empty() || depth < forced_key_min_depth(*lock_kind) {
+            continue;
+        }
+
+        let ring_hash = season_seed
+            .wrapping_mul(53)
+            .wrapping_add(depth as u64)
+            .wrapping_mul(97)
+            .wrapping_add((kind_index as u64).wrapping_mul(0x9E37_79B9));
+        let selected_index = (ring_hash as usize) % ring_coords.len();
+        // Removing the pick keeps every kind's chest on its own room.
+        selected[kind_index] = Some(ring_coords.remove(selected_index));
+    }
+    selected
+}
+
+/// Stairs down from `floor`: one non-bone, non-key-chest room on the
+/// outer complete ring. The deepest floor has none.
+pub fn select_stairs_down_coords(
+    season_seed: u64,
+    grid: DungeonGrid,
+    floor: u8,
+) -> Option<(i8, i8)> {
+    if floor >= GlobalAccount::MAX_FLOOR {
+        return None;
+    }
+
+    let stairs_ring = stairs_down_ring(grid);
+    let depth = u32::from(floor) * FLOOR_DEPTH_STRIDE + stairs_ring;
+    let forced_key_chests = if depth >= FORCED_KEY_CHEST_MIN_DEPTH {
+        select_forced_key_chests(season_seed, grid, depth)
+    } else {
+        [None; FORCED_KEY_LOCK_KINDS.len()]
+    };
+    let mut ring_coords = Vec::<(i8, i8)>::new();
+    for x in GlobalAccount::MIN_COORD..=grid.max_x() {
+        for y in GlobalAccount::MIN_COORD..=grid.max_y() {
+            if calculate_depth(grid, x, y) == stairs_ring
+                && !is_bone_room(season_seed, x, y, depth)
+                && !forced_key_chests.contains(&Some((x, y)))
+            {
+                ring_coords.push((x, y));
+            }
+        }
+    }
     if ring_coords.is_empty() {
         return None;
     }
 
-    let ring_hash = season_seed
-        .wrapping_mul(53)
-        .wrapping_add(depth as u64)
-        .wrapping_mul(97);
-    let selected_index = (ring_hash as usize) % ring_coords.len();
-    Some(ring_coords[selected_index])
+    let stairs_hash = generate_room_hash(season_seed ^ 0x57A1_57A1_57A1_57A1, floor as i8, 0);
+    Some(ring_coords[(stairs_hash as usize) % ring_coords.len()])
+}
+
+fn is_stairs_down_room(season_seed: u64, grid: DungeonGrid, room_x: i8, room_y: i8, depth: u32) -> bool {
+    ring_for_depth(depth) == stairs_down_ring(grid)
+        && select_stairs_down_coords(season_seed, grid, floor_for_depth(depth))
+            == Some((room_x, room_y))
+}
+
+/// Arrival room of a lower floor: same shape as the start room, with the south
+/// stairs leading back up instead of out of the dungeon.
+pub fn initialize_floor_arrival_room(
+    room: &mut RoomAccount,
+    season_seed: u64,
+    grid: DungeonGrid,
+    floor: u8,
+    created_by: Pubkey,
+    created_slot: u64,
+    bump: u8,
+) {
+    let room_depth = floor_depth(grid, floor, grid.start_x, grid.start_y);
+
+    room.x = grid.start_x;
+    room.y = grid.start_y;
+    room.season_seed = season_seed;
+    room.floor = floor;
+    room.biome_id = biome_for_depth(room_depth).id;
+    room.walls = RoomAccount::generate_start_walls(
+        floor_season_seed(season_seed, floor),
+        grid,
+        grid.start_x,
+        grid.start_y,
+    );
+    room.door_lock_kinds = [LOCK_KIND_NONE; 4];
+    room.helper_counts = [0; 4];
+    room.progress = [0; 4];
+    room.start_slot = [0; 4];
+    room.base_slots = [RoomAccount::calculate_base_slots(room_depth); 4];
+    room.total_staked = [0; 4];
+    room.job_completed = [false; 4];
+    room.bonus_per_helper = [0; 4];
+    room.has_chest = false;
+    room.forced_key_drop = false;
+    room.center_type = CENTER_EMPTY;
+    room.center_id = 0;
+    room.boss_max_hp = 0;
+    room.boss_current_hp = 0;
+    room.boss_last_update_slot = created_slot;
+    room.boss_total_dps = 0;
+    room.boss_fighter_count = 0;
+    room.boss_defeated = false;
+    room.boss_modifiers = 0;
+    room.boss_engaged_slot = 0;
+    room.trap_disarmed = false;
+    room.looted_count = 0;
+    room.created_by = created_by;
+    room.created_slot = created_slot;
+    room.bump = bump;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::analyze_season_connectivity;
+
+    const GRID: DungeonGrid = DungeonGrid::LEGACY;
+
+    fn blank_room() -> RoomAccount {
+        RoomAccount {
+            x: 0,
+            y: 0,
+            season_seed: 0,
+            walls: [WALL_SOLID; 4],
+            door_lock_kinds: [LOCK_KIND_NONE; 4],
+            helper_counts: [0; 4],
+            progress: [0; 4],
+            start_slot: [0; 4],
+            base_slots: [0; 4],
+            total_staked: [0; 4],
+            job_completed: [false; 4],
+            bonus_per_helper: [0; 4],
+            has_chest: false,
+            forced_key_drop: false,
+            center_type: CENTER_EMPTY,
+            center_id: 0,
+            boss_max_hp: 0,
+            boss_current_hp: 0,
+            boss_last_update_slot: 0,
+            boss_total_dps: 0,
+            boss_fighter_count: 0,
+            boss_defeated: false,
+            looted_count: 0,
+            created_by: Pubkey::default(),
+            created_slot: 0,
+            bump: 0,
+            floor: 0,
+            biome_id: 0,
+            boss_modifiers: 0,
+            boss_engaged_slot: 0,
+            trap_disarmed: false,
+        }
+    }
 
     #[test]
     fn room_generation_is_deterministic() {
@@ -435,18 +949,18 @@ mod tests {
         let second_walls = generate_walls(second_hash, entrance);
         assert_eq!(first_walls, second_walls);
 
-        let depth = calculate_depth(x, y);
-        let first_center = generate_room_center(seed, x, y, depth);
-        let second_center = generate_room_center(seed, x, y, depth);
+        let depth = calculate_depth(GRID, x, y);
+        let first_center = generate_room_center(seed, GRID, x, y, depth);
+        let second_center = generate_room_center(seed, GRID, x, y, depth);
         assert_eq!(first_center, second_center);
     }
 
     #[test]
     fn no_locked_doors_before_depth_two() {
         let seed = 12345u64;
-        let x = GlobalAccount::START_X;
-        let y = GlobalAccount::START_Y + 1;
-        let depth = calculate_depth(x, y);
+        let x = GRID.start_x;
+        let y = GRID.start_y + 1;
+        let depth = calculate_depth(GRID, x, y);
         assert_eq!(depth, 1);
 
         let mut walls = generate_walls(generate_room_hash(seed, x, y), 1);
@@ -461,25 +975,82 @@ mod tests {
     fn forced_key_chest_exists_for_depth_ring() {
         let seed = 424242u64;
         let depth = 3u32;
-        let forced_coords = select_forced_key_chest_coords(seed, depth);
+        let forced_coords = select_forced_key_chest_coords(seed, GRID, depth);
         assert!(forced_coords.is_some());
 
         let (forced_x, forced_y) = forced_coords.unwrap();
-        assert_eq!(calculate_depth(forced_x, forced_y), depth);
+        assert_eq!(calculate_depth(GRID, forced_x, forced_y), depth);
         assert!(!is_bone_room(seed, forced_x, forced_y, depth));
-        assert!(is_forced_key_chest(seed, forced_x, forced_y, depth));
+        assert!(is_forced_key_chest(seed, GRID, forced_x, forced_y, depth));
         let (center_type, _center_id, forced_key_drop) =
-            generate_room_center(seed, forced_x, forced_y, depth);
+            generate_room_center(seed, GRID, forced_x, forced_y, depth);
         assert_eq!(center_type, CENTER_CHEST);
         assert!(forced_key_drop);
     }
 
+    #[test]
+    fn deep_rings_get_a_forced_chest_per_key_kind() {
+        for seed in [7u64, 424242, 998877] {
+            for depth in FORCED_KEY_CHEST_MIN_DEPTH..=9 {
+                let forced = select_forced_key_chests(seed, GRID, depth);
+                assert_eq!(forced[0], select_forced_key_chest_coords(seed, GRID, depth));
+
+                for (kind_index, lock_kind) in FORCED_KEY_LOCK_KINDS.iter().enumerate() {
+                    let Some((x, y)) = forced[kind_index] else {
+                        assert!(depth < forced_key_min_depth(*lock_kind));
+                        continue;
+                    };
+                    assert!(depth >= forced_key_min_depth(*lock_kind));
+                    assert_eq!(calculate_depth(GRID, x, y), depth);
+                    assert!(!is_bone_room(seed, x, y, depth));
+                    assert!(forced[kind_index + 1..].iter().all(|other| *other != Some((x, y))));
+
+                    let requirement = lock_requirement(*lock_kind).unwrap();
+                    assert_eq!(
+                        forced_key_drop_for_room(seed, GRID, x, y, depth),
+                        Some((requirement.key_item_id, requirement.keys_required))
+                    );
+                    let (center_type, _, forced_key_drop) = generate_room_center(seed, GRID, x, y, depth);
+                    assert!(center_type == CENTER_CHEST || center_type == CENTER_BONE_CHEST);
+                    assert!(forced_key_drop);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lock_kinds_respect_min_depths() {
+        let mut seen = [false; 5];
+        for seed in 1u64..=50u64 {
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
+                    let lock_kind = select_door_lock_kind(seed, x, y, depth);
+                    let min_depth = match lock_kind {
+                        LOCK_KIND_GOLD => GOLD_LOCK_MIN_DEPTH,
+                        LOCK_KIND_GOLD_COOP => COOP_LOCK_MIN_DEPTH,
+                        LOCK_KIND_RUNE => RUNE_LOCK_MIN_DEPTH,
+                        _ => 0,
+                    };
+                    assert!(depth >= min_depth, "Lock kind {lock_kind} at depth {depth}");
+                    assert!(lock_requirement(lock_kind).is_some());
+                    seen[lock_kind as usize] = true;
+                }
+            }
+        }
+
+        assert!(seen[LOCK_KIND_SKELETON as usize]);
+        assert!(seen[LOCK_KIND_GOLD as usize]);
+        assert!(seen[LOCK_KIND_GOLD_COOP as usize]);
+        assert!(seen[LOCK_KIND_RUNE as usize]);
+    }
+
     #[test]
     fn room_below_start_never_opens_north() {
         let seed = 999u64;
         let mut room = RoomAccount {
-            x: GlobalAccount::START_X,
-            y: GlobalAccount::START_Y - 1,
+            x: GRID.start_x,
+            y: GRID.start_y - 1,
             season_seed: seed,
             walls: [WALL_OPEN; 4],
             door_lock_kinds: [LOCK_KIND_SKELETON; 4],
@@ -504,9 +1075,14 @@ mod tests {
             created_by: Pubkey::default(),
             created_slot: 0,
             bump: 0,
+            floor: 0,
+            biome_id: 0,
+            boss_modifiers: 0,
+            boss_engaged_slot: 0,
+            trap_disarmed: false,
         };
 
-        enforce_special_room_topology(&mut room);
+        enforce_special_room_topology(&mut room, GRID);
         assert_eq!(room.walls[DIRECTION_NORTH as usize], WALL_SOLID);
         assert_eq!(room.door_lock_kinds[DIRECTION_NORTH as usize], LOCK_KIND_NONE);
     }
@@ -514,20 +1090,20 @@ mod tests {
     #[test]
     fn bone_rooms_never_touch_orthogonally() {
         let seed = 123456u64;
-        for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-            for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                let depth = calculate_depth(x, y);
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                let depth = calculate_depth(GRID, x, y);
                 if !is_bone_room(seed, x, y, depth) {
                     continue;
                 }
 
                 let neighbors = [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)];
                 for (nx, ny) in neighbors {
-                    if !is_within_dungeon_bounds(nx, ny) {
+                    if !is_within_dungeon_bounds(GRID, nx, ny) {
                         continue;
                     }
 
-                    let neighbor_depth = calculate_depth(nx, ny);
+                    let neighbor_depth = calculate_depth(GRID, nx, ny);
                     assert!(
                         !is_bone_room(seed, nx, ny, neighbor_depth),
                         "Adjacent bone rooms found at ({x},{y}) and ({nx},{ny})"
@@ -540,15 +1116,15 @@ mod tests {
     #[test]
     fn bone_rooms_always_spawn_boss_11_or_bone_chest() {
         let seed = 77123u64;
-        for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-            for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                let depth = calculate_depth(x, y);
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                let depth = calculate_depth(GRID, x, y);
                 if !is_bone_room(seed, x, y, depth) {
                     continue;
                 }
 
                 let (center_type, center_id, _forced_key_drop) =
-                    generate_room_center(seed, x, y, depth);
+                    generate_room_center(seed, GRID, x, y, depth);
                 assert!(
                     center_type == CENTER_BOSS || center_type == CENTER_BONE_CHEST,
                     "Bone room ({x},{y}) produced invalid center_type={center_type}"
@@ -563,16 +1139,19 @@ mod tests {
     #[test]
     fn adjacent_standard_boss_ids_never_match() {
         let seed = 884422u64;
-        for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-            for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                let boss_id = select_standard_boss_id(seed, x, y);
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                let biome = biome_for_depth(calculate_depth(GRID, x, y));
+                let boss_id = select_standard_boss_id(seed, biome, x, y);
                 let neighbors = [(x + 1, y), (x, y + 1)];
                 for (neighbor_x, neighbor_y) in neighbors {
-                    if !is_within_dungeon_bounds(neighbor_x, neighbor_y) {
+                    if !is_within_dungeon_bounds(GRID, neighbor_x, neighbor_y) {
                         continue;
                     }
 
-                    let neighbor_boss_id = select_standard_boss_id(seed, neighbor_x, neighbor_y);
+                    let neighbor_biome = biome_for_depth(calculate_depth(GRID, neighbor_x, neighbor_y));
+                    let neighbor_boss_id =
+                        select_standard_boss_id(seed, neighbor_biome, neighbor_x, neighbor_y);
                     assert_ne!(
                         boss_id, neighbor_boss_id,
                         "Adjacent standard bosses matched at ({x},{y}) and ({neighbor_x},{neighbor_y})"
@@ -585,14 +1164,14 @@ mod tests {
     #[test]
     fn no_gilded_or_sarcophagus_before_depth_three() {
         let seed = 998877u64;
-        for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-            for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                let depth = calculate_depth(x, y);
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                let depth = calculate_depth(GRID, x, y);
                 if depth != 2 {
                     continue;
                 }
 
-                let (center_type, _, _) = generate_room_center(seed, x, y, depth);
+                let (center_type, _, _) = generate_room_center(seed, GRID, x, y, depth);
                 assert_ne!(center_type, CENTER_GILDED_CHEST);
                 assert_ne!(center_type, CENTER_SARCOPHAGUS_CHEST);
             }
@@ -604,14 +1183,14 @@ mod tests {
         let mut saw_gilded = false;
         let mut saw_sarcophagus = false;
         for seed in 1u64..=3000u64 {
-            for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                    let depth = calculate_depth(x, y);
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
                     if depth < 3 {
                         continue;
                     }
 
-                    let (center_type, _, forced_key_drop) = generate_room_center(seed, x, y, depth);
+                    let (center_type, _, forced_key_drop) = generate_room_center(seed, GRID, x, y, depth);
                     if forced_key_drop || is_bone_room(seed, x, y, depth) {
                         continue;
                     }
@@ -642,29 +1221,38 @@ mod tests {
         let mut saw_boss = false;
         let mut saw_chest = false;
         let mut saw_empty = false;
+        let mut saw_trap = false;
+        let mut saw_shrine = false;
+        let mut saw_merchant = false;
 
         for seed in 1u64..=2000u64 {
-            for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                    let depth = calculate_depth(x, y);
-                    if depth < 2 || is_bone_room(seed, x, y, depth) || is_forced_key_chest(seed, x, y, depth)
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
+                    if depth < 2 || is_bone_room(seed, x, y, depth) || is_forced_key_chest(seed, GRID, x, y, depth)
                     {
                         continue;
                     }
 
-                    let (center_type, _, _) = generate_room_center(seed, x, y, depth);
+                    let (center_type, _, _) = generate_room_center(seed, GRID, x, y, depth);
                     match center_type {
                         CENTER_BOSS => saw_boss = true,
                         CENTER_CHEST | CENTER_GILDED_CHEST | CENTER_SARCOPHAGUS_CHEST => {
                             saw_chest = true
                         }
                         CENTER_EMPTY => saw_empty = true,
+                        CENTER_TRAP => saw_trap = true,
+                        CENTER_SHRINE => saw_shrine = true,
+                        CENTER_MERCHANT => {
+                            assert!(depth >= MERCHANT_MIN_DEPTH);
+                            saw_merchant = true
+                        }
                         _ => {}
                     }
                 }
             }
 
-            if saw_boss && saw_chest && saw_empty {
+            if saw_boss && saw_chest && saw_empty && saw_trap && saw_shrine && saw_merchant {
                 break;
             }
         }
@@ -672,6 +1260,357 @@ mod tests {
         assert!(saw_boss, "Boss path was not observed.");
         assert!(saw_chest, "Chest path was not observed.");
         assert!(saw_empty, "Empty path was not observed.");
+        assert!(saw_trap, "Trap path was not observed.");
+        assert!(saw_shrine, "Shrine path was not observed.");
+        assert!(saw_merchant, "Merchant path was not observed.");
+    }
+
+    #[test]
+    fn center_weights_cover_full_roll() {
+        for biome in BIOMES.iter() {
+            assert_eq!(
+                biome.boss_weight
+                    + biome.chest_weight
+                    + biome.trap_weight
+                    + biome.shrine_weight
+                    + biome.merchant_weight
+                    + biome.empty_weight,
+                ROOM_CENTER_ROLL_SIDES,
+                "Biome {} center weights",
+                biome.id
+            );
+            assert_eq!(
+                biome.chest_tier_weights.iter().sum::<u64>(),
+                CHEST_TIER_ROLL_SIDES,
+                "Biome {} chest tiers",
+                biome.id
+            );
+        }
+    }
+
+    #[test]
+    fn elite_modifiers_follow_biome_chance() {
+        for depth in [2u32, 5, 9, FLOOR_DEPTH_STRIDE + 2] {
+            let biome = biome_for_depth(depth);
+            let mut elites = 0u64;
+            let mut seen = 0u8;
+            for seed in 1u64..=2_000u64 {
+                let modifiers = select_elite_modifiers(seed, 4, 7, depth);
+                if modifiers != 0 {
+                    assert_eq!(modifiers.count_ones(), 1, "Elites roll exactly one modifier");
+                    elites += 1;
+                    seen |= modifiers;
+                }
+            }
+
+            let elite_percent = elites * 100 / 2_000;
+            assert!(
+                elite_percent.abs_diff(biome.elite_chance) <= 3,
+                "Elite rate {elite_percent}% drifted from {}% at depth {depth}",
+                biome.elite_chance
+            );
+            if biome.elite_chance > 0 {
+                assert_eq!(seen, ELITE_REGENERATING | ELITE_ENRAGE | ELITE_SHIELDED);
+            }
+        }
+
+        let seed = 515151u64;
+        let connectivity = analyze_season_connectivity(seed, GRID);
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                if GRID.is_start(x, y) {
+                    continue;
+                }
+                let mut room = blank_room();
+                initialize_discovered_room(
+                    &mut room,
+                    RoomLocation::new(seed, GRID, 0, x, y),
+                    DIRECTION_NORTH,
+                    connectivity.repaired_directions(x, y),
+                    Pubkey::default(),
+                    0,
+                    0,
+                );
+                if room.center_type != CENTER_BOSS || room.center_id == RoomAccount::BONE_BOSS_ID {
+                    assert_eq!(room.boss_modifiers, 0, "Only standard bosses can be elite");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn elite_modifiers_change_boss_fights() {
+        let mut room = blank_room();
+        room.center_type = CENTER_BOSS;
+        room.boss_max_hp = 3_000;
+
+        assert!(!room.is_elite());
+        assert_eq!(room.boss_regen_for_slots(500), 0);
+        assert!(!room.is_boss_shield_up());
+        assert_eq!(room.enraged_boss_ticks(0, 100, 50), 0);
+
+        room.boss_modifiers = ELITE_REGENERATING;
+        assert!(room.is_elite());
+        assert_eq!(room.boss_regen_for_slots(RoomAccount::ELITE_REGEN_SLOTS_TO_FULL / 2), 1_500);
+        assert_eq!(room.boss_regen_for_slots(u64::MAX), 3_000);
+
+        room.boss_modifiers = ELITE_SHIELDED;
+        room.boss_fighter_count = RoomAccount::ELITE_SHIELD_MIN_FIGHTERS - 1;
+        assert!(room.is_boss_shield_up());
+        room.boss_fighter_count = RoomAccount::ELITE_SHIELD_MIN_FIGHTERS;
+        assert!(!room.is_boss_shield_up());
+
+        room.boss_modifiers = ELITE_ENRAGE;
+        room.boss_engaged_slot = 1_000;
+        let enrage_slot = 1_000 + RoomAccount::ELITE_ENRAGE_AFTER_SLOTS;
+        assert_eq!(room.enraged_boss_ticks(1_000, 4, 50), 0);
+        // Ticks ending at enrage_slot - 50, enrage_slot and enrage_slot + 50.
+        assert_eq!(room.enraged_boss_ticks(enrage_slot - 100, 3, 50), 2);
+        assert_eq!(room.enraged_boss_ticks(enrage_slot + 10, 3, 50), 3);
+
+        room.center_type = CENTER_TRAP;
+        assert!(!room.is_elite());
+    }
+
+    #[test]
+    fn biomes_follow_depth_bands() {
+        assert!(BIOMES.windows(2).all(|pair| pair[0].min_depth < pair[1].min_depth));
+        assert_eq!(BIOMES[0].min_depth, 0);
+        assert_eq!(biome_for_depth(0).id, BIOME_CRYPT);
+        assert_eq!(biome_for_depth(3).id, BIOME_CRYPT);
+        assert_eq!(biome_for_depth(4).id, BIOME_CATACOMBS);
+        assert_eq!(biome_for_depth(7).id, BIOME_CATACOMBS);
+        assert_eq!(biome_for_depth(8).id, BIOME_ABYSS);
+        assert_eq!(biome_for_depth(FLOOR_DEPTH_STRIDE - 1).id, BIOME_ABYSS);
+        assert_eq!(biome_for_depth(FLOOR_DEPTH_STRIDE).id, BIOME_UNDERDARK);
+        assert_eq!(biome_for_depth(u32::MAX).id, BIOME_UNDERDARK);
+
+        for biome in BIOMES.iter() {
+            for (slot, boss_id) in biome.boss_pool.iter().enumerate() {
+                let slot = slot as u16;
+                assert!(*boss_id == slot + 1 || *boss_id == slot + 5);
+            }
+        }
+
+        let seed = 424242u64;
+        for floor in 0..=GlobalAccount::MAX_FLOOR {
+            let connectivity = analyze_season_connectivity(floor_season_seed(seed, floor), GRID);
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    if GRID.is_start(x, y) {
+                        continue;
+                    }
+                    let mut room = blank_room();
+                    initialize_discovered_room(
+                        &mut room,
+                        RoomLocation::new(seed, GRID, floor, x, y),
+                        DIRECTION_NORTH,
+                        connectivity.repaired_directions(x, y),
+                        Pubkey::default(),
+                        0,
+                        0,
+                    );
+                    let biome = biome_for_depth(floor_depth(GRID, floor, x, y));
+                    assert_eq!(room.biome_id, biome.id);
+                    if room.center_type == CENTER_BOSS && room.center_id != 11 {
+                        assert!(biome.boss_pool.contains(&room.center_id));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn boss_hp_does_not_drop_into_the_next_biome() {
+        for pair in BIOMES.windows(2) {
+            let (previous, next) = (&pair[0], &pair[1]);
+            let last_depth = next.min_depth - 1;
+            for slot in 0..STANDARD_BOSS_VARIANTS as usize {
+                assert!(
+                    RoomAccount::boss_hp_for_depth(next.min_depth, next.boss_pool[slot])
+                        >= RoomAccount::boss_hp_for_depth(last_depth, previous.boss_pool[slot])
+                );
+            }
+
+            let pool_hp = |biome: &BiomeProfile, depth: u32| -> u64 {
+                biome
+                    .boss_pool
+                    .iter()
+                    .map(|&boss_id| RoomAccount::boss_hp_for_depth(depth, boss_id))
+                    .sum()
+            };
+            assert!(pool_hp(next, next.min_depth) >= pool_hp(previous, last_depth));
+        }
+    }
+
+    /// Centers placed by a fixed rule rather than the biome weight roll.
+    fn is_fixed_center(center_type: u8, center_id: u16, forced_key_drop: bool) -> bool {
+        forced_key_drop
+            || matches!(
+                center_type,
+                CENTER_BONE_CHEST | CENTER_EXTRACTION_STAIRS | CENTER_STAIRS_DOWN
+            )
+            || (center_type == CENTER_BOSS && center_id == RoomAccount::BONE_BOSS_ID)
+    }
+
+    #[test]
+    fn shrines_are_moderately_rare_with_both_variants() {
+        let mut rolled = 0u64;
+        let mut shrines = 0u64;
+        let mut expected_weight = 0u64;
+        let mut saw_free = false;
+        let mut saw_tribute = false;
+        for seed in 1u64..=8u64 {
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
+                    let (center_type, center_id, forced_key_drop) =
+                        generate_room_center(seed, GRID, x, y, depth);
+                    if center_type == CENTER_SHRINE {
+                        assert!(depth >= SHRINE_MIN_DEPTH, "Shrine generated at depth {depth}");
+                        match center_id {
+                            SHRINE_FREE => saw_free = true,
+                            SHRINE_TRIBUTE => saw_tribute = true,
+                            _ => panic!("Unknown shrine variant {center_id}"),
+                        }
+                    }
+
+                    if depth < 2 || is_fixed_center(center_type, center_id, forced_key_drop) {
+                        continue;
+                    }
+                    rolled += 1;
+                    expected_weight += biome_for_depth(depth).shrine_weight;
+                    if center_type == CENTER_SHRINE {
+                        shrines += 1;
+                    }
+                }
+            }
+        }
+
+        let shrine_percent = shrines * 100 / rolled;
+        let expected_percent = expected_weight / rolled;
+        assert!(
+            shrine_percent.abs_diff(expected_percent) <= 3,
+            "Shrine rate {shrine_percent}% drifted from weight {expected_percent}%"
+        );
+        assert!(saw_free && saw_tribute);
+    }
+
+    #[test]
+    fn traps_follow_weights_and_start_armed() {
+        let mut rolled = 0u64;
+        let mut traps = 0u64;
+        let mut expected_weight = 0u64;
+        for seed in 1u64..=8u64 {
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
+                    let (center_type, center_id, forced_key_drop) =
+                        generate_room_center(seed, GRID, x, y, depth);
+                    if center_type == CENTER_TRAP {
+                        assert!(depth >= TRAP_MIN_DEPTH, "Trap generated at depth {depth}");
+                        assert!((1..=RoomAccount::TRAP_VARIANTS).contains(&center_id));
+                        assert!(!forced_key_drop);
+                    }
+
+                    if depth < 2 || is_fixed_center(center_type, center_id, forced_key_drop) {
+                        continue;
+                    }
+                    rolled += 1;
+                    expected_weight += biome_for_depth(depth).trap_weight;
+                    if center_type == CENTER_TRAP {
+                        traps += 1;
+                    }
+                }
+            }
+        }
+
+        let trap_percent = traps * 100 / rolled;
+        let expected_percent = expected_weight / rolled;
+        assert!(
+            trap_percent.abs_diff(expected_percent) <= 3,
+            "Trap rate {trap_percent}% drifted from weight {expected_percent}%"
+        );
+
+        let seed = 31337u64;
+        let (x, y) = (GlobalAccount::MIN_COORD..=GRID.max_x())
+            .flat_map(|x| (GlobalAccount::MIN_COORD..=GRID.max_y()).map(move |y| (x, y)))
+            .find(|&(x, y)| generate_room_center(seed, GRID, x, y, calculate_depth(GRID, x, y)).0 == CENTER_TRAP)
+            .expect("No trap room for seed");
+        let mut room = blank_room();
+        let repaired = analyze_season_connectivity(seed, GRID).repaired_directions(x, y);
+        initialize_discovered_room(
+            &mut room,
+            RoomLocation::new(seed, GRID, 0, x, y),
+            DIRECTION_NORTH,
+            repaired,
+            Pubkey::default(),
+            1,
+            1,
+        );
+        assert!(room.is_trap_armed());
+        assert!(!room.has_chest);
+        assert_eq!(room.boss_max_hp, 0);
+        room.disarm_trap();
+        assert!(!room.is_trap_armed());
+        assert!(!room.boss_defeated);
+    }
+
+    #[test]
+    fn extraction_rooms_never_touch_and_skip_special_rooms() {
+        for seed in [1u64, 2, 3, 4, 424242, 998877, 11223344] {
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
+                    if !is_extraction_room(seed, GRID, x, y, depth) {
+                        continue;
+                    }
+
+                    assert!(depth >= EXTRACTION_ROOM_MIN_DEPTH);
+                    assert!(!is_bone_room(seed, x, y, depth));
+                    assert!(!is_forced_key_chest(seed, GRID, x, y, depth));
+                    let (center_type, _, _) = generate_room_center(seed, GRID, x, y, depth);
+                    assert_eq!(center_type, CENTER_EXTRACTION_STAIRS);
+
+                    for dx in -1i8..=1 {
+                        for dy in -1i8..=1 {
+                            let (nx, ny) = (x + dx, y + dy);
+                            if (dx == 0 && dy == 0) || !is_within_dungeon_bounds(GRID, nx, ny) {
+                                continue;
+                            }
+                            assert!(
+                                !is_extraction_room(seed, GRID, nx, ny, calculate_depth(GRID, nx, ny)),
+                                "Adjacent extraction rooms found at ({x},{y}) and ({nx},{ny})"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extraction_rooms_appear_only_past_min_depth() {
+        let mut seeds_with_exit = 0;
+        for seed in 1u64..=10u64 {
+            let mut found = false;
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = calculate_depth(GRID, x, y);
+                    let (center_type, _, _) = generate_room_center(seed, GRID, x, y, depth);
+                    if center_type != CENTER_EXTRACTION_STAIRS {
+                        continue;
+                    }
+                    assert!(depth >= EXTRACTION_ROOM_MIN_DEPTH);
+                    found = true;
+                }
+            }
+            if found {
+                seeds_with_exit += 1;
+            }
+        }
+
+        assert!(seeds_with_exit >= 6, "Extraction rooms are too rare: {seeds_with_exit}/10");
     }
 
     #[test]
@@ -681,15 +1620,16 @@ mod tests {
         let created_slot = 42u64;
         let bump = 1u8;
         let entrance_direction = DIRECTION_NORTH;
+        let connectivity = analyze_season_connectivity(seed, GRID);
 
-        for x in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-            for y in GlobalAccount::MIN_COORD..=GlobalAccount::MAX_COORD {
-                let depth = calculate_depth(x, y);
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                let depth = calculate_depth(GRID, x, y);
                 if depth < 2 {
                     continue;
                 }
 
-                let (center_type, _, _) = generate_room_center(seed, x, y, depth);
+                let (center_type, _, _) = generate_room_center(seed, GRID, x, y, depth);
                 if center_type != CENTER_CHEST
                     && center_type != CENTER_BONE_CHEST
                     && center_type != CENTER_GILDED_CHEST
@@ -725,14 +1665,18 @@ mod tests {
                     created_by: Pubkey::default(),
                     created_slot: 0,
                     bump: 0,
+                    floor: 0,
+                    biome_id: 0,
+                    boss_modifiers: 0,
+                    boss_engaged_slot: 0,
+                    trap_disarmed: false,
                 };
 
                 initialize_discovered_room(
                     &mut room,
-                    seed,
-                    x,
-                    y,
+                    RoomLocation::new(seed, GRID, 0, x, y),
                     entrance_direction,
+                    connectivity.repaired_directions(x, y),
                     created_by,
                     created_slot,
                     bump,
@@ -748,4 +1692,105 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn each_upper_floor_has_one_stairs_down_room_on_the_outer_ring() {
+        for seed in [1u64, 42, 424242, u64::MAX] {
+            let (stairs_x, stairs_y) = select_stairs_down_coords(seed, GRID, 0).unwrap();
+            let depth = floor_depth(GRID, 0, stairs_x, stairs_y);
+            assert_eq!(depth, stairs_down_ring(GRID));
+            assert!(!is_bone_room(seed, stairs_x, stairs_y, depth));
+            assert!(!is_forced_key_chest(seed, GRID, stairs_x, stairs_y, depth));
+            assert_eq!(
+                generate_room_center(seed, GRID, stairs_x, stairs_y, depth).0,
+                CENTER_STAIRS_DOWN
+            );
+            assert_eq!(select_stairs_down_coords(seed, GRID, GlobalAccount::MAX_FLOOR), None);
+
+            let mut stairs_rooms = 0;
+            for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+                for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                    let depth = floor_depth(GRID, 0, x, y);
+                    if generate_room_center(seed, GRID, x, y, depth).0 == CENTER_STAIRS_DOWN {
+                        stairs_rooms += 1;
+                    }
+                    let lower_depth = floor_depth(GRID, GlobalAccount::MAX_FLOOR, x, y);
+                    let lower_seed = floor_season_seed(seed, GlobalAccount::MAX_FLOOR);
+                    assert_ne!(
+                        generate_room_center(lower_seed, GRID, x, y, lower_depth).0,
+                        CENTER_STAIRS_DOWN
+                    );
+                }
+            }
+            assert_eq!(stairs_rooms, 1);
+        }
+    }
+
+    #[test]
+    fn lower_floors_continue_depth_with_their_own_layout() {
+        let seed = 424242u64;
+        assert_eq!(floor_season_seed(seed, 0), seed);
+        assert_ne!(floor_season_seed(seed, 1), seed);
+        assert_eq!(
+            floor_depth(GRID, 1, GRID.start_x, GRID.start_y),
+            FLOOR_DEPTH_STRIDE
+        );
+        assert_eq!(floor_for_depth(FLOOR_DEPTH_STRIDE + 3), 1);
+        assert_eq!(depth_on_same_floor(GRID, FLOOR_DEPTH_STRIDE + 3, 10, 10), FLOOR_DEPTH_STRIDE);
+
+        let lower_seed = floor_season_seed(seed, 1);
+        let mut differing_walls = 0;
+        for x in GlobalAccount::MIN_COORD..=GRID.max_x() {
+            for y in GlobalAccount::MIN_COORD..=GRID.max_y() {
+                if generate_walls(generate_room_hash(seed, x, y), 0)
+                    != generate_walls(generate_room_hash(lower_seed, x, y), 0)
+                {
+                    differing_walls += 1;
+                }
+            }
+        }
+        assert!(differing_walls > 0);
+
+        for ring in FORCED_KEY_CHEST_MIN_DEPTH..=stairs_down_ring(GRID) {
+            let depth = FLOOR_DEPTH_STRIDE + ring;
+            let (x, y) = select_forced_key_chest_coords(lower_seed, GRID, depth).unwrap();
+            assert_eq!(calculate_depth(GRID, x, y), ring);
+            assert!(generate_room_center(lower_seed, GRID, x, y, depth).2);
+        }
+    }
+
+    #[test]
+    fn small_grids_keep_walls_inside_and_stairs_on_their_outer_ring() {
+        let grid = DungeonGrid {
+            width: 9,
+            height: 7,
+            start_x: 3,
+            start_y: 3,
+        };
+        for seed in [1u64, 42, 424242, u64::MAX] {
+            let connectivity = analyze_season_connectivity(seed, grid);
+            for x in GlobalAccount::MIN_COORD..=grid.max_x() {
+                for y in GlobalAccount::MIN_COORD..=grid.max_y() {
+                    if grid.is_start(x, y) {
+                        continue;
+                    }
+                    let (walls, _) = generate_discovered_walls(
+                        RoomLocation::new(seed, grid, 0, x, y),
+                        DIRECTION_NORTH,
+                        connectivity.repaired_directions(x, y),
+                    );
+                    for direction in 0..=DIRECTION_WEST {
+                        let (nx, ny) = RoomAccount::adjacent_coords(x, y, direction);
+                        if !grid.contains(nx, ny) {
+                            assert_eq!(walls[direction as usize], WALL_SOLID);
+                        }
+                    }
+                }
+            }
+
+            let (stairs_x, stairs_y) = select_stairs_down_coords(seed, grid, 0).unwrap();
+            assert_eq!(calculate_depth(grid, stairs_x, stairs_y), grid.outer_complete_ring());
+            assert!(grid.contains(stairs_x, stairs_y));
+        }
+    }
 }
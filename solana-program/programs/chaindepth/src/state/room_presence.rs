@@ -13,6 +13,7 @@ pub struct RoomPresence {
     pub activity_direction: u8,
     pub is_current: bool,
     pub bump: u8,
+    pub floor: u8,
 }
 
 impl RoomPresence {
@@ -30,6 +30,45 @@ pub fn score_value_for_item(item_id: u16) -> u64 {
     }
 }
 
+/// Carry weight per unit; unknown items weigh 1.
+pub fn item_weight(item_id: u16) -> u64 {
+    match item_id {
+        100 => 8,  // Bronze Pickaxe
+        101 => 10, // Iron Pickaxe
+        102 => 6,  // Bronze Sword
+        103 => 8,  // Iron Sword
+        104 => 7,  // Diamond Sword
+        105 => 2,  // Nokia 3310
+        106 => 3,  // Wooden Pipe
+        107 => 7,  // Iron Scimitar
+        108 => 3,  // Wooden Tankard
+        200 => 1,  // Silver Coin
+        201 => 1,  // Gold Coin
+        202 => 5,  // Gold Bar
+        203 => 1,  // Diamond
+        204 => 1,  // Ruby
+        205 => 1,  // Sapphire
+        206 => 1,  // Emerald
+        207 => 6,  // Ancient Crown
+        208 => 1,  // Goblin Tooth
+        209 => 4,  // Dragon Scale
+        210 => 1,  // Cursed Amulet
+        211 => 3,  // Dusty Tome
+        212 => 1,  // Enchanted Scroll
+        213 => 4,  // Golden Chalice
+        214 => 0,  // Skeleton Key
+        215 => 3,  // Mystic Orb
+        216 => 1,  // Rusted Compass
+        217 => 0,  // Dwarf Beard Ring
+        218 => 0,  // Phoenix Feather
+        219 => 2,  // Void Shard
+        220 => 0,  // Gold Key
+        221 => 0,  // Rune Key
+        302 => 2,  // Trap Kit
+        _ => 1,
+    }
+}
+
 pub fn is_scored_loot_item(item_id: u16) -> bool {
     (200..=299).contains(&item_id)
 }
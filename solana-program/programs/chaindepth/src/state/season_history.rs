@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use super::WorldBossAccount;
+
+/// Milestones of one season, kept after the season resets.
+/// PDA seeds: ["season_history", season_seed]
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonHistoryAccount {
+    pub season_seed: u64,
+
+    /// Slot the world boss was spawned (the history account is created with it)
+    pub world_boss_spawned_slot: u64,
+
+    pub world_boss_defeated: bool,
+    pub world_boss_defeated_slot: u64,
+    pub world_boss_participants: u32,
+    pub world_boss_total_damage: u64,
+
+    /// SKR reserved from the prize pool for world boss fighters
+    pub world_boss_prize: u64,
+
+    pub bump: u8,
+}
+
+impl SeasonHistoryAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"season_history";
+
+    /// Copy the world boss result once it is defeated; later calls change nothing.
+    pub fn record_world_boss_defeat(&mut self, world_boss: &WorldBossAccount) -> bool {
+        if !world_boss.defeated || self.world_boss_defeated {
+            return false;
+        }
+
+        self.world_boss_defeated = true;
+        self.world_boss_defeated_slot = world_boss.defeated_slot;
+        self.world_boss_participants = world_boss.participant_count;
+        self.world_boss_total_damage = world_boss.total_damage;
+        self.world_boss_prize = world_boss.prize_amount;
+        true
+    }
+}
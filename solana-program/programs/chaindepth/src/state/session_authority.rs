@@ -38,4 +38,15 @@ pub mod session_instruction_bits {
     pub const TICK_BOSS_FIGHT: u64 = 1 << 16;
     pub const LEAVE_BOSS_FIGHT: u64 = 1 << 17;
     pub const ENTER_DUNGEON: u64 = 1 << 18;
+    pub const WITHDRAW_FROM_STORAGE: u64 = 1 << 19;
+    pub const DEPOSIT_TO_STORAGE: u64 = 1 << 20;
+    pub const BUY_ITEM: u64 = 1 << 21;
+    pub const SELL_VALUABLE: u64 = 1 << 22;
+    pub const USE_ITEM: u64 = 1 << 23;
+    pub const DISARM_TRAP: u64 = 1 << 24;
+    pub const PRAY_AT_SHRINE: u64 = 1 << 25;
+    pub const TRADE_WITH_MERCHANT: u64 = 1 << 26;
+    pub const CHANGE_FLOOR: u64 = 1 << 27;
+    pub const CLAIM_WORLD_BOSS_REWARD: u64 = 1 << 28;
+    pub const CLAIM_DISCOVERY_ROYALTIES: u64 = 1 << 29;
 }
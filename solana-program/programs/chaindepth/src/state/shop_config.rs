@@ -0,0 +1,249 @@
+use anchor_lang::prelude::*;
+
+use super::is_scored_loot_item;
+use crate::errors::ChainDepthError;
+
+pub const MAX_SHOP_LISTINGS: usize = 16;
+pub const MAX_SHOP_SELL_PRICES: usize = 24;
+
+/// Surface shop configuration - one per program
+/// PDA seeds: ["shop_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct ShopConfig {
+    /// Season the per-listing sold counters belong to.
+    /// Counters reset lazily on the first purchase after a season reset.
+    pub season_seed: u64,
+
+    /// If true, SKR spent in the shop is burned instead of paid into the prize pool.
+    pub burn_payments: bool,
+
+    /// Gear sold for SKR
+    #[max_len(MAX_SHOP_LISTINGS)]
+    pub listings: Vec<ShopListing>,
+
+    /// SKR paid per unit when selling banked valuables back
+    #[max_len(MAX_SHOP_SELL_PRICES)]
+    pub sell_prices: Vec<ShopSellPrice>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ShopListing {
+    pub item_id: u16,
+    pub durability: u16,
+    /// Price per unit in SKR base units
+    pub price: u64,
+    /// Units available per season (0 = unlimited)
+    pub stock_per_season: u32,
+    /// Units sold during `ShopConfig::season_seed`
+    pub sold_this_season: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ShopSellPrice {
+    pub item_id: u16,
+    /// Payout per unit in SKR base units
+    pub price: u64,
+}
+
+impl ShopConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"shop_config";
+
+    /// Fill in a config `init_if_needed` just created; a set bump means it already existed.
+    pub fn init_if_new(&mut self, season_seed: u64, bump: u8) {
+        if self.bump != 0 {
+            return;
+        }
+        self.season_seed = season_seed;
+        self.listings = Vec::new();
+        self.sell_prices = Vec::new();
+        self.bump = bump;
+    }
+
+    /// Reset sold counters when the global season has moved on.
+    pub fn sync_season(&mut self, season_seed: u64) {
+        if self.season_seed == season_seed {
+            return;
+        }
+        self.season_seed = season_seed;
+        for listing in self.listings.iter_mut() {
+            listing.sold_this_season = 0;
+        }
+    }
+
+    /// Insert or update a listing. A zero price removes it.
+    pub fn upsert_listing(
+        &mut self,
+        item_id: u16,
+        durability: u16,
+        price: u64,
+        stock_per_season: u32,
+    ) -> Result<()> {
+        require!(item_id > 0, ChainDepthError::InvalidItemId);
+        // Bought valuables would score on extraction, turning SKR straight into score.
+        require!(
+            !is_scored_loot_item(item_id),
+            ChainDepthError::ShopItemNotBuyable
+        );
+
+        if price == 0 {
+            self.listings.retain(|listing| listing.item_id != item_id);
+            return Ok(());
+        }
+
+        if let Some(listing) = self
+            .listings
+            .iter_mut()
+            .find(|listing| listing.item_id == item_id)
+        {
+            listing.durability = durability;
+            listing.price = price;
+            listing.stock_per_season = stock_per_season;
+            return Ok(());
+        }
+
+        require!(
+            self.listings.len() < MAX_SHOP_LISTINGS,
+            ChainDepthError::ShopConfigFull
+        );
+        self.listings.push(ShopListing {
+            item_id,
+            durability,
+            price,
+            stock_per_season,
+            sold_this_season: 0,
+        });
+        Ok(())
+    }
+
+    /// Insert or update a valuable buy-back price. A zero price removes it.
+    pub fn upsert_sell_price(&mut self, item_id: u16, price: u64) -> Result<()> {
+        require!(item_id > 0, ChainDepthError::InvalidItemId);
+
+        if price == 0 {
+            self.sell_prices.retain(|entry| entry.item_id != item_id);
+            return Ok(());
+        }
+
+        if let Some(entry) = self
+            .sell_prices
+            .iter_mut()
+            .find(|entry| entry.item_id == item_id)
+        {
+            entry.price = price;
+            return Ok(());
+        }
+
+        require!(
+            self.sell_prices.len() < MAX_SHOP_SELL_PRICES,
+            ChainDepthError::ShopConfigFull
+        );
+        self.sell_prices.push(ShopSellPrice { item_id, price });
+        Ok(())
+    }
+
+    /// Reserve stock for a purchase and return `(durability, total_price)`.
+    pub fn reserve_purchase(&mut self, item_id: u16, amount: u32) -> Result<(u16, u64)> {
+        require!(amount > 0, ChainDepthError::InvalidItemAmount);
+
+        let listing = self
+            .listings
+            .iter_mut()
+            .find(|listing| listing.item_id == item_id)
+            .ok_or(ChainDepthError::ShopItemNotListed)?;
+
+        let sold_after = listing
+            .sold_this_season
+            .checked_add(amount)
+            .ok_or(ChainDepthError::Overflow)?;
+        if listing.stock_per_season > 0 {
+            require!(
+                sold_after <= listing.stock_per_season,
+                ChainDepthError::ShopOutOfStock
+            );
+        }
+        listing.sold_this_season = sold_after;
+
+        let total_price = listing
+            .price
+            .checked_mul(u64::from(amount))
+            .ok_or(ChainDepthError::Overflow)?;
+        Ok((listing.durability, total_price))
+    }
+
+    pub fn sell_payout(&self, item_id: u16, amount: u32) -> Result<u64> {
+        require!(amount > 0, ChainDepthError::InvalidItemAmount);
+
+        let entry = self
+            .sell_prices
+            .iter()
+            .find(|entry| entry.item_id == item_id)
+            .ok_or(ChainDepthError::ShopItemNotSellable)?;
+        entry
+            .price
+            .checked_mul(u64::from(amount))
+            .ok_or(ChainDepthError::Overflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shop() -> ShopConfig {
+        ShopConfig {
+            season_seed: 1,
+            burn_payments: false,
+            listings: Vec::new(),
+            sell_prices: Vec::new(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn stock_limit_enforced_and_resets_with_season() {
+        let mut shop = shop();
+        shop.upsert_listing(7, 30, 100, 3).unwrap();
+
+        assert_eq!(shop.reserve_purchase(7, 2).unwrap(), (30, 200));
+        assert!(shop.reserve_purchase(7, 2).is_err());
+        assert_eq!(shop.listings[0].sold_this_season, 2);
+
+        shop.sync_season(2);
+        assert_eq!(shop.reserve_purchase(7, 3).unwrap(), (30, 300));
+    }
+
+    #[test]
+    fn zero_price_removes_entries() {
+        let mut shop = shop();
+        shop.upsert_listing(7, 0, 50, 0).unwrap();
+        shop.upsert_sell_price(200, 25).unwrap();
+
+        shop.upsert_listing(7, 0, 0, 0).unwrap();
+        shop.upsert_sell_price(200, 0).unwrap();
+
+        assert!(shop.reserve_purchase(7, 1).is_err());
+        assert!(shop.sell_payout(200, 1).is_err());
+    }
+
+    #[test]
+    fn unlimited_stock_and_sell_payout() {
+        let mut shop = shop();
+        shop.upsert_listing(7, 0, 10, 0).unwrap();
+        shop.upsert_sell_price(200, 25).unwrap();
+
+        assert_eq!(shop.reserve_purchase(7, 1_000).unwrap(), (0, 10_000));
+        assert_eq!(shop.sell_payout(200, 4).unwrap(), 100);
+    }
+
+    #[test]
+    fn scored_valuables_cannot_be_listed() {
+        let mut shop = shop();
+        assert!(shop.upsert_listing(200, 0, 10, 0).is_err());
+        assert!(shop.upsert_listing(299, 0, 10, 0).is_err());
+        assert!(shop.listings.is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Per-player prayer receipt for a specific shrine room.
+/// Existence of this PDA proves the player has already used the shrine.
+/// PDA seeds: ["shrine_receipt", season_seed (8 bytes), room_x (1 byte), room_y (1 byte), floor (1 byte, floors > 0 only), player_pubkey]
+#[account]
+#[derive(InitSpace)]
+pub struct ShrineReceipt {
+    pub player: Pubkey,
+    pub season_seed: u64,
+    pub room_x: i8,
+    pub room_y: i8,
+    pub bump: u8,
+}
+
+impl ShrineReceipt {
+    pub const SEED_PREFIX: &'static [u8] = b"shrine_receipt";
+}
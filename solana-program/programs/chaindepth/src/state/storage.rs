@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ChainDepthError;
-use crate::state::inventory::InventoryItem;
+use crate::state::inventory::{remove_stack, space_with_bonus_slots, InventoryItem};
 
 pub const MAX_STORAGE_SLOTS: usize = 64;
 
@@ -12,6 +12,10 @@ pub struct StorageAccount {
     #[max_len(MAX_STORAGE_SLOTS)]
     pub items: Vec<InventoryItem>,
     pub bump: u8,
+    /// Slots bought on top of `MAX_STORAGE_SLOTS`; the account is reallocated to fit.
+    /// Accounts created before this field hold stale item bytes here until `expand_storage`
+    /// grows them; every handler that reads it calls `reset_stale_bonus_slots` first.
+    pub bonus_slots: u16,
 }
 
 impl StorageAccount {
@@ -34,7 +38,7 @@ impl StorageAccount {
         }
 
         require!(
-            self.items.len() < MAX_STORAGE_SLOTS,
+            self.items.len() < self.slot_capacity(),
             ChainDepthError::InventoryFull
         );
 
@@ -46,4 +50,191 @@ impl StorageAccount {
 
         Ok(())
     }
+
+    pub fn slot_capacity(&self) -> usize {
+        MAX_STORAGE_SLOTS + usize::from(self.bonus_slots)
+    }
+
+    /// Allocated account size for the given number of bonus slots.
+    pub fn space_for_bonus_slots(bonus_slots: u16) -> usize {
+        space_with_bonus_slots(Self::DISCRIMINATOR.len() + Self::INIT_SPACE, bonus_slots)
+    }
+
+    /// An account shorter than `space_for_bonus_slots(0)` predates `bonus_slots`;
+    /// the bytes it was read from are left over from a longer item list.
+    pub fn reset_stale_bonus_slots(&mut self, data_len: usize) {
+        if data_len < Self::space_for_bonus_slots(0) {
+            self.bonus_slots = 0;
+        }
+    }
+
+    /// Remove units from the single stack matching `(item_id, durability)`.
+    pub fn remove_stack(&mut self, item_id: u16, durability: u16, amount: u32) -> Result<()> {
+        remove_stack(&mut self.items, item_id, durability, amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::inventory::{
+        item_ids, next_capacity_upgrade, InventoryAccount, CAPACITY_UPGRADE_BASE_PRICE,
+        LEVELS_PER_CAPACITY_UPGRADE, MAX_CAPACITY_UPGRADES, MAX_INVENTORY_SLOTS,
+        SLOTS_PER_CAPACITY_UPGRADE,
+    };
+
+    fn empty_storage() -> StorageAccount {
+        StorageAccount {
+            owner: Pubkey::new_unique(),
+            items: Vec::new(),
+            bump: 0,
+            bonus_slots: 0,
+        }
+    }
+
+    fn empty_inventory() -> InventoryAccount {
+        InventoryAccount {
+            owner: Pubkey::new_unique(),
+            items: Vec::new(),
+            bump: 0,
+            bonus_slots: 0,
+        }
+    }
+
+    #[test]
+    fn withdraw_merges_into_matching_inventory_stack() {
+        let mut storage = empty_storage();
+        let mut inventory = empty_inventory();
+        storage.add_item(item_ids::IRON_SWORD, 2, 120).unwrap();
+        inventory.add_item(item_ids::IRON_SWORD, 1, 120).unwrap();
+
+        storage.remove_stack(item_ids::IRON_SWORD, 120, 2).unwrap();
+        inventory.add_item(item_ids::IRON_SWORD, 2, 120).unwrap();
+
+        assert!(storage.items.is_empty());
+        assert_eq!(inventory.items.len(), 1);
+        assert_eq!(inventory.items[0].amount, 3);
+    }
+
+    #[test]
+    fn stacks_with_different_durability_stay_separate() {
+        let mut storage = empty_storage();
+        storage.add_item(item_ids::BRONZE_SWORD, 1, 80).unwrap();
+        storage.add_item(item_ids::BRONZE_SWORD, 1, 40).unwrap();
+        assert_eq!(storage.items.len(), 2);
+
+        storage.remove_stack(item_ids::BRONZE_SWORD, 40, 1).unwrap();
+        assert_eq!(storage.items.len(), 1);
+        assert_eq!(storage.items[0].durability, 80);
+        assert!(storage.remove_stack(item_ids::BRONZE_SWORD, 40, 1).is_err());
+    }
+
+    #[test]
+    fn partial_withdraw_keeps_remainder_in_storage() {
+        let mut storage = empty_storage();
+        storage.add_item(item_ids::SKELETON_KEY, 5, 0).unwrap();
+
+        storage.remove_stack(item_ids::SKELETON_KEY, 0, 3).unwrap();
+        assert_eq!(storage.items[0].amount, 2);
+        assert!(storage.remove_stack(item_ids::SKELETON_KEY, 0, 3).is_err());
+        assert_eq!(storage.items[0].amount, 2);
+    }
+
+    #[test]
+    fn storage_rejects_new_stack_when_full_but_merges_existing() {
+        let mut storage = empty_storage();
+        for slot in 0..MAX_STORAGE_SLOTS {
+            storage.add_item(1000 + slot as u16, 1, 0).unwrap();
+        }
+
+        assert!(storage.add_item(item_ids::GOLD_COIN, 1, 0).is_err());
+        storage.add_item(1000, 4, 0).unwrap();
+        assert_eq!(storage.items.len(), MAX_STORAGE_SLOTS);
+        assert_eq!(storage.items[0].amount, 5);
+    }
+
+    #[test]
+    fn inventory_rejects_deposit_target_overflow_when_full() {
+        let mut inventory = empty_inventory();
+        for slot in 0..MAX_INVENTORY_SLOTS {
+            inventory.add_item(1000 + slot as u16, 1, 0).unwrap();
+        }
+
+        assert!(inventory.add_item(item_ids::IRON_PICKAXE, 1, 120).is_err());
+        inventory.remove_stack(1000, 0, 1).unwrap();
+        inventory.add_item(item_ids::IRON_PICKAXE, 1, 120).unwrap();
+        assert_eq!(inventory.items.len(), MAX_INVENTORY_SLOTS);
+    }
+
+    #[test]
+    fn bonus_slots_raise_capacity() {
+        let mut storage = empty_storage();
+        storage.bonus_slots = SLOTS_PER_CAPACITY_UPGRADE;
+        for slot in 0..storage.slot_capacity() {
+            storage.add_item(1000 + slot as u16, 1, 0).unwrap();
+        }
+        assert!(storage.add_item(item_ids::GOLD_COIN, 1, 0).is_err());
+
+        let mut inventory = empty_inventory();
+        inventory.bonus_slots = SLOTS_PER_CAPACITY_UPGRADE;
+        for slot in 0..inventory.slot_capacity() {
+            assert!(inventory.try_add_item(1000 + slot as u16, 1, 0).unwrap());
+        }
+        assert!(!inventory.try_add_item(item_ids::GOLD_COIN, 1, 0).unwrap());
+        assert!(inventory.try_add_item(1000, 1, 0).unwrap());
+        assert_eq!(
+            inventory.items.len(),
+            MAX_INVENTORY_SLOTS + usize::from(SLOTS_PER_CAPACITY_UPGRADE)
+        );
+    }
+
+    #[test]
+    fn legacy_length_ignores_stale_bonus_slots() {
+        // Legacy inventories end at `bump`; a shrunken item list leaves its old
+        // bytes where `bonus_slots` now lives.
+        let legacy_len = InventoryAccount::space_for_bonus_slots(0) - 2;
+        let mut inventory = empty_inventory();
+        for slot in 0..MAX_INVENTORY_SLOTS {
+            inventory.add_item(0xF000 + slot as u16, u32::MAX, 0xFFFF).unwrap();
+        }
+        let mut data = Vec::new();
+        inventory.try_serialize(&mut data).unwrap();
+        data.truncate(legacy_len);
+
+        inventory.items.truncate(3);
+        let mut encoded = Vec::new();
+        inventory.try_serialize(&mut encoded).unwrap();
+        let written = encoded.len() - 2;
+        data[..written].copy_from_slice(&encoded[..written]);
+
+        let mut decoded = InventoryAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_ne!(decoded.bonus_slots, 0);
+        decoded.reset_stale_bonus_slots(data.len());
+        assert_eq!(decoded.bonus_slots, 0);
+        assert_eq!(decoded.items.len(), 3);
+        assert_eq!(decoded.slot_capacity(), MAX_INVENTORY_SLOTS);
+
+        let mut storage = empty_storage();
+        storage.bonus_slots = SLOTS_PER_CAPACITY_UPGRADE;
+        storage.reset_stale_bonus_slots(StorageAccount::space_for_bonus_slots(0));
+        assert_eq!(storage.bonus_slots, SLOTS_PER_CAPACITY_UPGRADE);
+        storage.reset_stale_bonus_slots(StorageAccount::space_for_bonus_slots(0) - 2);
+        assert_eq!(storage.bonus_slots, 0);
+    }
+
+    #[test]
+    fn capacity_upgrades_escalate_and_cap() {
+        assert_eq!(
+            next_capacity_upgrade(0),
+            Some((LEVELS_PER_CAPACITY_UPGRADE, CAPACITY_UPGRADE_BASE_PRICE))
+        );
+        assert_eq!(
+            next_capacity_upgrade(SLOTS_PER_CAPACITY_UPGRADE),
+            Some((2 * LEVELS_PER_CAPACITY_UPGRADE, 2 * CAPACITY_UPGRADE_BASE_PRICE))
+        );
+        assert_eq!(
+            next_capacity_upgrade(SLOTS_PER_CAPACITY_UPGRADE * MAX_CAPACITY_UPGRADES),
+            None
+        );
+    }
 }
@@ -0,0 +1,225 @@
+use anchor_lang::prelude::*;
+
+use super::{BossFightAccount, DungeonGrid, MAX_BOSS_HP};
+use crate::errors::ChainDepthError;
+
+/// Season-wide boss fought from every floor-0 room on the grid's outermost ring.
+/// Spawns once `GlobalAccount::depth` reaches that ring; its prize is split by damage.
+/// PDA seeds: ["world_boss", season_seed]
+#[account]
+#[derive(InitSpace)]
+pub struct WorldBossAccount {
+    pub season_seed: u64,
+
+    /// Identifier used by Unity to pick the world boss prefab
+    pub boss_id: u16,
+
+    pub max_hp: u64,
+    pub current_hp: u64,
+
+    /// Slot of latest HP update
+    pub last_update_slot: u64,
+
+    /// Total DPS from current fighters
+    pub total_dps: u64,
+
+    /// Number of current fighters
+    pub fighter_count: u32,
+
+    /// Distinct players who ever joined the fight
+    pub participant_count: u32,
+
+    /// Damage dealt by all fighters, final overkill included; the prize split denominator
+    pub total_damage: u64,
+
+    pub spawned_by: Pubkey,
+    pub spawned_slot: u64,
+
+    pub defeated: bool,
+    pub defeated_slot: u64,
+
+    /// SKR set aside for fighters, fixed from the prize pool by the first claim after defeat
+    pub prize_amount: u64,
+
+    /// SKR already paid out of `prize_amount`
+    pub prize_paid: u64,
+
+    pub bump: u8,
+}
+
+impl WorldBossAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"world_boss";
+
+    pub const BOSS_ID: u16 = 20;
+
+    /// Far beyond any room boss: meant to take the whole server most of a season.
+    pub const MAX_HP: u64 = MAX_BOSS_HP * 50;
+
+    /// Share of the prize pool paid out to world boss fighters (20%).
+    pub const PRIZE_BPS: u64 = 2_000;
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+
+    /// Global depth at which the world boss can be spawned.
+    pub fn spawn_depth(grid: DungeonGrid) -> u32 {
+        grid.max_ring()
+    }
+
+    /// Rooms the world boss can be fought from.
+    pub fn is_arena_room(grid: DungeonGrid, floor: u8, x: i8, y: i8) -> bool {
+        floor == 0 && grid.contains(x, y) && grid.ring(x, y) == grid.max_ring()
+    }
+
+    /// Apply fighter DPS since the last update, as `apply_boss_damage` does for rooms.
+    pub fn apply_damage(&mut self, current_slot: u64) -> Result<()> {
+        if self.defeated || self.fighter_count == 0 || self.total_dps == 0 {
+            self.last_update_slot = current_slot;
+            return Ok(());
+        }
+
+        let elapsed_slots = current_slot.saturating_sub(self.last_update_slot);
+        let damage = elapsed_slots
+            .checked_mul(self.total_dps)
+            .ok_or(ChainDepthError::Overflow)?;
+
+        self.total_damage = self.total_damage.saturating_add(damage);
+        self.current_hp = self.current_hp.saturating_sub(damage);
+        self.last_update_slot = current_slot;
+        if self.current_hp == 0 {
+            self.defeated = true;
+            self.defeated_slot = current_slot;
+        }
+
+        Ok(())
+    }
+
+    /// Credit an active fighter with their DPS for the slots since their last settlement,
+    /// stopping at the kill. Call `apply_damage` first so the kill slot is known.
+    pub fn settle_fighter(&self, boss_fight: &mut BossFightAccount, current_slot: u64) {
+        if !boss_fight.is_active {
+            return;
+        }
+
+        let end_slot = if self.defeated {
+            current_slot.min(self.defeated_slot)
+        } else {
+            current_slot
+        };
+        let slots = end_slot.saturating_sub(boss_fight.last_damage_slot);
+        boss_fight.damage_dealt = boss_fight
+            .damage_dealt
+            .saturating_add(slots.saturating_mul(boss_fight.dps));
+        boss_fight.last_damage_slot = boss_fight.last_damage_slot.max(end_slot);
+    }
+
+    /// Prize pool amount reserved for fighters when the first reward is claimed.
+    pub fn prize_for_pool(prize_pool_amount: u64) -> u64 {
+        let prize = u128::from(prize_pool_amount) * u128::from(Self::PRIZE_BPS)
+            / u128::from(Self::BPS_DENOMINATOR);
+        prize as u64
+    }
+
+    /// A fighter's share of `prize_amount`, proportional to their damage.
+    pub fn reward_for_damage(&self, damage_dealt: u64) -> u64 {
+        if self.total_damage == 0 {
+            return 0;
+        }
+        let share = u128::from(self.prize_amount) * u128::from(damage_dealt)
+            / u128::from(self.total_damage);
+        let remaining = self.prize_amount.saturating_sub(self.prize_paid);
+        (share as u64).min(remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: DungeonGrid = DungeonGrid::LEGACY;
+
+    fn spawned_boss() -> WorldBossAccount {
+        WorldBossAccount {
+            season_seed: 1,
+            boss_id: WorldBossAccount::BOSS_ID,
+            max_hp: 10_000,
+            current_hp: 10_000,
+            last_update_slot: 0,
+            total_dps: 0,
+            fighter_count: 0,
+            participant_count: 0,
+            total_damage: 0,
+            spawned_by: Pubkey::default(),
+            spawned_slot: 0,
+            defeated: false,
+            defeated_slot: 0,
+            prize_amount: 0,
+            prize_paid: 0,
+            bump: 0,
+        }
+    }
+
+    fn fighter(dps: u64, joined_slot: u64) -> BossFightAccount {
+        BossFightAccount {
+            player: Pubkey::new_unique(),
+            room: Pubkey::default(),
+            dps,
+            joined_slot,
+            last_damage_slot: joined_slot,
+            is_active: true,
+            bump: 0,
+            damage_dealt: 0,
+            reward_claimed: false,
+        }
+    }
+
+    fn join(boss: &mut WorldBossAccount, dps: u64, slot: u64) -> BossFightAccount {
+        boss.apply_damage(slot).unwrap();
+        boss.fighter_count += 1;
+        boss.total_dps += dps;
+        fighter(dps, slot)
+    }
+
+    #[test]
+    fn arena_is_the_outermost_ring_of_the_first_floor() {
+        assert_eq!(WorldBossAccount::spawn_depth(GRID), 10);
+        assert!(WorldBossAccount::is_arena_room(GRID, 0, 0, 0));
+        assert!(WorldBossAccount::is_arena_room(GRID, 0, 0, 12));
+        assert!(!WorldBossAccount::is_arena_room(GRID, 0, 19, 19));
+        assert!(!WorldBossAccount::is_arena_room(GRID, 1, 0, 0));
+        assert!(!WorldBossAccount::is_arena_room(GRID, 0, -1, 0));
+    }
+
+    #[test]
+    fn fighter_damage_adds_up_to_the_prize_denominator() {
+        let mut boss = spawned_boss();
+        let mut early = join(&mut boss, 10, 100);
+        let mut late = join(&mut boss, 70, 200);
+
+        // Early fighter leaves at 300.
+        boss.apply_damage(300).unwrap();
+        boss.settle_fighter(&mut early, 300);
+        early.is_active = false;
+        boss.fighter_count -= 1;
+        boss.total_dps -= early.dps;
+        assert_eq!(early.damage_dealt, 2_000);
+
+        // 10_000 - 1_000 - 8_000 leaves 1_000 HP; the final 7_000 counts in full, overkill included.
+        boss.apply_damage(400).unwrap();
+        assert!(boss.defeated);
+        assert_eq!(boss.defeated_slot, 400);
+        boss.settle_fighter(&mut late, 500);
+        assert_eq!(late.last_damage_slot, 400);
+        assert_eq!(late.damage_dealt, 14_000);
+        assert_eq!(early.damage_dealt + late.damage_dealt, boss.total_damage);
+
+        boss.prize_amount = WorldBossAccount::prize_for_pool(4_000);
+        assert_eq!(boss.prize_amount, 800);
+        let early_reward = boss.reward_for_damage(early.damage_dealt);
+        boss.prize_paid += early_reward;
+        let late_reward = boss.reward_for_damage(late.damage_dealt);
+        boss.prize_paid += late_reward;
+        assert_eq!(early_reward, 100);
+        assert_eq!(late_reward, 700);
+        assert!(boss.prize_paid <= boss.prize_amount);
+        assert_eq!(boss.reward_for_damage(late.damage_dealt), 0);
+    }
+}
@@ -0,0 +1,61 @@
+/**
+ * Grow the global account to the current layout after a program upgrade.
+ * Instructions that load GlobalAccount fail to decode a legacy global until this runs.
+ *
+ * Usage:
+ *   npm run migrate-global
+ *
+ * Environment:
+ *   ANCHOR_PROVIDER_URL=https://api.devnet.solana.com
+ *   ANCHOR_WALLET=devnet-wallet.json
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import type { Chaindepth } from "../target/types/chaindepth";
+
+async function main(): Promise<void> {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Chaindepth as Program<Chaindepth>;
+  const [globalPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("global")],
+    program.programId
+  );
+
+  const before = await provider.connection.getAccountInfo(globalPda, "confirmed");
+  if (!before) {
+    throw new Error(`Global account ${globalPda.toBase58()} does not exist`);
+  }
+
+  console.log("=== Migrate Global ===");
+  console.log("Program:", program.programId.toBase58());
+  console.log("Payer:", provider.wallet.publicKey.toBase58());
+  console.log("Global PDA:", globalPda.toBase58());
+  console.log("Before length:", before.data.length);
+
+  const signature = await program.methods
+    .migrateGlobal()
+    .accountsPartial({
+      payer: provider.wallet.publicKey,
+      global: globalPda,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc();
+
+  const after = await provider.connection.getAccountInfo(globalPda, "confirmed");
+  const verified = await program.account.globalAccount.fetch(globalPda);
+  console.log("Migrate signature:", signature);
+  console.log("After length:", after?.data.length ?? 0);
+  console.log("Season seed:", verified.seasonSeed.toString());
+}
+
+main().catch((thrownObject: unknown) => {
+  const error =
+    thrownObject instanceof Error
+      ? thrownObject
+      : new Error(`Non-Error thrown: ${String(thrownObject)}`);
+  console.error("Error:", error.message);
+  process.exit(1);
+});
@@ -6,7 +6,10 @@ import {
   createAssociatedTokenAccount,
   mintTo,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
 import { expect } from "chai";
 
@@ -151,6 +154,26 @@ describe("chaindepth", () => {
         }
       }
     });
+
+    it("migrate_global leaves a current global unchanged", async () => {
+      const before = await program.account.globalAccount.fetch(globalPda);
+      const beforeInfo = await provider.connection.getAccountInfo(globalPda);
+
+      await program.methods
+        .migrateGlobal()
+        .accounts({
+          payer: admin.publicKey,
+          global: globalPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const after = await program.account.globalAccount.fetch(globalPda);
+      const afterInfo = await provider.connection.getAccountInfo(globalPda);
+      expect(afterInfo?.data.length).to.equal(beforeInfo?.data.length);
+      expect(after.seasonSeed.toString()).to.equal(before.seasonSeed.toString());
+      expect(after.admin.toBase58()).to.equal(before.admin.toBase58());
+    });
   });
 
   describe("player operations", () => {
@@ -160,6 +183,185 @@ describe("chaindepth", () => {
     });
   });
 
+  describe("item nft export", () => {
+    const DIAMOND_SWORD = 104;
+    const SWORD_DURABILITY = 50;
+    const itemMint = anchor.web3.Keypair.generate();
+
+    it("refuses to export from wallets granted items by add_inventory_item", async () => {
+      const airdrop = await provider.connection.requestAirdrop(
+        player.publicKey,
+        2 * anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        (admin as any).payer,
+        skrMint,
+        globalPda,
+        true
+      );
+      await program.methods
+        .initPlayer()
+        .accountsPartial({ player: player.publicKey, skrMint })
+        .signers([player])
+        .rpc();
+
+      await program.methods
+        .addInventoryItem(DIAMOND_SWORD, 1, SWORD_DURABILITY)
+        .accountsPartial({ player: player.publicKey })
+        .signers([player])
+        .rpc();
+
+      // Items can only be exported outside a run; the sword is unscored, so it stays in inventory.
+      const { seasonSeed } = await program.account.globalAccount.fetch(globalPda);
+      await program.methods
+        .exitDungeon()
+        .accountsPartial({
+          authority: player.publicKey,
+          player: player.publicKey,
+          room: deriveRoomPda(program.programId, seasonSeed, START_X, START_Y)[0],
+          roomPresence: derivePresencePda(
+            program.programId,
+            seasonSeed,
+            START_X,
+            START_Y,
+            player.publicKey
+          )[0],
+        })
+        .signers([player])
+        .rpc();
+
+      const nftAccount = getAssociatedTokenAddressSync(
+        itemMint.publicKey,
+        player.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      let error: any;
+      try {
+        await program.methods
+          .exportItem(DIAMOND_SWORD, SWORD_DURABILITY)
+          .accountsPartial({
+            player: player.publicKey,
+            itemMint: itemMint.publicKey,
+            playerTokenAccount: nftAccount,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([player, itemMint])
+          .rpc();
+      } catch (e: any) {
+        error = e;
+      }
+      expect(error?.error?.errorCode?.code).to.equal("GrantedItemNotExportable");
+
+      const [inventoryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("inventory"), player.publicKey.toBuffer()],
+        program.programId
+      );
+      const inventory = await program.account.inventoryAccount.fetch(inventoryPda);
+      const sword = inventory.items.find((item: any) => item.itemId === DIAMOND_SWORD);
+      expect(sword?.amount).to.equal(1);
+    });
+  });
+
+  describe("room discovery compute", () => {
+    const WALL_OPEN = 2;
+    const DEFAULT_COMPUTE_UNIT_LIMIT = 200_000;
+
+    const adjacent = (x: number, y: number, direction: number): [number, number] => {
+      switch (direction) {
+        case DIRECTION_NORTH:
+          return [x, y + 1];
+        case DIRECTION_SOUTH:
+          return [x, y - 1];
+        case DIRECTION_EAST:
+          return [x + 1, y];
+        default:
+          return [x - 1, y];
+      }
+    };
+
+    const moveBuilder = (seasonSeed: anchor.BN, from: [number, number], to: [number, number]) =>
+      program.methods.movePlayer(to[0], to[1]).accountsPartial({
+        authority: player.publicKey,
+        player: player.publicKey,
+        currentRoom: deriveRoomPda(program.programId, seasonSeed, from[0], from[1])[0],
+        targetRoom: deriveRoomPda(program.programId, seasonSeed, to[0], to[1])[0],
+        floorConnectivity: deriveFloorConnectivityPda(program.programId, seasonSeed, 0)[0],
+        currentPresence: derivePresencePda(
+          program.programId,
+          seasonSeed,
+          from[0],
+          from[1],
+          player.publicKey
+        )[0],
+        targetPresence: derivePresencePda(
+          program.programId,
+          seasonSeed,
+          to[0],
+          to[1],
+          player.publicKey
+        )[0],
+        sessionAuthority: null,
+      });
+
+    it("discovers rooms after the first on a floor within the default compute budget", async function () {
+      const { seasonSeed } = await program.account.globalAccount.fetch(globalPda);
+      const start: [number, number] = [START_X, START_Y];
+      await program.methods
+        .enterDungeon()
+        .accountsPartial({
+          authority: player.publicKey,
+          player: player.publicKey,
+          startRoom: deriveRoomPda(program.programId, seasonSeed, START_X, START_Y)[0],
+          roomPresence: derivePresencePda(
+            program.programId,
+            seasonSeed,
+            START_X,
+            START_Y,
+            player.publicKey
+          )[0],
+          sessionAuthority: null,
+        })
+        .signers([player])
+        .rpc();
+
+      const startRoom = await program.account.roomAccount.fetch(
+        deriveRoomPda(program.programId, seasonSeed, START_X, START_Y)[0]
+      );
+      const openDirections = [DIRECTION_NORTH, DIRECTION_SOUTH, DIRECTION_EAST, DIRECTION_WEST]
+        .filter((direction) => startRoom.walls[direction] === WALL_OPEN);
+      if (openDirections.length < 2) {
+        this.skip();
+      }
+
+      // The first discovery on the floor runs the full connectivity analysis once.
+      const first = adjacent(START_X, START_Y, openDirections[0]);
+      await moveBuilder(seasonSeed, start, first)
+        .preInstructions([
+          anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+        ])
+        .signers([player])
+        .rpc();
+      await moveBuilder(seasonSeed, first, start).signers([player]).rpc();
+
+      const tx = await moveBuilder(
+        seasonSeed,
+        start,
+        adjacent(START_X, START_Y, openDirections[1])
+      ).transaction();
+      tx.feePayer = player.publicKey;
+      tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+      const simulation = await provider.connection.simulateTransaction(tx, [player]);
+
+      expect(simulation.value.err).to.equal(null);
+      expect(simulation.value.unitsConsumed).to.be.lessThan(DEFAULT_COMPUTE_UNIT_LIMIT);
+    });
+  });
+
   describe("job operations", () => {
     it("validates direction parameter", async () => {
       // Test that invalid directions are rejected
@@ -206,6 +408,40 @@ export function deriveRoomPda(
   );
 }
 
+export function derivePresencePda(
+  programId: anchor.web3.PublicKey,
+  seasonSeed: anchor.BN,
+  x: number,
+  y: number,
+  playerPubkey: anchor.web3.PublicKey
+): [anchor.web3.PublicKey, number] {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("presence"),
+      seasonSeed.toArrayLike(Buffer, "le", 8),
+      Buffer.from([x]),
+      Buffer.from([y]),
+      playerPubkey.toBuffer(),
+    ],
+    programId
+  );
+}
+
+export function deriveFloorConnectivityPda(
+  programId: anchor.web3.PublicKey,
+  seasonSeed: anchor.BN,
+  floor: number
+): [anchor.web3.PublicKey, number] {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("floor_connectivity"),
+      seasonSeed.toArrayLike(Buffer, "le", 8),
+      Buffer.from([floor]),
+    ],
+    programId
+  );
+}
+
 export function deriveEscrowPda(
   programId: anchor.web3.PublicKey,
   roomPda: anchor.web3.PublicKey,